@@ -0,0 +1,22 @@
+#ifndef READTIMESTAMP_H
+#define READTIMESTAMP_H
+
+/* Built with `cargo build --release --no-default-features --features ffi`, which produces libreadtimestamp.so (or
+ * .dylib, or .dll) in target/release */
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Every readable candidate for `input`, as JSON: {"input": ..., "candidates": [...]}, or {"error": ...}. Returns NULL
+ * only if `input` is NULL. The result has to be freed with readtimestamp_free. */
+char *readtimestamp_interpret(const char *input);
+
+/* Frees a string returned by readtimestamp_interpret (doing nothing if it is NULL) */
+void readtimestamp_free(char *json);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
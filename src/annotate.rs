@@ -0,0 +1,629 @@
+use crate::{
+    config::{BOOT_GROUP_NAME, TIMESTAMP_GROUP_NAME},
+    date_time::{parse_date_time, parse_syslog, parse_time_of_day},
+    interpret::{self, Settings},
+    number::Number,
+    timing::Timing,
+    Unit, Zone,
+};
+use regex::{Captures, Regex};
+use serde_json::{json, Value};
+use std::{
+    cell::Cell,
+    fmt::{self, Write as _},
+    str::FromStr,
+};
+use time::{
+    format_description::well_known::Rfc3339, Date, Duration, OffsetDateTime, PrimitiveDateTime,
+    Time, UtcOffset,
+};
+
+/// Digit runs long enough to plausibly be a Unix timestamp in seconds (9 digits reaches back to 1973) or a smaller
+/// unit, the dates and times of Apache and nginx access logs, and those of both kinds of syslog (RFC 3339 in RFC 5424)
+const DEFAULT_PATTERN: &str = concat!(
+    r"\b-?[0-9]{9,19}(?:\.[0-9]+)?\b",
+    r"|[0-9]{2}/[A-Z][a-z]{2}/[0-9]{4}:[0-9]{2}:[0-9]{2}:[0-9]{2} [+-][0-9]{4}",
+    r"|\b[0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}(?:\.[0-9]+)?(?:Z|[+-][0-9]{2}:[0-9]{2})",
+    r"|\b(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec) [ 0-9][0-9] [0-9]{2}:[0-9]{2}:[0-9]{2}\b"
+);
+
+/// Which timestamp on a line to use, either by position (starting at 1) or by the name of the capture group it was
+/// matched by
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Selector {
+    Position(usize),
+    Name(String),
+}
+
+impl FromStr for Selector {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        match st.parse::<usize>() {
+            Ok(0_usize) => anyhow::bail!("Positions start at 1"),
+            Ok(us) => Ok(Self::Position(us)),
+            Err(_) => Ok(Self::Name(st.to_owned())),
+        }
+    }
+}
+
+/// Two timestamps on the same line to compute the difference between, written "FROM,TO" (e.g. "1,2" or
+/// "start,end")
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeltaSelector {
+    pub from: Selector,
+    pub to: Selector,
+}
+
+impl FromStr for DeltaSelector {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let (from, to) = st
+            .split_once(',')
+            .ok_or_else(|| anyhow::anyhow!("Expected two selectors separated by a comma"))?;
+
+        Ok(Self {
+            from: from.trim().parse()?,
+            to: to.trim().parse()?,
+        })
+    }
+}
+
+/// How the lines annotated so far went, for deciding whether a batch of input was acceptable
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tally {
+    pub lines: usize,
+    /// Timestamps with exactly one plausible interpretation (or that were interpreted in a given unit)
+    pub parsed: usize,
+    /// Timestamps with more than one plausible interpretation, of which the best candidate was used
+    pub ambiguous: usize,
+    /// Matches that could not be interpreted as a timestamp in any unit
+    pub failed: usize,
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fo,
+            "{} lines, {} timestamps: {} parsed, {} ambiguous, {} failed",
+            self.lines,
+            self.parsed + self.ambiguous + self.failed,
+            self.parsed,
+            self.ambiguous,
+            self.failed
+        )
+    }
+}
+
+/// A timestamp found on a line
+struct Found {
+    /// What was matched
+    text: String,
+    name: Option<String>,
+    instant: OffsetDateTime,
+}
+
+/// Appends the interpretation of every timestamp found on a line to the end of it
+pub struct Annotator {
+    regex: Regex,
+    unit: Option<Unit>,
+    zone: Option<Zone>,
+    delta: Option<DeltaSelector>,
+    boot_time: Option<OffsetDateTime>,
+    /// The date and time of the last time of day seen, which starts at midnight of the date they are anchored to
+    last_time_of_day: Cell<Option<PrimitiveDateTime>>,
+    settings: Settings,
+    tally: Tally,
+    timing: Timing,
+}
+
+impl Annotator {
+    /// Timestamps are found with `pattern` if it is given: every capture group that participates in a match is a
+    /// timestamp (only the groups named "ts" if there are any), or the whole match if the pattern has no capture
+    /// groups
+    ///
+    /// Timestamps are interpreted in `unit` if it is given, instead of in the unit of the best candidate, and are
+    /// displayed in `zone` (UTC if it is not given)
+    ///
+    /// Groups named "boot" are times since boot (in `unit`, or seconds if it is not given) instead of timestamps, and
+    /// are added to `boot_time`
+    ///
+    /// Times of day (e.g. "22:13:20.123456") are on `date`, or on the day after the previous time of day if they are
+    /// more than 12 hours before it (so a capture that runs past midnight moves on to the next day), and are read in
+    /// the settings' time zone
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression, or if it has a group named "boot" and
+    /// `boot_time` is not given
+    pub fn new(
+        pattern: Option<&str>,
+        unit: Option<Unit>,
+        zone: Option<Zone>,
+        delta: Option<DeltaSelector>,
+        boot_time: Option<OffsetDateTime>,
+        date: Option<Date>,
+        settings: Settings,
+    ) -> anyhow::Result<Self> {
+        let regex = Regex::new(pattern.unwrap_or(DEFAULT_PATTERN))?;
+
+        anyhow::ensure!(
+            boot_time.is_some() || !regex.capture_names().any(|op| op == Some(BOOT_GROUP_NAME)),
+            "The pattern has times since boot (a capture group named \"{BOOT_GROUP_NAME}\"), so the boot time is needed"
+        );
+
+        Ok(Self {
+            regex,
+            unit,
+            zone,
+            delta,
+            boot_time,
+            last_time_of_day: Cell::new(date.map(Date::midnight)),
+            settings,
+            tally: Tally::default(),
+            timing: Timing::default(),
+        })
+    }
+
+    /// Counts of the lines and timestamps seen by [`Annotator::annotate_line`] so far
+    #[must_use]
+    pub const fn tally(&self) -> Tally {
+        self.tally
+    }
+
+    /// How long the lines seen by [`Annotator::annotate_line`] so far took to handle
+    #[must_use]
+    pub const fn timing(&self) -> Timing {
+        self.timing
+    }
+
+    /// Returns `line` unchanged if no timestamps were found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an instant cannot be formatted
+    pub fn annotate_line(&mut self, line: &str) -> anyhow::Result<String> {
+        let (found_vec, formatted_vec) = self.find_and_format(line)?;
+
+        if found_vec.is_empty() {
+            return Ok(line.to_owned());
+        }
+
+        let mut annotated = format!("{line}  #");
+
+        for (us, st) in formatted_vec.iter().enumerate() {
+            write!(annotated, "{} {st}", if us == 0_usize { "" } else { " |" })?;
+        }
+
+        if let Some(st) = self.format_delta(&found_vec) {
+            write!(annotated, " | delta: {st}")?;
+        }
+
+        Ok(annotated)
+    }
+
+    /// Like [`Annotator::annotate_line`], but as a JSON object with the line's number (starting at 1), the line, and
+    /// the timestamps found on it (with what was matched, the capture group's name, and the instant), for writing as
+    /// JSON Lines
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an instant cannot be formatted
+    pub fn annotate_line_json(&mut self, line: &str) -> anyhow::Result<Value> {
+        let (found_vec, formatted_vec) = self.find_and_format(line)?;
+
+        let timestamps = found_vec
+            .iter()
+            .zip(formatted_vec)
+            .map(|(fo, st)| {
+                json!({
+                    "match": fo.text,
+                    "group": fo.name,
+                    "instant": st,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut object = json!({
+            "line": self.tally.lines,
+            "text": line,
+            "timestamps": timestamps,
+        });
+
+        if let Some(st) = self.format_delta(&found_vec) {
+            object["delta"] = Value::String(st);
+        }
+
+        Ok(object)
+    }
+
+    /// Finds the timestamps on `line`, and formats their instants in the time zone they are displayed in
+    fn find_and_format(&mut self, line: &str) -> anyhow::Result<(Vec<Found>, Vec<String>)> {
+        self.tally.lines += 1_usize;
+
+        let mut timing = self.timing;
+
+        let found_vec = Timing::measure(&mut timing.parsing, || self.find(line));
+
+        let mut formatted_vec = Vec::<String>::with_capacity(found_vec.len());
+
+        for fo in &found_vec {
+            let instant = match self.zone {
+                Some(zo) => fo
+                    .instant
+                    .to_offset(Timing::measure(&mut timing.zone_resolution, || {
+                        zo.offset_at(fo.instant)
+                    })?),
+                None => fo.instant,
+            };
+
+            formatted_vec.push(Timing::measure(&mut timing.formatting, || {
+                instant.format(&Rfc3339)
+            })?);
+        }
+
+        self.timing = timing;
+
+        Ok((found_vec, formatted_vec))
+    }
+
+    /// The difference between the timestamps selected by `--delta`, "n/a" if either is missing, or `None` if no
+    /// difference was asked for
+    fn format_delta(&self, found_vec: &[Found]) -> Option<String> {
+        let de = self.delta.as_ref()?;
+
+        let formatted = match (select(found_vec, &de.from), select(found_vec, &de.to)) {
+            (Some(fo), Some(fou)) => {
+                let duration = fou.instant - fo.instant;
+
+                format!(
+                    "{}{duration}",
+                    if duration.is_negative() { "" } else { "+" }
+                )
+            }
+            _ => "n/a".to_owned(),
+        };
+
+        Some(formatted)
+    }
+
+    /// Puts `time` on the date times of day are anchored to, or returns `None` if there is none
+    fn resolve_time_of_day(&self, time: Time) -> Option<OffsetDateTime> {
+        let last = self.last_time_of_day.get()?;
+
+        let mut primitive_date_time = last.replace_time(time);
+
+        if primitive_date_time < last - Duration::hours(12_i64) {
+            primitive_date_time += Duration::DAY;
+        }
+
+        self.last_time_of_day.set(Some(primitive_date_time));
+
+        self.settings.zone.resolve(primitive_date_time).ok()
+    }
+
+    fn find(&mut self, line: &str) -> Vec<Found> {
+        let name_vec = self.regex.capture_names().collect::<Vec<_>>();
+
+        let has_timestamp_group = name_vec.contains(&Some(TIMESTAMP_GROUP_NAME))
+            || name_vec.contains(&Some(BOOT_GROUP_NAME));
+
+        let mut found_vec = Vec::<Found>::new();
+
+        let mut tally = self.tally;
+
+        for ca in self.regex.captures_iter(line) {
+            if ca.len() == 1_usize {
+                self.push_found(&mut found_vec, &mut tally, &ca, 0_usize, None);
+
+                continue;
+            }
+
+            for (us, op) in name_vec.iter().enumerate().skip(1_usize) {
+                if has_timestamp_group
+                    && *op != Some(TIMESTAMP_GROUP_NAME)
+                    && *op != Some(BOOT_GROUP_NAME)
+                {
+                    continue;
+                }
+
+                self.push_found(&mut found_vec, &mut tally, &ca, us, *op);
+            }
+        }
+
+        self.tally = tally;
+
+        found_vec
+    }
+
+    fn push_found(
+        &self,
+        found_vec: &mut Vec<Found>,
+        tally: &mut Tally,
+        captures: &Captures<'_>,
+        index: usize,
+        name: Option<&str>,
+    ) {
+        let Some(ma) = captures.get(index) else {
+            return;
+        };
+
+        let Ok(nu) = ma.as_str().parse::<Number>() else {
+            let instant = parse_date_time(ma.as_str())
+                .map(|(_, of)| of)
+                .or_else(|| parse_syslog(ma.as_str(), self.settings.now(), self.settings.zone))
+                .or_else(|| self.resolve_time_of_day(parse_time_of_day(ma.as_str())?));
+
+            match instant {
+                Some(of) => {
+                    tally.parsed += 1_usize;
+
+                    // Displayed in UTC like the rest if no time zone is given, not in the offset it was written with
+                    found_vec.push(Found {
+                        text: ma.as_str().to_owned(),
+                        name: name.map(str::to_owned),
+                        instant: of.to_offset(UtcOffset::UTC),
+                    });
+                }
+                None => {
+                    tally.failed += 1_usize;
+                }
+            }
+
+            return;
+        };
+
+        let (instant, is_ambiguous) =
+            if let (Some(BOOT_GROUP_NAME), Some(of)) = (name, self.boot_time) {
+                (
+                    nu.nanoseconds(self.unit.unwrap_or(Unit::Seconds))
+                        .and_then(|io| i64::try_from(io).ok())
+                        .and_then(|io| of.checked_add(Duration::nanoseconds(io))),
+                    false,
+                )
+            } else if let Some(un) = self.unit {
+                (
+                    nu.unix_nanoseconds(un)
+                        .and_then(|io| OffsetDateTime::from_unix_timestamp_nanos(io).ok()),
+                    false,
+                )
+            } else {
+                let candidate_vec = interpret::interpret(nu, &self.settings);
+
+                // The best candidate is the one closest to now
+                (
+                    candidate_vec.first().and_then(|ca| ca.result.ok()),
+                    candidate_vec.iter().filter(|ca| ca.is_plausible()).count() > 1_usize,
+                )
+            };
+
+        let Some(of) = instant else {
+            tally.failed += 1_usize;
+
+            return;
+        };
+
+        if is_ambiguous {
+            tally.ambiguous += 1_usize;
+        } else {
+            tally.parsed += 1_usize;
+        }
+
+        found_vec.push(Found {
+            text: ma.as_str().to_owned(),
+            name: name.map(str::to_owned),
+            instant: of,
+        });
+    }
+}
+
+fn select<'a>(found_vec: &'a [Found], selector: &Selector) -> Option<&'a Found> {
+    match selector {
+        Selector::Position(us) => found_vec.get(us - 1_usize),
+        Selector::Name(st) => found_vec
+            .iter()
+            .find(|fo| fo.name.as_deref() == Some(st.as_str())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        annotate::{Annotator, DeltaSelector, Tally},
+        config::Config,
+        interpret::Settings,
+        Unit, Zone,
+    };
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn test_annotate_line() -> anyhow::Result<()> {
+        let mut annotator = Annotator::new(
+            None,
+            None,
+            None,
+            Some("1,2".parse::<DeltaSelector>()?),
+            None,
+            None,
+            Settings::deterministic(),
+        )?;
+
+        anyhow::ensure!(
+            annotator.annotate_line("start=1700000000 end=1700000001500")?
+                == "start=1700000000 end=1700000001500  # 2023-11-14T22:13:20Z | 2023-11-14T22:13:21.5Z | delta: +1s500ms"
+        );
+        anyhow::ensure!(annotator.annotate_line("no timestamps")? == "no timestamps");
+        anyhow::ensure!(annotator
+            .annotate_line(
+                r#"10.0.0.1 - - [14/Nov/2023:23:13:20 +0100] "GET / HTTP/1.1" 200 1700000001"#
+            )?
+            .ends_with("# 2023-11-14T22:13:20Z | 2023-11-14T22:13:21Z | delta: +1s"));
+
+        // Both kinds of syslog, the first of which has no year (the closest one to now is used)
+        anyhow::ensure!(
+            annotator.annotate_line("<34>1 2023-11-14T22:13:20.5Z host app - - - hi")?
+                == "<34>1 2023-11-14T22:13:20.5Z host app - - - hi  # 2023-11-14T22:13:20.5Z | delta: n/a"
+        );
+        anyhow::ensure!(
+            annotator.annotate_line("Dec 31 23:59:59 host app[1]: hi")?
+                == "Dec 31 23:59:59 host app[1]: hi  # 1999-12-31T23:59:59Z | delta: n/a"
+        );
+
+        anyhow::ensure!(
+            annotator.tally()
+                == Tally {
+                    lines: 5_usize,
+                    parsed: 6_usize,
+                    ambiguous: 0_usize,
+                    failed: 0_usize,
+                }
+        );
+
+        let mut named = Annotator::new(
+            Some(r"begin (?<begin>\d+) finish (?<finish>\d+)"),
+            None,
+            None,
+            Some("begin,finish".parse::<DeltaSelector>()?),
+            None,
+            None,
+            Settings::deterministic(),
+        )?;
+
+        anyhow::ensure!(named
+            .annotate_line("begin 1700000060 finish 1700000000")?
+            .ends_with("delta: -1m"));
+        anyhow::ensure!(
+            named
+                .annotate_line_json("begin 1700000060 finish 1700000000")?
+                .to_string()
+                == r#"{"line":2,"text":"begin 1700000060 finish 1700000000","timestamps":[{"match":"1700000060","group":"begin","instant":"2023-11-14T22:14:20Z"},{"match":"1700000000","group":"finish","instant":"2023-11-14T22:13:20Z"}],"delta":"-1m"}"#
+        );
+
+        // Only the "ts" group is a timestamp, and its unit is not guessed
+        let mut profile = Annotator::new(
+            Some(r"pid=(?<pid>\d+) at=(?<ts>\d+)"),
+            Some(Unit::Milliseconds),
+            Some("+01:00".parse::<Zone>()?),
+            None,
+            None,
+            None,
+            Settings::deterministic(),
+        )?;
+
+        anyhow::ensure!(
+            profile.annotate_line("pid=1700000000 at=1700000000")?
+                == "pid=1700000000 at=1700000000  # 1970-01-20T17:13:20+01:00"
+        );
+        anyhow::ensure!(
+            profile.annotate_line("pid=1 at=99999999999999999999")?
+                == "pid=1 at=99999999999999999999"
+        );
+        anyhow::ensure!(profile.tally().parsed == 1_usize);
+        anyhow::ensure!(profile.tally().failed == 1_usize);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotate_since_boot() -> anyhow::Result<()> {
+        let config = Config::default();
+
+        let journald = config.profile("journald")?;
+
+        anyhow::ensure!(Annotator::new(
+            Some(&journald.pattern),
+            journald.unit,
+            None,
+            None,
+            None,
+            None,
+            Settings::deterministic()
+        )
+        .is_err());
+
+        let mut annotator = Annotator::new(
+            Some(&journald.pattern),
+            journald.unit,
+            None,
+            None,
+            Some(datetime!(2023-11-14 22:00 UTC)),
+            None,
+            Settings::deterministic(),
+        )?;
+
+        anyhow::ensure!(
+            annotator.annotate_line("__REALTIME_TIMESTAMP=1700000000123456")?
+                == "__REALTIME_TIMESTAMP=1700000000123456  # 2023-11-14T22:13:20.123456Z"
+        );
+        anyhow::ensure!(
+            annotator.annotate_line("__MONOTONIC_TIMESTAMP=800123456")?
+                == "__MONOTONIC_TIMESTAMP=800123456  # 2023-11-14T22:13:20.123456Z"
+        );
+        anyhow::ensure!(annotator.annotate_line("_PID=1700000000")? == "_PID=1700000000");
+
+        let dmesg = config.profile("dmesg")?;
+
+        let mut dmesg_annotator = Annotator::new(
+            Some(&dmesg.pattern),
+            dmesg.unit,
+            None,
+            None,
+            Some(datetime!(2023-11-14 22:00 UTC)),
+            None,
+            Settings::deterministic(),
+        )?;
+
+        anyhow::ensure!(
+            dmesg_annotator.annotate_line("[  800.500000] usb 1-1: new device")?
+                == "[  800.500000] usb 1-1: new device  # 2023-11-14T22:13:20.5Z"
+        );
+        anyhow::ensure!(dmesg_annotator
+            .annotate_line("<6>[    0.000000] Linux version")?
+            .ends_with("# 2023-11-14T22:00:00Z"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotate_time_of_day() -> anyhow::Result<()> {
+        let tcpdump = Config::default().profile("tcpdump")?;
+
+        let mut annotator = Annotator::new(
+            Some(&tcpdump.pattern),
+            tcpdump.unit,
+            None,
+            None,
+            None,
+            Some(date!(2023 - 11 - 14)),
+            Settings::deterministic(),
+        )?;
+
+        anyhow::ensure!(annotator
+            .annotate_line("23:59:59.999999 IP 10.0.0.1.22 > 10.0.0.2.5000: Flags [P.]")?
+            .contains("# 2023-11-14T23:59:59.999999Z"));
+        // Past midnight
+        anyhow::ensure!(annotator
+            .annotate_line("00:00:00.000001 IP 10.0.0.2.5000 > 10.0.0.1.22: Flags [.]")?
+            .contains("# 2023-11-15T00:00:00.000001Z"));
+        // Given "-tt"
+        anyhow::ensure!(annotator
+            .annotate_line("1700000000.123456 IP 10.0.0.1.22 > 10.0.0.2.5000: Flags [P.]")?
+            .contains("# 2023-11-14T22:13:20.123456Z"));
+
+        let mut unanchored = Annotator::new(
+            Some(&tcpdump.pattern),
+            tcpdump.unit,
+            None,
+            None,
+            None,
+            None,
+            Settings::deterministic(),
+        )?;
+
+        anyhow::ensure!(unanchored.annotate_line("23:59:59.999999 IP")? == "23:59:59.999999 IP");
+        anyhow::ensure!(unanchored.tally().failed == 1_usize);
+
+        Ok(())
+    }
+}
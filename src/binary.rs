@@ -0,0 +1,142 @@
+//! Raw integers in binary files, for pulling timestamps out of file formats that are not documented
+
+use std::{
+    fs::File,
+    io::{Read as _, Seek as _, SeekFrom},
+    path::Path,
+    str::FromStr,
+};
+
+/// The byte order of an integer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+impl FromStr for Endian {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        match st.to_ascii_lowercase().as_str() {
+            "little" | "le" => Ok(Self::Little),
+            "big" | "be" => Ok(Self::Big),
+            _ => anyhow::bail!("Unrecognized byte order \"{st}\" (expected \"little\" or \"big\")"),
+        }
+    }
+}
+
+/// Parses a file offset in decimal, or in hexadecimal with a "0x" prefix (e.g. "0x40")
+///
+/// # Errors
+///
+/// Returns an error if `input` is not a non-negative integer
+pub fn parse_offset(input: &str) -> anyhow::Result<u64> {
+    let offset = match input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        Some(st) => u64::from_str_radix(st, 16_u32)?,
+        None => input.parse::<u64>()?,
+    };
+
+    Ok(offset)
+}
+
+/// Reads the integer that fills `by` (which has to be 1, 2, 4, 8, or 16 bytes long)
+///
+/// # Errors
+///
+/// Returns an error if `by` has another length, or if it is an unsigned 16-byte integer too large for an `i128`
+pub fn read_integer(by: &[u8], endian: Endian, signed: bool) -> anyhow::Result<i128> {
+    anyhow::ensure!(
+        matches!(by.len(), 1_usize | 2_usize | 4_usize | 8_usize | 16_usize),
+        "The width has to be 1, 2, 4, 8, or 16 bytes, not {}",
+        by.len()
+    );
+
+    let mut big_endian = [0_u8; 16_usize];
+
+    // Right-aligned, so the integer is in the low bytes
+    let start = big_endian.len() - by.len();
+
+    big_endian[start..].copy_from_slice(by);
+
+    if endian == Endian::Little {
+        big_endian[start..].reverse();
+    }
+
+    // Sign extension, so a narrower negative integer stays negative
+    if signed && big_endian[start] & 0x80_u8 != 0_u8 {
+        big_endian[..start].fill(0xFF_u8);
+    }
+
+    let unsigned = u128::from_be_bytes(big_endian);
+
+    if signed {
+        Ok(i128::from_be_bytes(big_endian))
+    } else {
+        i128::try_from(unsigned)
+            .map_err(|_| anyhow::anyhow!("{unsigned} is too large to interpret"))
+    }
+}
+
+/// Reads the `width`-byte integer at `offset` in the file at `path`
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, it ends before the integer does, or [`read_integer`] fails
+pub fn read_file_integer(
+    path: &Path,
+    offset: u64,
+    width: usize,
+    endian: Endian,
+    signed: bool,
+) -> anyhow::Result<i128> {
+    let mut file = File::open(path)
+        .map_err(|er| anyhow::anyhow!("Could not open \"{}\": {er}", path.display()))?;
+
+    file.seek(SeekFrom::Start(offset))?;
+
+    let mut by = vec![0_u8; width];
+
+    file.read_exact(&mut by).map_err(|er| {
+        anyhow::anyhow!(
+            "Could not read {width} bytes at offset {offset:#x} of \"{}\": {er}",
+            path.display()
+        )
+    })?;
+
+    read_integer(&by, endian, signed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binary::{parse_offset, read_integer, Endian};
+
+    #[test]
+    fn test_read_integer() -> anyhow::Result<()> {
+        // 1700000000 is 0x6553F100
+        anyhow::ensure!(
+            read_integer(&[0x00, 0xF1, 0x53, 0x65], Endian::Little, false)? == 1_700_000_000_i128
+        );
+        anyhow::ensure!(
+            read_integer(&[0x65, 0x53, 0xF1, 0x00], Endian::Big, false)? == 1_700_000_000_i128
+        );
+        anyhow::ensure!(
+            read_integer(&1_700_000_000_000_i64.to_le_bytes(), Endian::Little, true)?
+                == 1_700_000_000_000_i128
+        );
+
+        anyhow::ensure!(read_integer(&[0xFF, 0xFF], Endian::Big, true)? == -1_i128);
+        anyhow::ensure!(read_integer(&[0xFF, 0xFF], Endian::Big, false)? == 65_535_i128);
+        anyhow::ensure!(read_integer(&[0xFF; 16], Endian::Big, false).is_err());
+        anyhow::ensure!(read_integer(&[0x00; 3], Endian::Big, false).is_err());
+
+        anyhow::ensure!(parse_offset("0x40")? == 64_u64);
+        anyhow::ensure!(parse_offset("64")? == 64_u64);
+        anyhow::ensure!(parse_offset("-1").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+//! When the system booted, which times since boot (like the offsets at the start of `dmesg` lines) count from
+//!
+//! The kernel only knows the boot time to the second, and its times since boot stop while the system is suspended, so
+//! instants computed from them fall further behind the real ones after each suspend.
+
+use std::fs;
+use time::OffsetDateTime;
+
+const PROC_STAT_PATH: &str = "/proc/stat";
+
+/// Reads the boot time from `/proc/stat`, which only exists on Linux
+///
+/// # Errors
+///
+/// Returns an error if `/proc/stat` cannot be read or has no boot time
+pub fn boot_time() -> anyhow::Result<OffsetDateTime> {
+    let string = fs::read_to_string(PROC_STAT_PATH)
+        .map_err(|er| anyhow::anyhow!("Could not read \"{PROC_STAT_PATH}\": {er}"))?;
+
+    parse_proc_stat(&string)
+}
+
+/// Reads the "btime" line (the boot time in Unix seconds) of the contents of `/proc/stat`
+///
+/// # Errors
+///
+/// Returns an error if there is no valid "btime" line
+pub fn parse_proc_stat(proc_stat: &str) -> anyhow::Result<OffsetDateTime> {
+    let seconds = proc_stat
+        .lines()
+        .find_map(|st| st.strip_prefix("btime "))
+        .ok_or_else(|| anyhow::anyhow!("There is no \"btime\" line in \"{PROC_STAT_PATH}\""))?
+        .trim()
+        .parse::<i64>()?;
+
+    Ok(OffsetDateTime::from_unix_timestamp(seconds)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::boot::parse_proc_stat;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_parse_proc_stat() -> anyhow::Result<()> {
+        let proc_stat = "cpu  10132153 290696 3084719 46828483 16683 0 25195 0 0 0
+intr 1462898 0 0 0
+ctxt 1990473
+btime 1700000000
+processes 2915
+";
+
+        anyhow::ensure!(parse_proc_stat(proc_stat)? == datetime!(2023-11-14 22:13:20 UTC));
+        anyhow::ensure!(parse_proc_stat("ctxt 1990473\n").is_err());
+
+        Ok(())
+    }
+}
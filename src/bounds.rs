@@ -0,0 +1,253 @@
+use crate::zone::Zone;
+use std::str::FromStr;
+use time::{Date, Duration, Month, OffsetDateTime};
+
+/// A calendar period that contains a date
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Period {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Period {
+    pub const ALL: [Self; 4_usize] = [Self::Day, Self::Week, Self::Month, Self::Year];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+
+    /// The first date of the period containing `date`, and the first date of the period after it
+    ///
+    /// Weeks start on Monday (ISO 8601)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either date cannot be represented
+    pub fn date_range(self, date: Date) -> anyhow::Result<(Date, Date)> {
+        let year = date.year();
+
+        let out_of_range =
+            || anyhow::anyhow!("The {} containing {date} is out of range", self.name());
+
+        let date_range = match self {
+            Self::Day => (date, date.next_day().ok_or_else(out_of_range)?),
+            Self::Week => {
+                let first = date
+                    .checked_sub(Duration::days(i64::from(
+                        date.weekday().number_days_from_monday(),
+                    )))
+                    .ok_or_else(out_of_range)?;
+
+                (
+                    first,
+                    first.checked_add(Duration::WEEK).ok_or_else(out_of_range)?,
+                )
+            }
+            Self::Month => {
+                let month = date.month();
+
+                let first = Date::from_calendar_date(year, month, 1_u8)?;
+
+                let next = if month == Month::December {
+                    Date::from_calendar_date(year + 1_i32, Month::January, 1_u8)?
+                } else {
+                    Date::from_calendar_date(year, month.next(), 1_u8)?
+                };
+
+                (first, next)
+            }
+            Self::Year => (
+                Date::from_calendar_date(year, Month::January, 1_u8)?,
+                Date::from_calendar_date(year + 1_i32, Month::January, 1_u8)?,
+            ),
+        };
+
+        Ok(date_range)
+    }
+}
+
+/// What instants are floored to with `--truncate`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Truncation {
+    Hour,
+    Period(Period),
+}
+
+impl Truncation {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Period(pe) => pe.name(),
+        }
+    }
+
+    /// The start of the hour, day, week, month, or year containing `instant` in `zone`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start cannot be represented or resolved in `zone`
+    pub fn floor(self, instant: OffsetDateTime, zone: Zone) -> anyhow::Result<OffsetDateTime> {
+        let local = instant.to_offset(zone.offset_at(instant)?);
+
+        match self {
+            // The local hour, which starts at half past the hour in UTC in offsets like +05:30
+            Self::Hour => Ok(local.replace_time(time::Time::from_hms(local.hour(), 0_u8, 0_u8)?)),
+            Self::Period(pe) => {
+                let (first, _) = pe.date_range(local.date())?;
+
+                zone.resolve(first.midnight())
+            }
+        }
+    }
+}
+
+impl FromStr for Truncation {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        match st.to_ascii_lowercase().as_str() {
+            "hour" | "h" => Ok(Self::Hour),
+            "day" | "d" => Ok(Self::Period(Period::Day)),
+            "week" | "w" => Ok(Self::Period(Period::Week)),
+            "month" | "mo" => Ok(Self::Period(Period::Month)),
+            "year" | "y" => Ok(Self::Period(Period::Year)),
+            _ => anyhow::bail!(
+                "Unrecognized period \"{st}\" (expected \"hour\", \"day\", \"week\", \"month\", or \"year\")"
+            ),
+        }
+    }
+}
+
+/// The first and last instant of a period, resolved in a zone
+pub struct Bounds {
+    pub period: Period,
+    pub start: OffsetDateTime,
+    /// The first instant of the next period (exclusive)
+    pub end_exclusive: OffsetDateTime,
+}
+
+impl Bounds {
+    #[must_use]
+    pub fn start_seconds(&self) -> i64 {
+        self.start.unix_timestamp()
+    }
+
+    #[must_use]
+    pub fn end_seconds(&self) -> i64 {
+        self.end_exclusive.unix_timestamp() - 1_i64
+    }
+
+    #[must_use]
+    pub fn start_milliseconds(&self) -> i128 {
+        self.start.unix_timestamp_nanos() / 1_000_000_i128
+    }
+
+    #[must_use]
+    pub fn end_milliseconds(&self) -> i128 {
+        self.end_exclusive.unix_timestamp_nanos() / 1_000_000_i128 - 1_i128
+    }
+}
+
+/// Computes the start and end of the day, week, month, and year containing `date` in `zone`
+///
+/// # Errors
+///
+/// Returns an error if a period boundary cannot be represented or resolved in `zone`
+pub fn bounds(date: Date, zone: Zone) -> anyhow::Result<Vec<Bounds>> {
+    Period::ALL
+        .into_iter()
+        .map(|pe| {
+            let (first, next) = pe.date_range(date)?;
+
+            Ok(Bounds {
+                period: pe,
+                start: zone.resolve(first.midnight())?,
+                end_exclusive: zone.resolve(next.midnight())?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bounds::{Period, Truncation},
+        zone::Zone,
+    };
+    use time::macros::{date, datetime, offset};
+
+    #[test]
+    fn test_bounds() -> anyhow::Result<()> {
+        let bounds_vec = crate::bounds::bounds(date!(2024 - 05 - 01), Zone::Utc)?;
+
+        let day = &bounds_vec[0_usize];
+
+        anyhow::ensure!(day.start_seconds() == 1_714_521_600_i64);
+        anyhow::ensure!(day.end_seconds() == 1_714_607_999_i64);
+        anyhow::ensure!(day.end_milliseconds() == 1_714_607_999_999_i128);
+
+        // 2024-05-01 was a Wednesday
+        let week = &bounds_vec[1_usize];
+
+        anyhow::ensure!(week.start.date() == date!(2024 - 04 - 29));
+
+        let month = &bounds_vec[2_usize];
+
+        anyhow::ensure!(month.end_exclusive.date() == date!(2024 - 06 - 01));
+
+        let fixed = crate::bounds::bounds(date!(2024 - 05 - 01), Zone::Fixed(offset!(+2)))?;
+
+        anyhow::ensure!(fixed[0_usize].start_seconds() == 1_714_521_600_i64 - 7_200_i64);
+
+        // The last date that can be represented has no day after it
+        anyhow::ensure!(Period::Day.date_range(date!(9999 - 12 - 31)).is_err());
+        anyhow::ensure!(crate::bounds::bounds(date!(9999 - 12 - 31), Zone::Utc).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncation() -> anyhow::Result<()> {
+        let instant = datetime!(2023-11-14 22:13:20.5 UTC);
+
+        anyhow::ensure!(
+            Truncation::Hour.floor(instant, Zone::Utc)? == datetime!(2023-11-14 22:00 UTC)
+        );
+        anyhow::ensure!(
+            "day".parse::<Truncation>()?.floor(instant, Zone::Utc)?
+                == datetime!(2023-11-14 0:00 UTC)
+        );
+        anyhow::ensure!(
+            Truncation::Period(Period::Week).floor(instant, Zone::Utc)?
+                == datetime!(2023-11-13 0:00 UTC)
+        );
+        anyhow::ensure!(
+            Truncation::Period(Period::Month).floor(instant, Zone::Utc)?
+                == datetime!(2023-11-01 0:00 UTC)
+        );
+
+        // Already the next day in +05:30
+        let kolkata = Zone::Fixed(offset!(+5:30));
+
+        anyhow::ensure!(
+            Truncation::Hour.floor(instant, kolkata)? == datetime!(2023-11-15 3:00 +5:30)
+        );
+        anyhow::ensure!(
+            Truncation::Period(Period::Day).floor(instant, kolkata)?
+                == datetime!(2023-11-15 0:00 +5:30)
+        );
+
+        anyhow::ensure!("fortnight".parse::<Truncation>().is_err());
+
+        Ok(())
+    }
+}
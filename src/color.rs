@@ -0,0 +1,78 @@
+//! Terminal colors that can be turned off globally
+//!
+//! The methods mirror [`owo_colors::OwoColorize`], so importing [`Colorize`] instead is enough to make existing
+//! styling respect [`set_enabled`]
+
+use owo_colors::{OwoColorize as _, Style};
+use std::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turns colors and other styling on or off for everything formatted afterwards
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// A value that is styled when displayed, if colors are enabled
+pub struct Painted<'a, T: ?Sized> {
+    value: &'a T,
+    style: Style,
+}
+
+impl<T: fmt::Display + ?Sized> fmt::Display for Painted<'_, T> {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if is_enabled() {
+            fmt::Display::fmt(&self.value.style(self.style), fo)
+        } else {
+            fmt::Display::fmt(self.value, fo)
+        }
+    }
+}
+
+pub trait Colorize: fmt::Display {
+    fn paint(&self, style: Style) -> Painted<'_, Self> {
+        Painted { value: self, style }
+    }
+
+    fn blue(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().blue())
+    }
+
+    fn bold(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().bold())
+    }
+
+    fn cyan(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().cyan())
+    }
+
+    fn dimmed(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().dimmed())
+    }
+
+    fn green(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().green())
+    }
+
+    fn purple(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().purple())
+    }
+
+    fn red(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().red())
+    }
+
+    fn yellow(&self) -> Painted<'_, Self> {
+        self.paint(Style::new().yellow())
+    }
+}
+
+impl<T: fmt::Display + ?Sized> Colorize for T {}
@@ -0,0 +1,199 @@
+//! Rewriting one column of a delimited file (CSV or TSV) from timestamps to dates, or from dates to timestamps, row by
+//! row
+//!
+//! Cells that cannot be converted (including empty ones) are left as they were, so a stray header or missing value does
+//! not stop the rest of the file from being rewritten.
+
+use crate::{
+    date_time::parse_date_time,
+    interpret::{self, Settings},
+    number::Number,
+    Unit,
+};
+use std::{
+    fmt,
+    io::{Read, Write},
+};
+use time::{format_description::well_known::Rfc3339, UtcOffset};
+
+/// Which way cells are converted
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// From timestamps to RFC 3339 dates and times in UTC
+    ToDates,
+    /// From dates and times (in any format [`parse_date_time`] recognizes) to timestamps
+    ToTimestamps,
+}
+
+/// How the cells of the column went
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tally {
+    pub rows: usize,
+    pub converted: usize,
+    /// Cells left as they were, including those of rows too short to have the column
+    pub failed: usize,
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fo,
+            "{} rows: {} converted, {} left as they were",
+            self.rows, self.converted, self.failed
+        )
+    }
+}
+
+/// Converts a single cell, or returns `None` if it is not a timestamp (or date) that can be converted
+#[must_use]
+pub fn convert_cell(cell: &str, unit: Unit, direction: Direction) -> Option<String> {
+    let trimmed = cell.trim();
+
+    match direction {
+        Direction::ToDates => {
+            let number = trimmed.parse::<Number>().ok()?;
+
+            interpret::interpret_in(number, unit, &Settings::default())
+                .result
+                .ok()?
+                .to_offset(UtcOffset::UTC)
+                .format(&Rfc3339)
+                .ok()
+        }
+        Direction::ToTimestamps => {
+            let (_, of) = parse_date_time(trimmed)?;
+
+            let unix_nanoseconds = of.unix_timestamp_nanos();
+
+            // Without the hexadecimal that is shown alongside DOS packed dates and times
+            Some(if unit == Unit::Dos {
+                unit.from_unix_nanoseconds(unix_nanoseconds).to_string()
+            } else {
+                unit.format_from_unix_nanoseconds(unix_nanoseconds)
+            })
+        }
+    }
+}
+
+/// Copies the delimited rows of `reader` to `writer`, converting the cells of `column` (starting at 0)
+///
+/// The first row is copied unchanged if `has_header` is true. Rows may have different numbers of fields.
+///
+/// # Errors
+///
+/// Returns an error if `reader` is not validly delimited, or if `writer` cannot be written to
+pub fn convert_column<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    column: usize,
+    unit: Unit,
+    direction: Direction,
+    delimiter: u8,
+    has_header: bool,
+) -> anyhow::Result<Tally> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .has_headers(false)
+        .from_reader(reader);
+
+    let mut csv_writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_writer(writer);
+
+    let mut tally = Tally::default();
+
+    for (us, re) in csv_reader.byte_records().enumerate() {
+        let byte_record = re?;
+
+        if has_header && us == 0_usize {
+            csv_writer.write_byte_record(&byte_record)?;
+
+            continue;
+        }
+
+        tally.rows += 1_usize;
+
+        let converted = byte_record
+            .get(column)
+            .and_then(|by| std::str::from_utf8(by).ok())
+            .and_then(|st| convert_cell(st, unit, direction));
+
+        if converted.is_some() {
+            tally.converted += 1_usize;
+        } else {
+            tally.failed += 1_usize;
+        }
+
+        csv_writer.write_record(byte_record.iter().enumerate().map(|(usi, by)| {
+            match (&converted, usi == column) {
+                (Some(st), true) => st.as_bytes(),
+                _ => by,
+            }
+        }))?;
+    }
+
+    csv_writer.flush()?;
+
+    Ok(tally)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        column::{convert_cell, convert_column, Direction, Tally},
+        Unit,
+    };
+
+    #[test]
+    fn test_convert_column() -> anyhow::Result<()> {
+        let input = "id,created,note
+1,1700000000,\"quoted, with a comma\"
+2,,empty
+3,soon
+";
+
+        let mut by = Vec::<u8>::new();
+
+        let tally = convert_column(
+            input.as_bytes(),
+            &mut by,
+            1_usize,
+            Unit::Seconds,
+            Direction::ToDates,
+            b',',
+            true,
+        )?;
+
+        anyhow::ensure!(
+            String::from_utf8(by)?
+                == "id,created,note
+1,2023-11-14T22:13:20Z,\"quoted, with a comma\"
+2,,empty
+3,soon
+"
+        );
+        anyhow::ensure!(
+            tally
+                == Tally {
+                    rows: 3_usize,
+                    converted: 1_usize,
+                    failed: 2_usize,
+                }
+        );
+
+        anyhow::ensure!(
+            convert_cell(
+                "2023-11-14T22:13:20.5Z",
+                Unit::Milliseconds,
+                Direction::ToTimestamps
+            )
+            .as_deref()
+                == Some("1700000000500")
+        );
+        anyhow::ensure!(convert_cell("a date", Unit::Seconds, Direction::ToTimestamps).is_none());
+
+        Ok(())
+    }
+}
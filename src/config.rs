@@ -0,0 +1,321 @@
+//! The configuration file, which is TOML
+//!
+//! ```toml
+//! holidays = ["2024-12-25", "2024-12-26"]
+//!
+//! [defaults]
+//! no_errors = true
+//! location = "52.52,13.40"
+//!
+//! [profiles.nginx]
+//! pattern = '\[(?<ts>[0-9]+\.[0-9]+)\]'
+//! unit = "seconds"
+//! zone = "UTC"
+//!
+//! [profiles.boot]
+//! pattern = 'uptime=(?<boot>[0-9]+)'
+//! unit = "ms"
+//!
+//! [snowflakes.myco]
+//! epoch_milliseconds = 1609459200000
+//! bits = "41,10,12"
+//! ```
+
+use crate::{
+    date_time::parse_date,
+    snowflake::{Bits, Layout},
+    sun::Location,
+    Unit, Zone,
+};
+use serde::{Deserialize, Deserializer};
+use std::{collections::BTreeMap, env, fmt::Display, fs, path::PathBuf, str::FromStr};
+use time::Date;
+
+/// The name of the capture group that holds the timestamp in a profile's pattern
+pub const TIMESTAMP_GROUP_NAME: &str = "ts";
+/// The name of the capture group that holds a time since boot in a profile's pattern, which is added to the boot time
+pub const BOOT_GROUP_NAME: &str = "boot";
+
+/// Profiles that do not have to be defined in the configuration file (but can be replaced there): the name, the
+/// pattern, and the unit
+pub const BUILT_IN_PROFILES: [(&str, &str, Unit); 3_usize] = [
+    (
+        "dmesg",
+        // "[12345.678901]", which "dmesg -r" prefixes with the priority (e.g. "<6>")
+        r"^(?:<[0-9]+>)?\[\s*(?<boot>[0-9]+\.[0-9]+)\]",
+        Unit::Seconds,
+    ),
+    (
+        "journald",
+        // The fields of `journalctl -o export` (and `-o verbose`), which are all in microseconds
+        r"^\s*_?_(?:SOURCE_)?REALTIME_TIMESTAMP=(?<ts>[0-9]+)$|^\s*_?_(?:SOURCE_)?MONOTONIC_TIMESTAMP=(?<boot>[0-9]+)$",
+        Unit::Microseconds,
+    ),
+    (
+        "tcpdump",
+        // The time of day tcpdump starts lines with by default, or the Unix time it starts them with given "-tt"
+        r"^(?<ts>[0-9]{2}:[0-9]{2}:[0-9]{2}\.[0-9]+|[0-9]+\.[0-9]{6})\b",
+        Unit::Seconds,
+    ),
+];
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub defaults: Defaults,
+    /// The days `--business-days` does not count, besides weekends
+    #[serde(default, deserialize_with = "deserialize_dates")]
+    pub holidays: Vec<Date>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default)]
+    pub snowflakes: BTreeMap<String, SnowflakePreset>,
+}
+
+/// What flags default to when they are not given on the command line
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Defaults {
+    /// Leave out the candidates that could not be read, like `--no-errors`
+    #[serde(default)]
+    pub no_errors: bool,
+    /// Show whether each instant is in daylight here, like `--location`
+    #[serde(default, deserialize_with = "deserialize_from_str")]
+    pub location: Option<Location>,
+}
+
+/// How to extract timestamps from a well-known log format, so they do not have to be guessed
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Regular expression with a capture group named "ts" around each timestamp, or named "boot" around each time
+    /// since boot
+    pub pattern: String,
+    /// The unit the timestamps are in (the best candidate is used if this is not set)
+    #[serde(default, deserialize_with = "deserialize_from_str")]
+    pub unit: Option<Unit>,
+    /// The time zone the timestamps are displayed in (UTC if this is not set)
+    #[serde(default, deserialize_with = "deserialize_from_str")]
+    pub zone: Option<Zone>,
+}
+
+/// An in-house snowflake variant, which every integer is also tried as
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SnowflakePreset {
+    /// The Unix time in milliseconds the timestamp counts from
+    pub epoch_milliseconds: i64,
+    /// The widths of the timestamp, worker ID, and sequence number (Twitter's "41,10,12" if this is not set)
+    #[serde(default, deserialize_with = "deserialize_from_str")]
+    pub bits: Option<Bits>,
+}
+
+impl SnowflakePreset {
+    #[must_use]
+    pub fn layout(&self) -> Layout {
+        Layout::new(self.epoch_milliseconds, self.bits.unwrap_or_default())
+    }
+}
+
+impl Config {
+    /// Reads the configuration file at `path`, or at the default path if `path` is `None`
+    ///
+    /// The default path is `$XDG_CONFIG_HOME/readtimestamp/config.toml` (or `~/.config/readtimestamp/config.toml`).
+    /// It is not an error for the file at the default path to be missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid configuration
+    pub fn load(path: Option<PathBuf>) -> anyhow::Result<Self> {
+        let path = match path {
+            Some(pa) => pa,
+            None => match default_path() {
+                Some(pa) if pa.exists() => pa,
+                _ => return Ok(Self::default()),
+            },
+        };
+
+        let string = fs::read_to_string(&path)
+            .map_err(|er| anyhow::anyhow!("Could not read \"{}\": {er}", path.display()))?;
+
+        string
+            .parse()
+            .map_err(|er| anyhow::anyhow!("Could not parse \"{}\": {er}", path.display()))
+    }
+
+    /// The profile named `name` in the configuration file, or else the one in [`BUILT_IN_PROFILES`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no profile named `name`
+    pub fn profile(&self, name: &str) -> anyhow::Result<Profile> {
+        if let Some(pr) = self.profiles.get(name) {
+            return Ok(pr.clone());
+        }
+
+        let built_in = BUILT_IN_PROFILES
+            .into_iter()
+            .find(|(st, _, _)| *st == name)
+            .map(|(_, pattern, unit)| Profile {
+                pattern: pattern.to_owned(),
+                unit: Some(unit),
+                zone: None,
+            });
+
+        built_in.ok_or_else(|| {
+            anyhow::anyhow!(
+                "No profile named \"{name}\" (profiles defined: {}; built in: {})",
+                if self.profiles.is_empty() {
+                    "none".to_owned()
+                } else {
+                    self.profiles
+                        .keys()
+                        .map(String::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                },
+                BUILT_IN_PROFILES.map(|(st, _, _)| st).join(", ")
+            )
+        })
+    }
+}
+
+impl FromStr for Config {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let config = toml::from_str::<Self>(st)?;
+
+        for (name, pr) in &config.profiles {
+            let has_group = |group_name: &str| {
+                pr.pattern.contains(&format!("(?<{group_name}>"))
+                    || pr.pattern.contains(&format!("(?P<{group_name}>"))
+            };
+
+            anyhow::ensure!(
+                has_group(TIMESTAMP_GROUP_NAME) || has_group(BOOT_GROUP_NAME),
+                "Pattern of profile \"{name}\" has no capture group named \"{TIMESTAMP_GROUP_NAME}\" or \"{BOOT_GROUP_NAME}\""
+            );
+        }
+
+        Ok(config)
+    }
+}
+
+/// `$XDG_CONFIG_HOME/readtimestamp` (or `~/.config/readtimestamp`), or `None` if the home directory is unknown
+#[must_use]
+pub fn config_directory() -> Option<PathBuf> {
+    let config_directory = match env::var_os("XDG_CONFIG_HOME") {
+        Some(os) if !os.is_empty() => PathBuf::from(os),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(config_directory.join("readtimestamp"))
+}
+
+fn default_path() -> Option<PathBuf> {
+    Some(config_directory()?.join("config.toml"))
+}
+
+fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|st| st.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+fn deserialize_dates<'de, D>(deserializer: D) -> Result<Vec<Date>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|st| {
+            parse_date(&st).ok_or_else(|| {
+                serde::de::Error::custom(format!("\"{st}\" is not a date like \"2024-12-25\""))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::Config, snowflake::Layout, Unit, Zone};
+    use time::macros::date;
+
+    #[test]
+    fn test_config() -> anyhow::Result<()> {
+        let config = r#"
+            [profiles.myapp]
+            pattern = 'at=(?<ts>[0-9]+)'
+            unit = "ms"
+            zone = "+05:30"
+
+            [profiles.nginx]
+            pattern = '\[(?<ts>[0-9.]+)\]'
+        "#
+        .parse::<Config>()?;
+
+        let myapp = config.profile("myapp")?;
+
+        anyhow::ensure!(myapp.unit == Some(Unit::Milliseconds));
+        anyhow::ensure!(myapp.zone == Some("+05:30".parse::<Zone>()?));
+        anyhow::ensure!(config.profile("nginx")?.unit.is_none());
+        anyhow::ensure!(!config.defaults.no_errors);
+        anyhow::ensure!(
+            "[defaults]\nno_errors = true"
+                .parse::<Config>()?
+                .defaults
+                .no_errors
+        );
+        anyhow::ensure!("[defaults]\nlocation = \"52.52,13.40\""
+            .parse::<Config>()?
+            .defaults
+            .location
+            .is_some());
+        anyhow::ensure!(config.holidays.is_empty());
+        anyhow::ensure!(
+            "holidays = [\"2024-12-25\"]".parse::<Config>()?.holidays == [date!(2024 - 12 - 25)]
+        );
+        anyhow::ensure!("holidays = [\"Christmas\"]".parse::<Config>().is_err());
+        anyhow::ensure!(config.profile("missing").is_err());
+        anyhow::ensure!(config.profile("journald")?.unit == Some(Unit::Microseconds));
+        anyhow::ensure!("[profiles.uptime]\npattern = 'up (?P<boot>[0-9]+)'"
+            .parse::<Config>()
+            .is_ok());
+
+        let snowflake_config = r#"
+            [snowflakes.discord]
+            epoch_milliseconds = 1420070400000
+            bits = "42,10,12"
+
+            [snowflakes.twitter]
+            epoch_milliseconds = 1288834974657
+        "#
+        .parse::<Config>()?;
+
+        anyhow::ensure!(snowflake_config.snowflakes["discord"].layout() == Layout::DISCORD);
+        anyhow::ensure!(snowflake_config.snowflakes["twitter"].layout() == Layout::TWITTER);
+        anyhow::ensure!(
+            "[snowflakes.bad]\nepoch_milliseconds = 0\nbits = \"42,10,13\""
+                .parse::<Config>()
+                .is_err()
+        );
+
+        anyhow::ensure!("[profiles.bad]\npattern = '[0-9]+'"
+            .parse::<Config>()
+            .is_err());
+        anyhow::ensure!(
+            "[profiles.bad]\npattern = '(?<ts>[0-9]+)'\nunit = \"weeks\""
+                .parse::<Config>()
+                .is_err()
+        );
+
+        Ok(())
+    }
+}
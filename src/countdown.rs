@@ -0,0 +1,163 @@
+//! A countdown to an instant, redrawn in place on the terminal
+
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+
+/// Where a countdown was at one instant
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct State {
+    pub target: OffsetDateTime,
+    pub now: OffsetDateTime,
+}
+
+/// The JSON written when a countdown is asked to dump its state
+#[derive(Serialize)]
+struct Snapshot {
+    target: String,
+    now: String,
+    remaining_seconds: i64,
+    finished: bool,
+}
+
+impl State {
+    /// Zero once the target has been reached
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        (self.target - self.now).max(Duration::ZERO)
+    }
+
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.now >= self.target
+    }
+
+    /// The line shown on the terminal, e.g. "1d 02:03:04 until 2024-05-01T12:00:00Z"
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the target cannot be formatted
+    pub fn line(&self) -> anyhow::Result<String> {
+        let target = self.target.format(&Rfc3339)?;
+
+        if self.is_finished() {
+            return Ok(format!("Reached {target}"));
+        }
+
+        Ok(format!(
+            "{} until {target}",
+            format_remaining(self.remaining())
+        ))
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if an instant cannot be formatted
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        let snapshot = Snapshot {
+            target: self.target.format(&Rfc3339)?,
+            now: self.now.format(&Rfc3339)?,
+            remaining_seconds: self.remaining().whole_seconds(),
+            finished: self.is_finished(),
+        };
+
+        Ok(serde_json::to_string(&snapshot)?)
+    }
+}
+
+/// The default file a countdown's state is dumped to, `$XDG_STATE_HOME/readtimestamp/countdown.json` (or
+/// `~/.local/state/readtimestamp/countdown.json`)
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined
+pub fn default_state_path() -> anyhow::Result<PathBuf> {
+    Ok(crate::state_directory()?.join("countdown.json"))
+}
+
+/// Writes `state` to `path` as JSON, replacing the file in one step so readers never see a partial write
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written
+pub fn dump(path: &Path, state: &State) -> anyhow::Result<()> {
+    if let Some(pa) = path.parent() {
+        fs::create_dir_all(pa)?;
+    }
+
+    let temporary_path = path.with_extension("tmp");
+
+    fs::write(&temporary_path, state.to_json()? + "\n")?;
+    fs::rename(&temporary_path, path)?;
+
+    Ok(())
+}
+
+/// Whole seconds, with days and hours only when needed, e.g. "4:05", "1:04:05", or "2d 01:04:05"
+#[must_use]
+pub fn format_remaining(duration: Duration) -> String {
+    let seconds = duration.whole_seconds().max(0_i64);
+
+    let days = seconds / 86_400_i64;
+    let hours = seconds % 86_400_i64 / 3_600_i64;
+    let minutes = seconds % 3_600_i64 / 60_i64;
+    let seconds = seconds % 60_i64;
+
+    if days > 0_i64 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else if hours > 0_i64 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+/// Shortens `line` to at most `width` characters, ending it with "…" if anything was cut off
+#[must_use]
+pub fn fit_to_width(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_owned();
+    }
+
+    if width == 0_usize {
+        return String::new();
+    }
+
+    let mut fitted = line.chars().take(width - 1_usize).collect::<String>();
+
+    fitted.push('…');
+
+    fitted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::countdown::{fit_to_width, format_remaining, State};
+    use time::{macros::datetime, Duration};
+
+    #[test]
+    fn test_countdown() -> anyhow::Result<()> {
+        anyhow::ensure!(format_remaining(Duration::seconds(245_i64)) == "4:05");
+        anyhow::ensure!(format_remaining(Duration::seconds(3_845_i64)) == "1:04:05");
+        anyhow::ensure!(format_remaining(Duration::seconds(176_645_i64)) == "2d 01:04:05");
+
+        let state = State {
+            target: datetime!(2024-05-01 12:00 UTC),
+            now: datetime!(2024-05-01 11:58:30 UTC),
+        };
+
+        anyhow::ensure!(state.line()? == "1:30 until 2024-05-01T12:00:00Z");
+        anyhow::ensure!(
+            state.to_json()?
+                == r#"{"target":"2024-05-01T12:00:00Z","now":"2024-05-01T11:58:30Z","remaining_seconds":90,"finished":false}"#
+        );
+
+        anyhow::ensure!(fit_to_width("1:30 until 2024-05-01T12:00:00Z", 10_usize) == "1:30 unti…");
+        anyhow::ensure!(fit_to_width("1:30", 10_usize) == "1:30");
+
+        Ok(())
+    }
+}
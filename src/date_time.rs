@@ -0,0 +1,477 @@
+use crate::Zone;
+use std::collections::BTreeSet;
+use time::{
+    format_description::{
+        well_known::{
+            iso8601::{Config, EncodedConfig},
+            Iso8601, Rfc2822, Rfc3339,
+        },
+        FormatItem,
+    },
+    macros::format_description,
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, Weekday,
+};
+
+const DATE_FORMAT_DESCRIPTION: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+/// ISO 8601 with a sign and six digits for the year, for instants RFC 3339 cannot write
+const EXTENDED_YEAR_CONFIG: EncodedConfig = Config::DEFAULT.set_year_is_six_digits(true).encode();
+const ORDINAL_DATE_FORMAT_DESCRIPTION: &[FormatItem<'_>] = format_description!("[year]-[ordinal]");
+const WEEK_DATE_FORMAT_DESCRIPTION: &[FormatItem<'_>] =
+    format_description!("[year base:iso_week]-W[week_number repr:iso]-[weekday repr:monday]");
+/// With the year put in front, and the day's padding removed
+const SYSLOG_FORMAT_DESCRIPTION: &[FormatItem<'_>] =
+    format_description!("[year] [month repr:short] [day padding:none] [hour]:[minute]:[second]");
+const TIME_OF_DAY_FORMAT_DESCRIPTION: &[FormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]");
+const COMMON_LOG_FORMAT_DESCRIPTION: &[FormatItem<'_>] = format_description!(
+    "[day]/[month repr:short]/[year]:[hour]:[minute]:[second] [offset_hour sign:mandatory][offset_minute]"
+);
+
+/// A textual date and time format that can be recognized in input
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DateTimeFormat {
+    /// The format used by email headers and `date -R`, e.g. "Tue, 14 Nov 2023 22:13:20 +0000"
+    Rfc2822,
+    /// The format of Apache and nginx access logs, e.g. "14/Nov/2023:22:13:20 +0000" (which the logs put in square
+    /// brackets, which are optional here)
+    CommonLog,
+    Rfc3339,
+    Iso8601,
+    /// ISO 8601 without a UTC offset, which is assumed to be UTC
+    Iso8601WithoutOffset,
+    /// A calendar date without a time, which is assumed to be midnight UTC
+    Date,
+    /// An ISO 8601 week date without a time (e.g. "2024-W18-4", the Thursday of week 18), which is assumed to be
+    /// midnight UTC
+    WeekDate,
+    /// An ISO 8601 ordinal date without a time (e.g. "2024-123", the 123rd day of 2024), which is assumed to be
+    /// midnight UTC
+    OrdinalDate,
+}
+
+impl DateTimeFormat {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rfc2822 => "an RFC 2822 date and time",
+            Self::CommonLog => "a Common Log Format date and time",
+            Self::Rfc3339 => "an RFC 3339 date and time",
+            Self::Iso8601 => "an ISO 8601 date and time",
+            Self::Iso8601WithoutOffset => "an ISO 8601 date and time without a UTC offset",
+            Self::Date => "a date without a time",
+            Self::WeekDate => "an ISO 8601 week date without a time",
+            Self::OrdinalDate => "an ISO 8601 ordinal date without a time",
+        }
+    }
+
+    /// Whether a UTC offset had to be assumed because the input did not have one
+    #[must_use]
+    pub const fn assumes_utc(self) -> bool {
+        matches!(
+            self,
+            Self::Iso8601WithoutOffset | Self::Date | Self::WeekDate | Self::OrdinalDate
+        )
+    }
+}
+
+/// Parses `input` if all of it is a date and time in a recognized format
+#[must_use]
+pub fn parse_date_time(input: &str) -> Option<(DateTimeFormat, OffsetDateTime)> {
+    if let Ok(of) = OffsetDateTime::parse(input, &Rfc2822) {
+        return Some((DateTimeFormat::Rfc2822, of));
+    }
+
+    let unbracketed = input
+        .strip_prefix('[')
+        .and_then(|st| st.strip_suffix(']'))
+        .unwrap_or(input);
+
+    if let Ok(of) = OffsetDateTime::parse(unbracketed, COMMON_LOG_FORMAT_DESCRIPTION) {
+        return Some((DateTimeFormat::CommonLog, of));
+    }
+
+    // Every other supported format has a date separated with "-", which also keeps plain (possibly negative) numbers
+    // from being treated as ISO 8601 basic format dates
+    if !input.trim_start_matches('-').contains('-') {
+        return None;
+    }
+
+    if let Ok(of) = OffsetDateTime::parse(input, &Rfc3339) {
+        return Some((DateTimeFormat::Rfc3339, of));
+    }
+
+    if let Ok(of) = OffsetDateTime::parse(input, &Iso8601::DEFAULT) {
+        return Some((DateTimeFormat::Iso8601, of));
+    }
+
+    if let Ok(pr) = PrimitiveDateTime::parse(input, &Iso8601::DEFAULT) {
+        return Some((DateTimeFormat::Iso8601WithoutOffset, pr.assume_utc()));
+    }
+
+    if let Ok(da) = Date::parse(input, DATE_FORMAT_DESCRIPTION) {
+        return Some((DateTimeFormat::Date, da.midnight().assume_utc()));
+    }
+
+    if let Ok(da) = Date::parse(input, WEEK_DATE_FORMAT_DESCRIPTION) {
+        return Some((DateTimeFormat::WeekDate, da.midnight().assume_utc()));
+    }
+
+    if let Ok(da) = Date::parse(input, ORDINAL_DATE_FORMAT_DESCRIPTION) {
+        return Some((DateTimeFormat::OrdinalDate, da.midnight().assume_utc()));
+    }
+
+    None
+}
+
+/// Parses a BSD syslog (RFC 3164) date and time, e.g. "Nov 14 22:13:20" (or "Nov  4 22:13:20"), which has neither a
+/// year nor a UTC offset
+///
+/// The wall clock time is read in `zone`, and the year is whichever one puts the instant closest to `now` (so "Dec 31"
+/// read in early January is in the year before)
+#[must_use]
+pub fn parse_syslog(input: &str, now: OffsetDateTime, zone: Zone) -> Option<OffsetDateTime> {
+    let normalized = input.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    [now.year() - 1_i32, now.year(), now.year() + 1_i32]
+        .into_iter()
+        .filter_map(|it| {
+            let primitive_date_time =
+                PrimitiveDateTime::parse(&format!("{it} {normalized}"), SYSLOG_FORMAT_DESCRIPTION)
+                    .ok()?;
+
+            zone.resolve(primitive_date_time).ok()
+        })
+        .min_by_key(|of| (*of - now).abs())
+}
+
+/// Parses a calendar date like "2024-12-25"
+#[must_use]
+pub fn parse_date(input: &str) -> Option<Date> {
+    Date::parse(input, DATE_FORMAT_DESCRIPTION).ok()
+}
+
+/// Parses a length of time like "90", "15m", or "1h30m": whole numbers, each followed by a unit ("ns", "us", "ms",
+/// "s", "m" or "min", "h", "d", or "w"), which can be left out of a lone number to mean seconds
+///
+/// # Errors
+///
+/// Returns an error if `input` is not a length of time, or is too long to represent
+pub fn parse_duration(input: &str) -> anyhow::Result<Duration> {
+    if let Ok(it) = input.parse::<i64>() {
+        return Ok(Duration::seconds(it));
+    }
+
+    let invalid = || {
+        anyhow::anyhow!("\"{input}\" is not a length of time (e.g. \"90\", \"15m\", or \"1h30m\")")
+    };
+
+    let mut duration = Duration::ZERO;
+
+    let mut rest = input.trim();
+
+    anyhow::ensure!(!rest.is_empty(), invalid());
+
+    while !rest.is_empty() {
+        let digits_end = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let unit_end = rest[digits_end..]
+            .find(|ch: char| ch.is_ascii_digit())
+            .map_or(rest.len(), |us| digits_end + us);
+
+        let count = rest[..digits_end].parse::<i64>().map_err(|_| invalid())?;
+
+        let scale = match &rest[digits_end..unit_end] {
+            "ns" => Duration::NANOSECOND,
+            "us" | "µs" => Duration::MICROSECOND,
+            "ms" => Duration::MILLISECOND,
+            "s" => Duration::SECOND,
+            "m" | "min" => Duration::MINUTE,
+            "h" => Duration::HOUR,
+            "d" => Duration::DAY,
+            "w" => Duration::WEEK,
+            _ => return Err(invalid()),
+        };
+
+        duration = i32::try_from(count)
+            .ok()
+            .and_then(|it| scale.checked_mul(it))
+            .and_then(|du| duration.checked_add(du))
+            .ok_or_else(|| anyhow::anyhow!("\"{input}\" is too long"))?;
+
+        rest = &rest[unit_end..];
+    }
+
+    Ok(duration)
+}
+
+/// Parses a wall clock time of day like the ones tcpdump prints, e.g. "22:13:20.123456" (the fraction is optional)
+#[must_use]
+pub fn parse_time_of_day(input: &str) -> Option<Time> {
+    Time::parse(input, TIME_OF_DAY_FORMAT_DESCRIPTION).ok()
+}
+
+/// `offset_date_time` as RFC 3339, or, if its year is negative or has more than four digits, as ISO 8601 with a signed
+/// six-digit year (e.g. "-001199-02-15T14:13:20.000000000Z")
+#[must_use]
+pub fn format_rfc3339(offset_date_time: OffsetDateTime) -> String {
+    offset_date_time
+        .format(&Rfc3339)
+        .or_else(|_| offset_date_time.format(&Iso8601::<EXTENDED_YEAR_CONFIG>))
+        .unwrap_or_else(|_| offset_date_time.to_string())
+}
+
+/// `date` as an ISO 8601 week date and as an ordinal date, e.g. "2024-W18-3" and "2024-122" for 2024-05-01
+///
+/// # Errors
+///
+/// Returns an error if `date` cannot be formatted
+pub fn format_calendar_info(date: Date) -> anyhow::Result<(String, String)> {
+    Ok((
+        date.format(WEEK_DATE_FORMAT_DESCRIPTION)?,
+        date.format(ORDINAL_DATE_FORMAT_DESCRIPTION)?,
+    ))
+}
+
+/// The fiscal year and quarter (1 through 4) that `date` falls in, for fiscal years that start on the first of `start`
+///
+/// Fiscal years are named for the calendar year they end in, so with fiscal years starting in April, 2023-11-14 is in
+/// Q3 of FY2024. With fiscal years starting in January, this is the calendar year and quarter.
+#[must_use]
+pub fn fiscal_quarter(date: Date, start: Month) -> (i32, u8) {
+    let month = u8::from(date.month());
+    let start = u8::from(start);
+
+    let months_into_year = (month + 12_u8 - start) % 12_u8;
+
+    let year = if start == 1_u8 || month < start {
+        date.year()
+    } else {
+        date.year() + 1_i32
+    };
+
+    (year, months_into_year / 3_u8 + 1_u8)
+}
+
+/// How many business days (Monday through Friday, except `holidays`) there are after `from`, up to and including `to`,
+/// or the negative of how many there are after `to`, up to and including `from`, if `to` is earlier
+///
+/// So a deadline 1 business day after a Friday is the following Monday.
+#[must_use]
+pub fn business_days_between(from: Date, to: Date, holidays: &[Date]) -> i64 {
+    let (earlier, later, sign) = if from <= to {
+        (from, to, 1_i64)
+    } else {
+        (to, from, -1_i64)
+    };
+
+    let holiday_count = holidays
+        .iter()
+        .filter(|da| is_weekday(**da) && earlier < **da && **da <= later)
+        .collect::<BTreeSet<_>>()
+        .len();
+
+    #[expect(clippy::cast_possible_wrap, reason = "Unimportant")]
+    let business_days = weekdays_through(later) - weekdays_through(earlier) - holiday_count as i64;
+
+    sign * business_days
+}
+
+fn is_weekday(date: Date) -> bool {
+    !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+}
+
+/// How many weekdays there are up to and including `date`, counted from an arbitrary Monday
+fn weekdays_through(date: Date) -> i64 {
+    let days_from_monday = i64::from(date.weekday().number_days_from_monday());
+
+    let monday = i64::from(date.to_julian_day()) - days_from_monday;
+
+    5_i64 * monday.div_euclid(7_i64) + (days_from_monday + 1_i64).min(5_i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        date_time::{
+            business_days_between, fiscal_quarter, format_calendar_info, parse_date_time,
+            parse_duration, parse_syslog, parse_time_of_day, DateTimeFormat,
+        },
+        Zone,
+    };
+    use time::{
+        macros::{date, datetime, time},
+        Duration, Month,
+    };
+
+    #[test]
+    fn test_parse_date_time() -> anyhow::Result<()> {
+        anyhow::ensure!(
+            parse_date_time("2024-05-01T12:00:00Z")
+                == Some((DateTimeFormat::Rfc3339, datetime!(2024-05-01 12:00 UTC)))
+        );
+        anyhow::ensure!(
+            parse_date_time("2024-05-01T14:00:00.5+02:00").map(|(_, of)| of)
+                == Some(datetime!(2024-05-01 12:00:00.5 UTC))
+        );
+        anyhow::ensure!(
+            parse_date_time("2024-05-01T12:00")
+                == Some((
+                    DateTimeFormat::Iso8601WithoutOffset,
+                    datetime!(2024-05-01 12:00 UTC)
+                ))
+        );
+        anyhow::ensure!(
+            parse_date_time("2024-05-01")
+                == Some((DateTimeFormat::Date, datetime!(2024-05-01 0:00 UTC)))
+        );
+        anyhow::ensure!(
+            parse_date_time("Tue, 14 Nov 2023 22:13:20 +0000")
+                == Some((DateTimeFormat::Rfc2822, datetime!(2023-11-14 22:13:20 UTC)))
+        );
+        anyhow::ensure!(
+            parse_date_time("14 Nov 2023 17:13:20 -0500").map(|(_, of)| of)
+                == Some(datetime!(2023-11-14 22:13:20 UTC))
+        );
+        anyhow::ensure!(
+            parse_date_time("[14/Nov/2023:23:13:20 +0100]")
+                == Some((
+                    DateTimeFormat::CommonLog,
+                    datetime!(2023-11-14 22:13:20 UTC)
+                ))
+        );
+        anyhow::ensure!(
+            parse_date_time("14/Nov/2023:22:13:20 -0000").map(|(_, of)| of)
+                == Some(datetime!(2023-11-14 22:13:20 UTC))
+        );
+        anyhow::ensure!(
+            parse_date_time("2024-W18-4")
+                == Some((DateTimeFormat::WeekDate, datetime!(2024-05-02 0:00 UTC)))
+        );
+        // The first ISO week of 2025 starts in 2024
+        anyhow::ensure!(
+            parse_date_time("2025-W01-1").map(|(_, of)| of) == Some(datetime!(2024-12-30 0:00 UTC))
+        );
+        anyhow::ensure!(
+            parse_date_time("2024-123")
+                == Some((DateTimeFormat::OrdinalDate, datetime!(2024-05-02 0:00 UTC)))
+        );
+        anyhow::ensure!(parse_date_time("2024-W54-1").is_none());
+        anyhow::ensure!(parse_date_time("2023-366").is_none());
+        anyhow::ensure!(parse_date_time("1704772140").is_none());
+        anyhow::ensure!(parse_date_time("-86400").is_none());
+        anyhow::ensure!(parse_date_time("./my-saved-file-1704772140").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_syslog() -> anyhow::Result<()> {
+        let now = datetime!(2023-11-20 0:00 UTC);
+
+        anyhow::ensure!(
+            parse_syslog("Nov 14 22:13:20", now, Zone::Utc)
+                == Some(datetime!(2023-11-14 22:13:20 UTC))
+        );
+        anyhow::ensure!(
+            parse_syslog("Nov  4 22:13:20", now, "+01:00".parse::<Zone>()?)
+                == Some(datetime!(2023-11-04 21:13:20 UTC))
+        );
+        // Closer to now in the next year than in this one
+        anyhow::ensure!(
+            parse_syslog("Jan  2 00:00:00", datetime!(2023-12-31 0:00 UTC), Zone::Utc)
+                == Some(datetime!(2024-01-02 0:00 UTC))
+        );
+        // There was no February 29 in 2023, but there was one in 2024
+        anyhow::ensure!(
+            parse_syslog("Feb 29 12:00:00", now, Zone::Utc)
+                == Some(datetime!(2024-02-29 12:00 UTC))
+        );
+        anyhow::ensure!(parse_syslog("Nov 31 22:13:20", now, Zone::Utc).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_time_of_day() -> anyhow::Result<()> {
+        anyhow::ensure!(parse_time_of_day("22:13:20.123456") == Some(time!(22:13:20.123456)));
+        anyhow::ensure!(parse_time_of_day("22:13:20") == Some(time!(22:13:20)));
+        anyhow::ensure!(parse_time_of_day("24:00:00").is_none());
+        anyhow::ensure!(parse_time_of_day("22:13").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_calendar_info() -> anyhow::Result<()> {
+        anyhow::ensure!(
+            format_calendar_info(date!(2024 - 05 - 01))?
+                == ("2024-W18-3".to_owned(), "2024-122".to_owned())
+        );
+        anyhow::ensure!(
+            format_calendar_info(date!(2024 - 12 - 30))?
+                == ("2025-W01-1".to_owned(), "2024-365".to_owned())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fiscal_quarter() -> anyhow::Result<()> {
+        anyhow::ensure!(fiscal_quarter(date!(2023 - 11 - 14), Month::January) == (2023_i32, 4_u8));
+        anyhow::ensure!(fiscal_quarter(date!(2023 - 11 - 14), Month::April) == (2024_i32, 3_u8));
+        anyhow::ensure!(fiscal_quarter(date!(2024 - 03 - 31), Month::April) == (2024_i32, 4_u8));
+        anyhow::ensure!(fiscal_quarter(date!(2024 - 04 - 01), Month::April) == (2025_i32, 1_u8));
+        // The US federal government's
+        anyhow::ensure!(fiscal_quarter(date!(2023 - 10 - 01), Month::October) == (2024_i32, 1_u8));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_duration() -> anyhow::Result<()> {
+        anyhow::ensure!(parse_duration("90")? == Duration::seconds(90_i64));
+        anyhow::ensure!(parse_duration("15m")? == Duration::minutes(15_i64));
+        anyhow::ensure!(parse_duration("1h30m")? == Duration::minutes(90_i64));
+        anyhow::ensure!(parse_duration("2d12h")? == Duration::hours(60_i64));
+        anyhow::ensure!(parse_duration("500ms")? == Duration::milliseconds(500_i64));
+        anyhow::ensure!(parse_duration("1w")? == Duration::WEEK);
+
+        anyhow::ensure!(parse_duration("").is_err());
+        anyhow::ensure!(parse_duration("h").is_err());
+        anyhow::ensure!(parse_duration("15").is_ok());
+        anyhow::ensure!(parse_duration("15 minutes").is_err());
+        anyhow::ensure!(parse_duration("1h30").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_days_between() -> anyhow::Result<()> {
+        // A Friday, the following Monday, and the Friday after that
+        let friday = date!(2024 - 12 - 20);
+        let monday = date!(2024 - 12 - 23);
+        let next_friday = date!(2024 - 12 - 27);
+
+        anyhow::ensure!(business_days_between(friday, friday, &[]) == 0_i64);
+        anyhow::ensure!(business_days_between(friday, monday, &[]) == 1_i64);
+        anyhow::ensure!(business_days_between(friday, date!(2024 - 12 - 22), &[]) == 0_i64);
+        anyhow::ensure!(business_days_between(friday, next_friday, &[]) == 5_i64);
+        anyhow::ensure!(business_days_between(next_friday, friday, &[]) == -5_i64);
+        anyhow::ensure!(business_days_between(friday, date!(2025 - 01 - 03), &[]) == 10_i64);
+
+        // Christmas and Boxing Day, listed twice, and a holiday on a Saturday, which is not a business day anyway
+        let holidays = [
+            date!(2024 - 12 - 25),
+            date!(2024 - 12 - 26),
+            date!(2024 - 12 - 25),
+            date!(2024 - 12 - 28),
+        ];
+
+        anyhow::ensure!(business_days_between(friday, next_friday, &holidays) == 3_i64);
+        anyhow::ensure!(business_days_between(next_friday, friday, &holidays) == -3_i64);
+        anyhow::ensure!(business_days_between(monday, date!(2024 - 12 - 24), &holidays) == 1_i64);
+
+        Ok(())
+    }
+}
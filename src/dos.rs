@@ -0,0 +1,124 @@
+//! MS-DOS packed dates and times, as stored by FAT file systems and ZIP archives
+//!
+//! The date is the high 16 bits (7 bits of years since 1980, 4 of month, and 5 of day) and the time the low 16 (5 bits
+//! of hour, 6 of minute, and 5 of seconds divided by 2). There is no time zone, so they are read as UTC.
+
+use crate::interpret::CandidateError;
+use time::{macros::datetime, Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// The earliest instant that can be packed
+pub const MINIMUM: OffsetDateTime = datetime!(1980-01-01 0:00 UTC);
+/// The latest instant that can be packed
+pub const MAXIMUM: OffsetDateTime = datetime!(2107-12-31 23:59:58 UTC);
+
+/// The fields of a packed date and time, as stored
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fields {
+    pub date: u16,
+    pub time: u16,
+    /// Since 1980
+    pub year: u16,
+    pub month: u16,
+    pub day: u16,
+    pub hour: u16,
+    pub minute: u16,
+    /// Half of the second
+    pub half_second: u16,
+}
+
+impl Fields {
+    #[must_use]
+    pub const fn from_packed(packed: u32) -> Self {
+        let date = (packed >> 16_u32) as u16;
+        let time = (packed & 0xFFFF_u32) as u16;
+
+        Self {
+            date,
+            time,
+            year: date >> 9_u32,
+            month: (date >> 5_u32) & 0xF_u16,
+            day: date & 0x1F_u16,
+            hour: time >> 11_u32,
+            minute: (time >> 5_u32) & 0x3F_u16,
+            half_second: time & 0x1F_u16,
+        }
+    }
+}
+
+/// Unpacks `packed`, which has to fit in 32 bits and have a valid date and time
+///
+/// # Errors
+///
+/// Returns [`CandidateError::TooLarge`] if `packed` does not fit in 32 bits, and [`CandidateError::OutOfRange`] if a
+/// field is out of range (e.g. month 0, which is what a packed value of 0 has)
+pub fn decode(packed: i128) -> Result<OffsetDateTime, CandidateError> {
+    let packed = u32::try_from(packed).map_err(|_| CandidateError::TooLarge)?;
+
+    let fields = Fields::from_packed(packed);
+
+    let to_u8 = |field: u16| u8::try_from(field).unwrap_or(u8::MAX);
+
+    let date = Month::try_from(to_u8(fields.month))
+        .and_then(|mo| {
+            Date::from_calendar_date(1980_i32 + i32::from(fields.year), mo, to_u8(fields.day))
+        })
+        .map_err(CandidateError::OutOfRange)?;
+
+    let time = Time::from_hms(
+        to_u8(fields.hour),
+        to_u8(fields.minute),
+        to_u8(fields.half_second * 2_u16),
+    )
+    .map_err(CandidateError::OutOfRange)?;
+
+    Ok(PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+/// Packs `offset_date_time` (in UTC), rounding the second down to an even one, and clamping it to the range that can be
+/// packed
+#[must_use]
+pub fn encode(offset_date_time: OffsetDateTime) -> u32 {
+    let of = offset_date_time
+        .to_offset(time::UtcOffset::UTC)
+        .clamp(MINIMUM, MAXIMUM);
+
+    // Cannot be negative or more than 127 once clamped
+    let year = u32::try_from(of.year() - 1980_i32).unwrap_or_default();
+
+    let date = (year << 9_u32) | (u32::from(u8::from(of.month())) << 5_u32) | u32::from(of.day());
+    let time = (u32::from(of.hour()) << 11_u32)
+        | (u32::from(of.minute()) << 5_u32)
+        | u32::from(of.second() / 2_u8);
+
+    (date << 16_u32) | time
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dos::{decode, encode, MAXIMUM, MINIMUM},
+        interpret::CandidateError,
+    };
+    use time::macros::datetime;
+
+    #[test]
+    fn test_dos() -> anyhow::Result<()> {
+        // 0x5771 is 2023-11-17, and 0xA3A1 is 20:29:02
+        anyhow::ensure!(decode(0x5771_A3A1_i128) == Ok(datetime!(2023-11-17 20:29:02 UTC)));
+        anyhow::ensure!(encode(datetime!(2023-11-17 20:29:03 UTC)) == 0x5771_A3A1_u32);
+
+        anyhow::ensure!(decode(0x0021_0000_i128) == Ok(MINIMUM));
+        anyhow::ensure!(decode(i128::from(encode(MAXIMUM))) == Ok(MAXIMUM));
+        anyhow::ensure!(encode(datetime!(1970-01-01 0:00 UTC)) == encode(MINIMUM));
+
+        anyhow::ensure!(matches!(decode(0_i128), Err(CandidateError::OutOfRange(_))));
+        // 31 half seconds
+        anyhow::ensure!(matches!(
+            decode(0x0021_001F_i128),
+            Err(CandidateError::OutOfRange(_))
+        ));
+        anyhow::ensure!(decode(0x1_0000_0000_i128) == Err(CandidateError::TooLarge));
+
+        Ok(())
+    }
+}
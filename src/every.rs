@@ -0,0 +1,216 @@
+use crate::zone::Zone;
+use std::str::FromStr;
+use time::{Duration, OffsetDateTime, Time, Weekday};
+
+/// Which days a schedule fires on
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Recurrence {
+    Daily,
+    /// Monday through Friday
+    Weekdays,
+    Weekly(Weekday),
+    /// On this day of every month (months that are too short are skipped)
+    Monthly(u8),
+}
+
+impl Recurrence {
+    fn matches(self, date: time::Date) -> bool {
+        match self {
+            Self::Daily => true,
+            Self::Weekdays => date.weekday().number_days_from_monday() < 5_u8,
+            Self::Weekly(we) => date.weekday() == we,
+            Self::Monthly(da) => date.day() == da,
+        }
+    }
+}
+
+/// A simple recurrence phrase like "tuesday 09:00", "weekdays 8:30am", "daily 17:00", or "15th 12:00"
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Schedule {
+    pub recurrence: Recurrence,
+    pub time: Time,
+}
+
+impl Schedule {
+    /// The next `count` instants after `after` that this schedule fires at, with wall clock times resolved in `zone`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a date cannot be represented or resolved in `zone`
+    pub fn upcoming(
+        self,
+        after: OffsetDateTime,
+        zone: Zone,
+        count: usize,
+    ) -> anyhow::Result<Vec<OffsetDateTime>> {
+        // Even a monthly schedule on the 31st fires at least every two months
+        const MAXIMUM_DAYS_BETWEEN: usize = 62_usize;
+
+        let mut date = after.to_offset(zone.offset_at(after)?).date();
+
+        let mut instant_vec = Vec::<OffsetDateTime>::with_capacity(count);
+
+        let mut days_since_last = 0_usize;
+
+        while instant_vec.len() < count {
+            anyhow::ensure!(
+                days_since_last <= MAXIMUM_DAYS_BETWEEN,
+                "Schedule never fires"
+            );
+
+            if self.recurrence.matches(date) {
+                let instant = zone.resolve(date.with_time(self.time))?;
+
+                if instant > after {
+                    instant_vec.push(instant);
+
+                    days_since_last = 0_usize;
+                }
+            }
+
+            date = date
+                .checked_add(Duration::DAY)
+                .ok_or_else(|| anyhow::anyhow!("Date is out of range"))?;
+
+            days_since_last += 1_usize;
+        }
+
+        Ok(instant_vec)
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let lowercase = st.to_lowercase();
+
+        let mut word_iter = lowercase.split_whitespace().peekable();
+
+        if word_iter.peek() == Some(&"every") {
+            word_iter.next();
+        }
+
+        let recurrence_word = word_iter
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Schedule \"{st}\" is empty"))?;
+
+        let recurrence = parse_recurrence(recurrence_word).ok_or_else(|| {
+            anyhow::anyhow!("Unrecognized recurrence \"{recurrence_word}\" (expected a day name, \"daily\", \"weekdays\", or a day of the month like \"15th\")")
+        })?;
+
+        let time = match word_iter.next() {
+            Some(time_word) => parse_time(time_word)?,
+            None => Time::MIDNIGHT,
+        };
+
+        if let Some(extra_word) = word_iter.next() {
+            anyhow::bail!("Unexpected \"{extra_word}\" in schedule \"{st}\"");
+        }
+
+        Ok(Self { recurrence, time })
+    }
+}
+
+fn parse_recurrence(word: &str) -> Option<Recurrence> {
+    let recurrence = match word {
+        "day" | "daily" => Recurrence::Daily,
+        "weekday" | "weekdays" => Recurrence::Weekdays,
+        "monday" | "mon" | "mondays" => Recurrence::Weekly(Weekday::Monday),
+        "tuesday" | "tue" | "tuesdays" => Recurrence::Weekly(Weekday::Tuesday),
+        "wednesday" | "wed" | "wednesdays" => Recurrence::Weekly(Weekday::Wednesday),
+        "thursday" | "thu" | "thursdays" => Recurrence::Weekly(Weekday::Thursday),
+        "friday" | "fri" | "fridays" => Recurrence::Weekly(Weekday::Friday),
+        "saturday" | "sat" | "saturdays" => Recurrence::Weekly(Weekday::Saturday),
+        "sunday" | "sun" | "sundays" => Recurrence::Weekly(Weekday::Sunday),
+        _ => {
+            let digits = word.trim_end_matches(|ch: char| ch.is_ascii_alphabetic());
+
+            let day = digits.parse::<u8>().ok()?;
+
+            if !(1_u8..=31_u8).contains(&day) {
+                return None;
+            }
+
+            Recurrence::Monthly(day)
+        }
+    };
+
+    Some(recurrence)
+}
+
+/// Parses "09:00", "17:30:15", "9am", or "8:30pm"
+fn parse_time(word: &str) -> anyhow::Result<Time> {
+    let (clock, pm) = if let Some(st) = word.strip_suffix("am") {
+        (st, Some(false))
+    } else if let Some(st) = word.strip_suffix("pm") {
+        (st, Some(true))
+    } else {
+        (word, None)
+    };
+
+    let mut part_iter = clock.split(':');
+
+    let mut hour = part_iter.next().unwrap_or_default().parse::<u8>()?;
+    let minute = part_iter.next().map_or(Ok(0_u8), str::parse::<u8>)?;
+    let second = part_iter.next().map_or(Ok(0_u8), str::parse::<u8>)?;
+
+    anyhow::ensure!(part_iter.next().is_none(), "Unrecognized time \"{word}\"");
+
+    if let Some(bo) = pm {
+        anyhow::ensure!(
+            (1_u8..=12_u8).contains(&hour),
+            "Hour {hour} is not valid with AM/PM"
+        );
+
+        hour = match (hour, bo) {
+            (12_u8, false) => 0_u8,
+            (12_u8, true) => 12_u8,
+            (_, true) => hour + 12_u8,
+            (_, false) => hour,
+        };
+    }
+
+    Ok(Time::from_hms(hour, minute, second)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        every::{Recurrence, Schedule},
+        zone::Zone,
+    };
+    use time::{
+        macros::{datetime, time},
+        Weekday,
+    };
+
+    #[test]
+    fn test_schedule() -> anyhow::Result<()> {
+        let schedule = "every Tuesday 9:30pm".parse::<Schedule>()?;
+
+        anyhow::ensure!(schedule.recurrence == Recurrence::Weekly(Weekday::Tuesday));
+        anyhow::ensure!(schedule.time == time!(21:30));
+
+        // 2024-05-01 was a Wednesday
+        let instant_vec = schedule.upcoming(datetime!(2024-05-01 12:00 UTC), Zone::Utc, 2_usize)?;
+
+        anyhow::ensure!(
+            instant_vec
+                == [
+                    datetime!(2024-05-07 21:30 UTC),
+                    datetime!(2024-05-14 21:30 UTC)
+                ]
+        );
+
+        let monthly = "31st 09:00".parse::<Schedule>()?;
+
+        let instant_vec = monthly.upcoming(datetime!(2024-05-31 12:00 UTC), Zone::Utc, 1_usize)?;
+
+        anyhow::ensure!(instant_vec == [datetime!(2024-07-31 09:00 UTC)]);
+
+        anyhow::ensure!("fortnightly 09:00".parse::<Schedule>().is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! A C-compatible interface to the interpretation engine, so editor plugins and programs not written in Rust can embed
+//! it instead of running the command line program
+//!
+//! Every string returned is JSON, owned by this library, and has to be given back to [`readtimestamp_free`].
+
+use crate::{interpret::Settings, output};
+use serde_json::{json, Value};
+use std::{
+    ffi::{c_char, CStr, CString},
+    ptr,
+};
+
+/// Every readable candidate for `input`, as `{"input": ..., "candidates": [...]}` (with the same fields as
+/// `--output csv`, best first), or `{"error": ...}` if `input` is neither a timestamp nor a date and time
+///
+/// Returns null only if `input` is null.
+///
+/// # Safety
+///
+/// `input` has to be null or point to a NUL-terminated string that stays valid for the duration of the call
+#[no_mangle]
+pub unsafe extern "C" fn readtimestamp_interpret(input: *const c_char) -> *mut c_char {
+    if input.is_null() {
+        return ptr::null_mut();
+    }
+
+    // SAFETY: the caller promises `input` is a valid NUL-terminated string
+    let input = unsafe { CStr::from_ptr(input) }.to_string_lossy();
+
+    let value = interpret_json(input.trim());
+
+    // JSON never contains a NUL byte, since serde_json escapes control characters
+    CString::new(value.to_string()).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Frees a string returned by [`readtimestamp_interpret`] (doing nothing if it is null)
+///
+/// # Safety
+///
+/// `json` has to be null or have been returned by [`readtimestamp_interpret`], and not have been freed already
+#[no_mangle]
+pub unsafe extern "C" fn readtimestamp_free(json: *mut c_char) {
+    if !json.is_null() {
+        // SAFETY: the caller promises `json` came from `CString::into_raw` and is only freed once
+        drop(unsafe { CString::from_raw(json) });
+    }
+}
+
+fn interpret_json(input: &str) -> Value {
+    match output::interpret_records(input, &Settings::default()) {
+        Some(records) => json!({ "input": input, "candidates": records }),
+        None => {
+            json!({ "error": format!("\"{input}\" is not a timestamp or a date and time") })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::{readtimestamp_free, readtimestamp_interpret};
+    use serde_json::Value;
+    use std::{
+        ffi::{CStr, CString},
+        ptr,
+    };
+
+    #[test]
+    fn test_readtimestamp_interpret() -> anyhow::Result<()> {
+        let input = CString::new("1700000000")?;
+
+        let json = unsafe { readtimestamp_interpret(input.as_ptr()) };
+
+        let value = serde_json::from_str::<Value>(unsafe { CStr::from_ptr(json) }.to_str()?)?;
+
+        unsafe { readtimestamp_free(json) };
+
+        anyhow::ensure!(value["candidates"][0_usize]["utc"] == "2023-11-14T22:13:20Z");
+
+        let input = CString::new("soon")?;
+
+        let json = unsafe { readtimestamp_interpret(input.as_ptr()) };
+
+        anyhow::ensure!(unsafe { CStr::from_ptr(json) }
+            .to_str()?
+            .starts_with(r#"{"error":"#));
+
+        unsafe { readtimestamp_free(json) };
+
+        anyhow::ensure!(unsafe { readtimestamp_interpret(ptr::null()) }.is_null());
+
+        Ok(())
+    }
+}
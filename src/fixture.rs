@@ -0,0 +1,173 @@
+use crate::Unit;
+use serde_json::{Map, Value};
+use std::str::FromStr;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// How a generated timestamp field is written
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldFormat {
+    /// An RFC 3339 string like "2024-05-01T12:00:00Z"
+    Iso,
+    /// An integer epoch in a unit
+    Epoch(Unit),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub format: FieldFormat,
+}
+
+/// The fields of each generated object, e.g. `created_at:iso,updated_at:ms`
+///
+/// Every field is at or after the field before it, so `created_at` always precedes `updated_at`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Shape(pub Vec<Field>);
+
+impl FromStr for Shape {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let field_vec = st
+            .split(',')
+            .map(|st| {
+                let (name, format) = st.split_once(':').unwrap_or((st, "iso"));
+
+                let name = name.trim();
+
+                anyhow::ensure!(!name.is_empty(), "Field name in \"{st}\" is empty");
+
+                let format = match format.trim() {
+                    "iso" => FieldFormat::Iso,
+                    "s" => FieldFormat::Epoch(Unit::Seconds),
+                    "ms" => FieldFormat::Epoch(Unit::Milliseconds),
+                    "us" => FieldFormat::Epoch(Unit::Microseconds),
+                    "ns" => FieldFormat::Epoch(Unit::Nanoseconds),
+                    other => anyhow::bail!(
+                        "Unrecognized field format \"{other}\" (expected \"iso\", \"s\", \"ms\", \"us\", or \"ns\")"
+                    ),
+                };
+
+                Ok(Field {
+                    name: name.to_owned(),
+                    format,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self(field_vec))
+    }
+}
+
+/// Generates `count` objects with the fields in `shape`, all between `start` and `end`
+///
+/// The same `seed` always produces the same objects
+///
+/// # Errors
+///
+/// Returns an error if `start` is after `end`, or if a timestamp cannot be formatted
+pub fn generate(
+    shape: &Shape,
+    count: usize,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    seed: u64,
+) -> anyhow::Result<Value> {
+    anyhow::ensure!(start <= end, "Start of the range is after the end");
+
+    let start_nanos = start.unix_timestamp_nanos();
+    let end_nanos = end.unix_timestamp_nanos();
+
+    let mut split_mix = SplitMix64(seed);
+
+    let mut value_vec = Vec::<Value>::with_capacity(count);
+
+    for _ in 0_usize..count {
+        let mut map = Map::new();
+
+        let mut previous_nanos = start_nanos;
+
+        for fi in &shape.0 {
+            let nanos = split_mix.between(previous_nanos, end_nanos);
+
+            // Real APIs rarely have sub-millisecond precision
+            let nanos = nanos - nanos.rem_euclid(1_000_000_i128);
+
+            let value = match fi.format {
+                FieldFormat::Iso => Value::String(
+                    OffsetDateTime::from_unix_timestamp_nanos(nanos)?.format(&Rfc3339)?,
+                ),
+                FieldFormat::Epoch(un) => {
+                    let epoch = un.from_unix_nanoseconds(nanos);
+
+                    Value::Number(i64::try_from(epoch)?.into())
+                }
+            };
+
+            map.insert(fi.name.clone(), value);
+
+            previous_nanos = nanos;
+        }
+
+        value_vec.push(Value::Object(map));
+    }
+
+    Ok(Value::Array(value_vec))
+}
+
+/// A small, fast, deterministic pseudorandom number generator (not suitable for anything security related)
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15_u64);
+
+        let mut bits = self.0;
+
+        bits = (bits ^ (bits >> 30_u32)).wrapping_mul(0xBF58_476D_1CE4_E5B9_u64);
+        bits = (bits ^ (bits >> 27_u32)).wrapping_mul(0x94D0_49BB_1331_11EB_u64);
+
+        bits ^ (bits >> 31_u32)
+    }
+
+    /// A value in `low..=high`
+    fn between(&mut self, low: i128, high: i128) -> i128 {
+        let span = u128::try_from(high - low).unwrap_or_default();
+
+        let random = (u128::from(self.next()) << 64_u32) | u128::from(self.next());
+
+        // The span is always much smaller than u128::MAX, so the bias from the modulo is negligible
+        low + i128::try_from(random % (span + 1_u128)).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixture::{generate, Shape};
+    use time::macros::datetime;
+
+    #[test]
+    fn test_generate() -> anyhow::Result<()> {
+        let shape = "created_at:ms,updated_at:ms".parse::<Shape>()?;
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        let end = datetime!(2024-12-31 0:00 UTC);
+
+        let value = generate(&shape, 20_usize, start, end, 1_u64)?;
+
+        anyhow::ensure!(value == generate(&shape, 20_usize, start, end, 1_u64)?);
+
+        for va in value.as_array().into_iter().flatten() {
+            let created_at = va["created_at"].as_i64().unwrap_or_default();
+            let updated_at = va["updated_at"].as_i64().unwrap_or_default();
+
+            anyhow::ensure!(created_at >= 1_704_067_200_000_i64);
+            anyhow::ensure!(updated_at >= created_at);
+            anyhow::ensure!(updated_at <= 1_735_603_200_000_i64);
+        }
+
+        anyhow::ensure!("created_at:days".parse::<Shape>().is_err());
+
+        Ok(())
+    }
+}
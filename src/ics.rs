@@ -0,0 +1,164 @@
+//! Calendar events in iCalendar format (RFC 5545), for putting an instant on a calendar
+
+use std::fmt::Write as _;
+use time::{
+    format_description::FormatItem, macros::format_description, Duration, OffsetDateTime, UtcOffset,
+};
+
+/// A date and time in UTC, e.g. "20231114T221320Z"
+const DATE_TIME_FORMAT_DESCRIPTION: &[FormatItem<'_>] =
+    format_description!("[year][month][day]T[hour][minute][second]Z");
+/// The longest a line can be before it has to be folded onto the next, in bytes (not counting the line break)
+const MAXIMUM_LINE_LENGTH: usize = 75_usize;
+
+/// An event starting at an instant
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    pub start: OffsetDateTime,
+    /// How long the event lasts (it takes no time if this is `None`)
+    pub duration: Option<Duration>,
+    pub summary: String,
+}
+
+impl Event {
+    /// A calendar holding just this event, with CRLF line breaks, stamped as created at `now`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an instant cannot be written in iCalendar format (e.g. its year has more than four digits)
+    pub fn to_ics(&self, now: OffsetDateTime) -> anyhow::Result<String> {
+        let start = format_date_time(self.start)?;
+
+        let mut line_vec = vec![
+            "BEGIN:VCALENDAR".to_owned(),
+            "VERSION:2.0".to_owned(),
+            "PRODID:-//readtimestamp//readtimestamp//EN".to_owned(),
+            "BEGIN:VEVENT".to_owned(),
+            format!(
+                "UID:{}-{}@readtimestamp",
+                self.start.unix_timestamp(),
+                now.unix_timestamp_nanos()
+            ),
+            format!("DTSTAMP:{}", format_date_time(now)?),
+            format!("DTSTART:{start}"),
+        ];
+
+        if let Some(du) = self.duration {
+            let end = self
+                .start
+                .checked_add(du)
+                .ok_or_else(|| anyhow::anyhow!("The event ends too far in the future"))?;
+
+            line_vec.push(format!("DTEND:{}", format_date_time(end)?));
+        }
+
+        line_vec.push(format!("SUMMARY:{}", escape_text(&self.summary)));
+        line_vec.push("END:VEVENT".to_owned());
+        line_vec.push("END:VCALENDAR".to_owned());
+
+        let mut ics = String::new();
+
+        for st in line_vec {
+            write!(ics, "{}\r\n", fold_line(&st))?;
+        }
+
+        Ok(ics)
+    }
+}
+
+fn format_date_time(offset_date_time: OffsetDateTime) -> anyhow::Result<String> {
+    Ok(offset_date_time
+        .to_offset(UtcOffset::UTC)
+        .format(DATE_TIME_FORMAT_DESCRIPTION)?)
+}
+
+/// Escapes the characters that mean something in a text value
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        match ch {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(ch),
+        }
+    }
+
+    escaped
+}
+
+/// Breaks `line` into lines of at most [`MAXIMUM_LINE_LENGTH`] bytes, each one after the first starting with a space,
+/// without splitting a character
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+
+    let mut length = 0_usize;
+
+    for ch in line.chars() {
+        if length + ch.len_utf8() > MAXIMUM_LINE_LENGTH {
+            folded.push_str("\r\n ");
+
+            // The space counts
+            length = 1_usize;
+        }
+
+        folded.push(ch);
+
+        length += ch.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ics::{fold_line, Event};
+    use time::{macros::datetime, Duration};
+
+    #[test]
+    fn test_to_ics() -> anyhow::Result<()> {
+        let event = Event {
+            start: datetime!(2023-11-14 23:13:20 +1),
+            duration: Some(Duration::minutes(30_i64)),
+            summary: "Outage, see ticket; 1700000000".to_owned(),
+        };
+
+        anyhow::ensure!(
+            event.to_ics(datetime!(2000-01-01 0:00 UTC))?
+                == "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//readtimestamp//readtimestamp//EN\r
+BEGIN:VEVENT\r
+UID:1700000000-946684800000000000@readtimestamp\r
+DTSTAMP:20000101T000000Z\r
+DTSTART:20231114T221320Z\r
+DTEND:20231114T224320Z\r
+SUMMARY:Outage\\, see ticket\\; 1700000000\r
+END:VEVENT\r
+END:VCALENDAR\r
+"
+        );
+
+        let event = Event {
+            duration: None,
+            ..event
+        };
+
+        anyhow::ensure!(!event
+            .to_ics(datetime!(2000-01-01 0:00 UTC))?
+            .contains("DTEND"));
+
+        let line = format!("SUMMARY:{}", "é".repeat(40_usize));
+
+        let folded = fold_line(&line);
+
+        anyhow::ensure!(folded.split("\r\n").all(|st| st.len() <= 75_usize));
+        anyhow::ensure!(folded.replace("\r\n ", "") == line);
+
+        Ok(())
+    }
+}
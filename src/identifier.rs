@@ -0,0 +1,252 @@
+//! Identifiers that embed the instant they were generated at
+
+use time::OffsetDateTime;
+
+/// Seconds from the Unix epoch to 2014-05-13 16:53:20 UTC, which KSUIDs count from
+const KSUID_EPOCH_OFFSET_SECONDS: i64 = 1_400_000_000_i64;
+/// Nanoseconds from the Unix epoch to 1582-10-15 00:00:00 UTC, the start of the Gregorian calendar, which version 1
+/// UUIDs count from
+const GREGORIAN_EPOCH_OFFSET_NANOSECONDS: i128 = -12_219_292_800_000_000_000_i128;
+
+/// A kind of identifier that can be recognized in input
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IdentifierKind {
+    /// 12 bytes written as 24 hexadecimal digits, starting with the big-endian Unix time in seconds
+    ObjectId,
+    /// A version 1 UUID, whose 60-bit timestamp counts 100-nanosecond intervals since 1582-10-15 00:00:00 UTC
+    UuidV1,
+    /// A version 7 UUID, which starts with the 48-bit Unix time in milliseconds
+    UuidV7,
+    /// 26 characters of Crockford's base 32, starting with the 48-bit Unix time in milliseconds
+    Ulid,
+    /// 27 characters of base 62 encoding 20 bytes, starting with the big-endian seconds since 2014-05-13 16:53:20 UTC
+    Ksuid,
+}
+
+impl IdentifierKind {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ObjectId => "a MongoDB ObjectId",
+            Self::UuidV1 => "a version 1 UUID",
+            Self::UuidV7 => "a version 7 UUID",
+            Self::Ulid => "a ULID",
+            Self::Ksuid => "a KSUID",
+        }
+    }
+}
+
+/// Parses `input` if all of it is a recognized identifier, and returns the instant it was generated at
+#[must_use]
+pub fn parse_identifier(input: &str) -> Option<(IdentifierKind, OffsetDateTime)> {
+    if let Some(of) = parse_object_id(input) {
+        return Some((IdentifierKind::ObjectId, of));
+    }
+
+    if let Some(of) = parse_ulid(input) {
+        return Some((IdentifierKind::Ulid, of));
+    }
+
+    if let Some(of) = parse_ksuid(input) {
+        return Some((IdentifierKind::Ksuid, of));
+    }
+
+    if let Some(uuid) = parse_uuid(input) {
+        match uuid_version(uuid) {
+            1_u8 => return Some((IdentifierKind::UuidV1, uuid_v1_instant(uuid)?)),
+            7_u8 => return Some((IdentifierKind::UuidV7, uuid_v7_instant(uuid)?)),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The version of `input` if all of it is a UUID, for telling UUIDs without a timestamp apart from other input
+#[must_use]
+pub fn parse_uuid_version(input: &str) -> Option<u8> {
+    parse_uuid(input).map(uuid_version)
+}
+
+/// Accepts the hyphenated form, optionally in braces or with a "urn:uuid:" prefix
+fn parse_uuid(input: &str) -> Option<u128> {
+    let hyphenated = input
+        .strip_prefix('{')
+        .and_then(|st| st.strip_suffix('}'))
+        .or_else(|| input.strip_prefix("urn:uuid:"))
+        .unwrap_or(input);
+
+    let group_vec = hyphenated.split('-').collect::<Vec<_>>();
+
+    if group_vec.iter().map(|st| st.len()).collect::<Vec<_>>()
+        != [8_usize, 4_usize, 4_usize, 4_usize, 12_usize]
+        || !group_vec
+            .iter()
+            .all(|st| st.bytes().all(|by| by.is_ascii_hexdigit()))
+    {
+        return None;
+    }
+
+    u128::from_str_radix(&group_vec.concat(), 16_u32).ok()
+}
+
+const fn uuid_version(uuid: u128) -> u8 {
+    ((uuid >> 76_u32) & 0xF_u128) as u8
+}
+
+fn uuid_v1_instant(uuid: u128) -> Option<OffsetDateTime> {
+    let time_low = (uuid >> 96_u32) & 0xFFFF_FFFF_u128;
+    let time_mid = (uuid >> 80_u32) & 0xFFFF_u128;
+    let time_high = (uuid >> 64_u32) & 0x0FFF_u128;
+
+    let intervals = i128::try_from((time_high << 48_u32) | (time_mid << 32_u32) | time_low).ok()?;
+
+    OffsetDateTime::from_unix_timestamp_nanos(
+        intervals * 100_i128 + GREGORIAN_EPOCH_OFFSET_NANOSECONDS,
+    )
+    .ok()
+}
+
+/// Also accepts the `ObjectId("...")` form the `MongoDB` shell prints
+fn parse_object_id(input: &str) -> Option<OffsetDateTime> {
+    let hex = input
+        .strip_prefix("ObjectId(\"")
+        .and_then(|st| st.strip_suffix("\")"))
+        .unwrap_or(input);
+
+    if hex.len() != 24_usize || !hex.bytes().all(|by| by.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let seconds = u32::from_str_radix(hex.get(..8_usize)?, 16_u32).ok()?;
+
+    OffsetDateTime::from_unix_timestamp(i64::from(seconds)).ok()
+}
+
+fn uuid_v7_instant(uuid: u128) -> Option<OffsetDateTime> {
+    let milliseconds = i128::try_from(uuid >> 80_u32).ok()?;
+
+    OffsetDateTime::from_unix_timestamp_nanos(milliseconds * 1_000_000_i128).ok()
+}
+
+/// At least one character has to be a letter, so that long decimal numbers are not taken for ULIDs
+fn parse_ulid(input: &str) -> Option<OffsetDateTime> {
+    if input.len() != 26_usize || !input.bytes().any(|by| by.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    // Fails if the value does not fit in 128 bits, which 26 characters (130 bits) can exceed
+    let ulid = decode_crockford_base32(input)?;
+
+    let milliseconds = i128::try_from(ulid >> 80_u32).ok()?;
+
+    OffsetDateTime::from_unix_timestamp_nanos(milliseconds * 1_000_000_i128).ok()
+}
+
+/// At least one character has to be a letter, so that long decimal numbers are not taken for KSUIDs
+fn parse_ksuid(input: &str) -> Option<OffsetDateTime> {
+    const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+    if input.len() != 27_usize || !input.bytes().any(|by| by.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    // 27 characters of base 62 can exceed the 20 bytes, so the value is built up a byte at a time (big-endian)
+    let mut byte_array = [0_u8; 20_usize];
+
+    for by in input.bytes() {
+        let mut carry = u32::try_from(ALPHABET.iter().position(|byte| *byte == by)?).ok()?;
+
+        for byt in byte_array.iter_mut().rev() {
+            let product = u32::from(*byt) * 62_u32 + carry;
+
+            *byt = (product & 0xFF_u32) as u8;
+
+            carry = product >> 8_u32;
+        }
+
+        if carry != 0_u32 {
+            return None;
+        }
+    }
+
+    let seconds = u32::from_be_bytes([
+        byte_array[0_usize],
+        byte_array[1_usize],
+        byte_array[2_usize],
+        byte_array[3_usize],
+    ]);
+
+    OffsetDateTime::from_unix_timestamp(i64::from(seconds) + KSUID_EPOCH_OFFSET_SECONDS).ok()
+}
+
+/// Decodes Crockford's base 32, which is case insensitive and reads "I" and "L" as 1 and "O" as 0
+///
+/// Returns `None` if a character is not in the alphabet, or if the value does not fit in a `u128`
+fn decode_crockford_base32(input: &str) -> Option<u128> {
+    const ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    input.bytes().try_fold(0_u128, |value, by| {
+        let digit = match by.to_ascii_uppercase() {
+            b'I' | b'L' => 1_usize,
+            b'O' => 0_usize,
+            byt => ALPHABET.iter().position(|byte| *byte == byt)?,
+        };
+
+        // Shifting would silently drop the top bits
+        value
+            .checked_mul(32_u128)?
+            .checked_add(u128::try_from(digit).ok()?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::identifier::{parse_identifier, parse_uuid_version, IdentifierKind};
+    use time::macros::datetime;
+
+    #[test]
+    fn test_parse_identifier() -> anyhow::Result<()> {
+        anyhow::ensure!(
+            parse_identifier("65541e80a1b2c3d4e5f60718")
+                == Some((IdentifierKind::ObjectId, datetime!(2023-11-15 1:27:28 UTC)))
+        );
+        anyhow::ensure!(parse_identifier("ObjectId(\"65541e80a1b2c3d4e5f60718\")").is_some());
+        anyhow::ensure!(parse_identifier("65541e80a1b2c3d4e5f6071").is_none());
+        anyhow::ensure!(parse_identifier("65541e80a1b2c3d4e5f6071z").is_none());
+
+        anyhow::ensure!(
+            parse_identifier("c232ab00-9414-11ec-b3c8-9f6bdeced846")
+                == Some((IdentifierKind::UuidV1, datetime!(2022-02-22 19:22:22 UTC)))
+        );
+        anyhow::ensure!(parse_identifier("{C232AB00-9414-11EC-B3C8-9F6BDECED846}").is_some());
+
+        anyhow::ensure!(
+            parse_identifier("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")
+                == Some((IdentifierKind::UuidV7, datetime!(2022-02-22 19:22:22 UTC)))
+        );
+
+        anyhow::ensure!(
+            parse_identifier("01ARZ3NDEKTSV4RRFFQ69G5FAV")
+                == Some((IdentifierKind::Ulid, datetime!(2016-07-30 23:54:10.259 UTC)))
+        );
+        anyhow::ensure!(parse_identifier("01arz3ndektsv4rrffq69g5fav").is_some());
+        // More than 128 bits
+        anyhow::ensure!(parse_identifier("81ARZ3NDEKTSV4RRFFQ69G5FAV").is_none());
+        anyhow::ensure!(parse_identifier("01ARZ3NDEKTSV4RRFFQ69G5FAU").is_none());
+
+        anyhow::ensure!(
+            parse_identifier("0ujtsYcgvSTl8PAuAdqWYSMnLOv")
+                == Some((IdentifierKind::Ksuid, datetime!(2017-10-10 4:00:47 UTC)))
+        );
+        // More than 20 bytes
+        anyhow::ensure!(parse_identifier("zzzzzzzzzzzzzzzzzzzzzzzzzzz").is_none());
+
+        // Version 4 UUIDs are random
+        anyhow::ensure!(parse_identifier("919108f7-52d1-4320-9bac-f847db4148a8").is_none());
+        anyhow::ensure!(parse_uuid_version("919108f7-52d1-4320-9bac-f847db4148a8") == Some(4_u8));
+        anyhow::ensure!(parse_uuid_version("919108f7-52d1-4320-9bac").is_none());
+
+        Ok(())
+    }
+}
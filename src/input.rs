@@ -0,0 +1,219 @@
+use crate::binary::{self, Endian};
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE},
+    Engine as _,
+};
+use std::num::ParseIntError;
+
+/// The base of an integer written with a `0x`, `0o`, or `0b` prefix
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Radix {
+    Binary,
+    Octal,
+    Hexadecimal,
+}
+
+impl Radix {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Binary => "binary",
+            Self::Octal => "octal",
+            Self::Hexadecimal => "hexadecimal",
+        }
+    }
+
+    #[must_use]
+    pub const fn value(self) -> u32 {
+        match self {
+            Self::Binary => 2_u32,
+            Self::Octal => 8_u32,
+            Self::Hexadecimal => 16_u32,
+        }
+    }
+}
+
+/// Parses an integer with a `0x`, `0o`, or `0b` prefix (case insensitive)
+///
+/// Returns `None` if `input` does not start with one of the prefixes
+#[must_use]
+pub fn parse_prefixed_integer(input: &str) -> Option<(Radix, Result<i128, ParseIntError>)> {
+    let prefix = input.get(..2_usize)?;
+
+    let radix = match prefix {
+        "0x" | "0X" => Radix::Hexadecimal,
+        "0o" | "0O" => Radix::Octal,
+        "0b" | "0B" => Radix::Binary,
+        _ => return None,
+    };
+
+    let digits = &input[2_usize..];
+
+    Some((radix, i128::from_str_radix(digits, radix.value())))
+}
+
+/// Parses a 64-bit NTP timestamp written as 16 hexadecimal digits, either as is (e.g. "e9a1c3b58f5c28f6") or with
+/// the seconds and the fraction separated by a "." as `ntpq` and `tcpdump` print them (e.g. "e9a1c3b5.8f5c28f6")
+///
+/// Without a ".", at least one digit has to be a letter, so that 16-digit decimal numbers are not taken for NTP
+/// timestamps
+#[must_use]
+pub fn parse_hexadecimal_ntp(input: &str) -> Option<i128> {
+    let digits = match input.split_once('.') {
+        Some((seconds, fraction)) if seconds.len() == 8_usize && fraction.len() == 8_usize => {
+            format!("{seconds}{fraction}")
+        }
+        None if input.len() == 16_usize && input.bytes().any(|by| by.is_ascii_alphabetic()) => {
+            input.to_owned()
+        }
+        _ => return None,
+    };
+
+    if !digits.bytes().all(|by| by.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u64::from_str_radix(&digits, 16_u32).ok().map(i128::from)
+}
+
+/// Parses a DOS packed date and time written as 8 hexadecimal digits without a prefix (e.g. "5771A3A1"), as hex dumps
+/// of ZIP headers show them
+///
+/// At least one digit has to be a letter, so that 8-digit decimal numbers are still read as decimal
+#[must_use]
+pub fn parse_hexadecimal_dos(input: &str) -> Option<i128> {
+    if input.len() != 8_usize
+        || !input.bytes().all(|by| by.is_ascii_hexdigit())
+        || !input.bytes().any(|by| by.is_ascii_alphabetic())
+    {
+        return None;
+    }
+
+    u32::from_str_radix(input, 16_u32).ok().map(i128::from)
+}
+
+/// What a base64 string decoded to
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Base64Timestamp {
+    /// A big-endian unsigned integer, 4, 8, or 16 bytes long
+    Integer { width: usize, value: i128 },
+    /// A number written in ASCII, e.g. "1700000000" (from `MTcwMDAwMDAwMA==`)
+    Digits(String),
+}
+
+/// Decodes `input` if it is padded base64 (standard or URL-safe) of a 4-, 8-, or 16-byte integer or of a number written
+/// in ASCII, as APIs wrap binary timestamp fields
+///
+/// At least one character has to be something other than a digit, so that numbers are not taken for base64
+#[must_use]
+pub fn parse_base64(input: &str) -> Option<Base64Timestamp> {
+    if !input.len().is_multiple_of(4_usize) || input.bytes().all(|by| by.is_ascii_digit()) {
+        return None;
+    }
+
+    let by = STANDARD
+        .decode(input)
+        .or_else(|_| URL_SAFE.decode(input))
+        .ok()?;
+
+    let is_number = by.iter().any(u8::is_ascii_digit)
+        && by
+            .iter()
+            .all(|byt| byt.is_ascii_digit() || matches!(byt, b'-' | b'.'));
+
+    if is_number {
+        return String::from_utf8(by).ok().map(Base64Timestamp::Digits);
+    }
+
+    if !matches!(by.len(), 4_usize | 8_usize | 16_usize) {
+        return None;
+    }
+
+    binary::read_integer(&by, Endian::Big, false)
+        .ok()
+        .map(|io| Base64Timestamp::Integer {
+            width: by.len(),
+            value: io,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{
+        parse_base64, parse_hexadecimal_dos, parse_hexadecimal_ntp, parse_prefixed_integer,
+        Base64Timestamp, Radix,
+    };
+
+    #[test]
+    fn test_parse_prefixed_integer() -> anyhow::Result<()> {
+        let (radix, result) =
+            parse_prefixed_integer("0x65A10C80").ok_or_else(|| anyhow::anyhow!("No prefix"))?;
+
+        anyhow::ensure!(radix == Radix::Hexadecimal);
+        anyhow::ensure!(result? == 1_705_053_312_i128);
+
+        let (_, result) =
+            parse_prefixed_integer("0b101").ok_or_else(|| anyhow::anyhow!("No prefix"))?;
+
+        anyhow::ensure!(result? == 5_i128);
+
+        anyhow::ensure!(parse_prefixed_integer("1704004736").is_none());
+        anyhow::ensure!(parse_prefixed_integer("0xZZ").is_some_and(|(_, re)| re.is_err()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_hexadecimal_ntp() -> anyhow::Result<()> {
+        anyhow::ensure!(
+            parse_hexadecimal_ntp("E9A1C3B580000000") == Some(0xE9A1_C3B5_8000_0000_i128)
+        );
+        anyhow::ensure!(
+            parse_hexadecimal_ntp("e9a1c3b5.80000000") == Some(0xE9A1_C3B5_8000_0000_i128)
+        );
+        anyhow::ensure!(parse_hexadecimal_ntp("1700000000000000").is_none());
+        anyhow::ensure!(parse_hexadecimal_ntp("1700000000.000000").is_none());
+        anyhow::ensure!(parse_hexadecimal_ntp("e9a1c3b5.8000000g").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_hexadecimal_dos() -> anyhow::Result<()> {
+        anyhow::ensure!(parse_hexadecimal_dos("5771A3A1") == Some(0x5771_A3A1_i128));
+        anyhow::ensure!(parse_hexadecimal_dos("5771a3a1") == Some(0x5771_A3A1_i128));
+        anyhow::ensure!(parse_hexadecimal_dos("57712345").is_none());
+        anyhow::ensure!(parse_hexadecimal_dos("5771A3A").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_base64() -> anyhow::Result<()> {
+        anyhow::ensure!(
+            parse_base64("ZVPxAA==")
+                == Some(Base64Timestamp::Integer {
+                    width: 4_usize,
+                    value: 1_700_000_000_i128
+                })
+        );
+        anyhow::ensure!(
+            parse_base64("AAABi8/laAA=")
+                == Some(Base64Timestamp::Integer {
+                    width: 8_usize,
+                    value: 1_700_000_000_000_i128
+                })
+        );
+        anyhow::ensure!(
+            parse_base64("MTcwMDAwMDAwMA==")
+                == Some(Base64Timestamp::Digits("1700000000".to_owned()))
+        );
+
+        // Unpadded, or decodes to 6 bytes
+        anyhow::ensure!(parse_base64("ZVPxAA").is_none());
+        anyhow::ensure!(parse_base64("abcdefgh").is_none());
+        anyhow::ensure!(parse_base64("12345678").is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,406 @@
+use crate::{date_time::parse_date_time, dos, number::Number, zone::Zone, Unit};
+use std::ops::RangeInclusive;
+use time::{error::ComponentRange, macros::datetime, Duration, OffsetDateTime};
+
+/// The years a timestamp that was found in the wild is expected to fall in
+pub const PLAUSIBLE_YEARS: RangeInclusive<i32> = 2000_i32..=2099_i32;
+
+/// How many years from the reference instant it takes for a reading's score to halve
+const SCORE_HALF_LIFE_YEARS: f64 = 20_f64;
+/// How much less likely a reading outside [`PLAUSIBLE_YEARS`] is than one inside them
+const IMPLAUSIBLE_WEIGHT: f64 = 0.2_f64;
+const SECONDS_PER_YEAR: f64 = 31_556_952_f64;
+
+/// Switches that control everything in the output that would otherwise depend on the environment
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Settings {
+    /// The instant relative times are measured from (the current time if `None`)
+    pub now: Option<OffsetDateTime>,
+    /// The zone local times are displayed in
+    pub zone: Zone,
+}
+
+impl Settings {
+    /// The reference instant used by [`Settings::deterministic`]
+    pub const DETERMINISTIC_NOW: OffsetDateTime = datetime!(2000-01-01 0:00 UTC);
+
+    /// Settings that produce the same output on every machine and every run, for snapshot testing
+    #[must_use]
+    pub const fn deterministic() -> Self {
+        Self {
+            now: Some(Self::DETERMINISTIC_NOW),
+            zone: Zone::Utc,
+        }
+    }
+
+    #[must_use]
+    pub fn now(&self) -> OffsetDateTime {
+        self.now.unwrap_or_else(OffsetDateTime::now_utc)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            now: None,
+            zone: Zone::Local,
+        }
+    }
+}
+
+/// Why a number could not be interpreted in a unit
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CandidateError {
+    /// The number does not fit in an `i128` when converted to nanoseconds
+    TooLarge,
+    /// The instant cannot be represented
+    OutOfRange(ComponentRange),
+}
+
+/// A number interpreted in one unit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Candidate {
+    pub unit: Unit,
+    pub result: Result<OffsetDateTime, CandidateError>,
+    /// The instant minus the reference instant, if the interpretation succeeded
+    pub delta: Option<Duration>,
+    /// How likely this is to be the right interpretation (see [`score`]), or 0 if the interpretation failed
+    pub score: f64,
+}
+
+impl Candidate {
+    /// Whether the interpretation succeeded and falls in [`PLAUSIBLE_YEARS`]
+    #[must_use]
+    pub fn is_plausible(&self) -> bool {
+        self.result
+            .is_ok_and(|of| PLAUSIBLE_YEARS.contains(&of.year()))
+    }
+}
+
+/// How many years apart `instant` and `now` are, in either direction
+#[must_use]
+pub fn years_between(instant: OffsetDateTime, now: OffsetDateTime) -> f64 {
+    (instant - now).abs().as_seconds_f64() / SECONDS_PER_YEAR
+}
+
+/// How likely a reading of a number as `instant` is to be the right one, to be compared with the scores of its other
+/// readings
+///
+/// The score halves every [`SCORE_HALF_LIFE_YEARS`] away from `now`, is lower outside [`PLAUSIBLE_YEARS`], and is lower
+/// the more `digit_difference` (how many more or fewer digits the number has than a timestamp in its unit has now)
+/// there is, if that is known.
+#[must_use]
+pub fn score(instant: OffsetDateTime, now: OffsetDateTime, digit_difference: Option<u32>) -> f64 {
+    let years = years_between(instant, now);
+
+    let distance_weight = 0.5_f64.powf(years / SCORE_HALF_LIFE_YEARS);
+
+    let plausibility_weight = if PLAUSIBLE_YEARS.contains(&instant.year()) {
+        1_f64
+    } else {
+        IMPLAUSIBLE_WEIGHT
+    };
+
+    let digit_weight = match digit_difference {
+        None | Some(0_u32) => 1_f64,
+        Some(1_u32) => 0.5_f64,
+        Some(_) => 0.25_f64,
+    };
+
+    distance_weight * plausibility_weight * digit_weight
+}
+
+/// Sorts `candidates` by score, highest first, keeping the order of ties (so the failed ones keep their order at the
+/// end)
+fn sort_by_score(candidates: &mut [Candidate]) {
+    candidates.sort_by(|ca, can| can.score.total_cmp(&ca.score));
+}
+
+/// Interprets `number` in every unit, returning the candidates that succeeded first, sorted so the one with the highest
+/// [`score`] comes first
+///
+/// Units that are only an alternative reading of another unit (see [`Unit::alternative_to`]) are left out unless their
+/// reading is plausible and the other unit's is not, and units that are only used on request are always left out. Ties
+/// and the failed candidates keep the order of [`Unit::ALL`], so the order is stable.
+#[must_use]
+pub fn interpret(number: Number, settings: &Settings) -> Vec<Candidate> {
+    let now = settings.now();
+
+    let all_candidate_vec = Unit::ALL
+        .into_iter()
+        .filter(|un| !un.is_only_on_request())
+        .map(|un| candidate(number, un, now))
+        .collect::<Vec<_>>();
+
+    let mut candidate_vec = all_candidate_vec
+        .iter()
+        .filter(|ca| match ca.unit.alternative_to() {
+            Some(un) => {
+                ca.is_plausible()
+                    && !all_candidate_vec
+                        .iter()
+                        .any(|cand| cand.unit == un && cand.is_plausible())
+            }
+            None => true,
+        })
+        .copied()
+        .collect::<Vec<_>>();
+
+    sort_by_score(&mut candidate_vec);
+
+    candidate_vec
+}
+
+/// Like [`interpret`], but with `unit`'s candidate (even if it is only used on request) first if it is plausible, since
+/// it is the unit the timestamp is expected to be in
+#[must_use]
+pub fn interpret_preferring(number: Number, unit: Unit, settings: &Settings) -> Vec<Candidate> {
+    let mut candidate_vec = interpret(number, settings);
+
+    candidate_vec.retain(|ca| ca.unit != unit);
+
+    let preferred = candidate(number, unit, settings.now());
+
+    if preferred.is_plausible() {
+        candidate_vec.insert(0_usize, preferred);
+    } else {
+        candidate_vec.push(preferred);
+
+        sort_by_score(&mut candidate_vec);
+    }
+
+    candidate_vec
+}
+
+/// The label and instant of each readable interpretation of `input`, best first, or `None` if it is neither a number
+/// nor a date and time (a date and time is its own single interpretation, labeled with its format)
+#[must_use]
+pub fn interpret_input(input: &str, settings: &Settings) -> Option<Vec<(String, OffsetDateTime)>> {
+    let trimmed = input.trim();
+
+    if let Some((da, of)) = parse_date_time(trimmed) {
+        return Some(vec![(da.name().to_owned(), of)]);
+    }
+
+    let number = trimmed.parse::<Number>().ok()?;
+
+    Some(
+        interpret(number, settings)
+            .into_iter()
+            .filter_map(|ca| Some((ca.unit.name().to_owned(), ca.result.ok()?)))
+            .collect(),
+    )
+}
+
+/// Interprets `number` in `unit` only
+#[must_use]
+pub fn interpret_in(number: Number, unit: Unit, settings: &Settings) -> Candidate {
+    candidate(number, unit, settings.now())
+}
+
+fn candidate(number: Number, unit: Unit, now: OffsetDateTime) -> Candidate {
+    let result = match (unit, number.nanoseconds(unit)) {
+        // Unpacked separately, to tell which field is out of range
+        (Unit::Dos, Some(io)) => dos::decode(io),
+        (_, Some(io)) => match unit.unix_nanoseconds(io) {
+            Some(int) => {
+                OffsetDateTime::from_unix_timestamp_nanos(int).map_err(CandidateError::OutOfRange)
+            }
+            None => Err(CandidateError::TooLarge),
+        },
+        (_, None) => Err(CandidateError::TooLarge),
+    };
+
+    let delta = result.ok().map(|of| of - now);
+
+    // Compared with what `now` is in the unit
+    let score = result.map_or(0_f64, |of| {
+        score(
+            of,
+            now,
+            number.integer_part().map(|io| {
+                digit_difference(io, unit.from_unix_nanoseconds(now.unix_timestamp_nanos()))
+            }),
+        )
+    });
+
+    Candidate {
+        unit,
+        result,
+        delta,
+        score,
+    }
+}
+
+/// How many more or fewer digits `integer` has than `expected` (ignoring their signs), for [`score`]
+#[must_use]
+pub fn digit_difference(integer: i128, expected: i128) -> u32 {
+    let digits = |io: i128| {
+        io.unsigned_abs()
+            .checked_ilog10()
+            .map_or(1_u32, |us| us + 1_u32)
+    };
+
+    digits(integer).abs_diff(digits(expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interpret::{interpret, interpret_preferring, Settings},
+        number::Number,
+        Unit,
+    };
+
+    #[test]
+    fn test_interpret() -> anyhow::Result<()> {
+        let candidate_vec = interpret(
+            Number::from_integer(946_684_800_000_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(candidate_vec[0_usize].unit == Unit::Milliseconds);
+        anyhow::ensure!(candidate_vec[0_usize].delta == Some(time::Duration::ZERO));
+        anyhow::ensure!(candidate_vec[0_usize].is_plausible());
+        anyhow::ensure!(!candidate_vec[1_usize].is_plausible());
+
+        // Too large to be seconds
+        anyhow::ensure!(candidate_vec[4_usize].unit == Unit::Seconds);
+        anyhow::ensure!(candidate_vec[4_usize].result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpret_filetime() -> anyhow::Result<()> {
+        let filetime_candidate_vec = interpret(
+            Number::from_integer(125_911_584_000_000_000_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(filetime_candidate_vec[0_usize].unit == Unit::Filetime);
+        anyhow::ensure!(filetime_candidate_vec[0_usize].delta == Some(time::Duration::ZERO));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpret_webkit() -> anyhow::Result<()> {
+        // In microseconds since 1970 this is in 2392, but since 1601 it is in 2023 (as Chrome would store it)
+        let webkit_candidate_vec = interpret(
+            Number::from_integer(13_343_385_600_000_000_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(webkit_candidate_vec
+            .iter()
+            .any(|ca| ca.unit == Unit::Webkit && ca.is_plausible()));
+
+        // Plausible in milliseconds, so the WebKit reading is not added
+        let candidate_vec = interpret(
+            Number::from_integer(946_684_800_000_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(!candidate_vec.iter().any(|ca| ca.unit == Unit::Webkit));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpret_cocoa() -> anyhow::Result<()> {
+        // 1992 in Unix seconds, 2023 in Cocoa seconds
+        let cocoa_candidate_vec = interpret(
+            Number::from_integer(721_612_800_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(cocoa_candidate_vec
+            .iter()
+            .any(|ca| ca.unit == Unit::Cocoa && ca.is_plausible()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpret_days() -> anyhow::Result<()> {
+        // 1970-01-01 05:28:20 in seconds, so the reading in days (2023-12-09) is suggested
+        let days_candidate_vec = interpret(
+            Number::from_integer(19_700_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(days_candidate_vec.iter().any(|ca| ca.unit == Unit::Days
+            && ca.result == Ok(time::macros::datetime!(2023-12-09 0:00 UTC))));
+
+        // Plausible in seconds, so the reading in days is not added
+        let hfs_candidate_vec = interpret(
+            Number::from_integer(3_782_764_800_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(!hfs_candidate_vec.iter().any(|ca| ca.unit == Unit::Days));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpret_hfs() -> anyhow::Result<()> {
+        // 2023 in HFS+ seconds, and 2089 in Unix seconds
+        let hfs_candidate_vec = interpret(
+            Number::from_integer(3_782_764_800_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(
+            hfs_candidate_vec
+                .iter()
+                .filter(|ca| ca.is_plausible())
+                .map(|ca| ca.unit)
+                .collect::<Vec<_>>()
+                == [Unit::Hfs, Unit::Seconds]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpret_score() -> anyhow::Result<()> {
+        // 2090 in milliseconds is further from 2000 than 1970 in microseconds, but has the digits of milliseconds now
+        let far_candidate_vec = interpret(
+            Number::from_integer(3_786_912_000_000_i128),
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(far_candidate_vec[0_usize].unit == Unit::Milliseconds);
+        anyhow::ensure!(far_candidate_vec[0_usize].score > far_candidate_vec[1_usize].score);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpret_preferring() -> anyhow::Result<()> {
+        // Preferred over the closer HFS+ reading, since it is plausible
+        anyhow::ensure!(
+            interpret_preferring(
+                Number::from_integer(3_782_764_800_i128),
+                Unit::Seconds,
+                &Settings::deterministic()
+            )[0_usize]
+                .unit
+                == Unit::Seconds
+        );
+
+        // Only used on request, and implausible, so not preferred
+        let excel_candidate_vec = interpret_preferring(
+            Number::from_integer(946_684_800_000_i128),
+            Unit::Excel,
+            &Settings::deterministic(),
+        );
+
+        anyhow::ensure!(excel_candidate_vec[0_usize].unit == Unit::Milliseconds);
+        anyhow::ensure!(excel_candidate_vec.iter().any(|ca| ca.unit == Unit::Excel));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write as _,
+    path::{Path, PathBuf},
+};
+use time::{Duration, OffsetDateTime};
+
+/// One recorded instant of a stopwatch session
+pub struct Lap {
+    /// Starting at 1 for the instant the session was started
+    pub number: usize,
+    pub instant: OffsetDateTime,
+    /// `None` for the first lap
+    pub since_previous: Option<Duration>,
+    pub since_start: Duration,
+}
+
+/// The state file of the stopwatch session named `session`
+///
+/// State files are kept in `$XDG_STATE_HOME/readtimestamp/laps` (or `~/.local/state/readtimestamp/laps`)
+///
+/// # Errors
+///
+/// Returns an error if `session` is not a valid name, or if the home directory cannot be determined
+pub fn session_path(session: &str) -> anyhow::Result<PathBuf> {
+    anyhow::ensure!(
+        !session.is_empty()
+            && session
+                .bytes()
+                .all(|by| by.is_ascii_alphanumeric() || by == b'-' || by == b'_'),
+        "Session name \"{session}\" must only contain letters, digits, \"-\", and \"_\""
+    );
+
+    Ok(crate::state_directory()?.join("laps").join(session))
+}
+
+/// Appends `now` to the session stored at `path` (starting the session if the file does not exist) and returns the
+/// resulting lap
+///
+/// # Errors
+///
+/// Returns an error if the state file cannot be read or written, or is corrupt
+pub fn record(path: &Path, now: OffsetDateTime) -> anyhow::Result<Lap> {
+    let instant_vec = read(path)?;
+
+    if let Some(pa) = path.parent() {
+        fs::create_dir_all(pa)?;
+    }
+
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+
+    writeln!(file, "{}", now.unix_timestamp_nanos())?;
+
+    Ok(Lap {
+        number: instant_vec.len() + 1_usize,
+        instant: now,
+        since_previous: instant_vec.last().map(|of| now - *of),
+        since_start: instant_vec.first().map_or(Duration::ZERO, |of| now - *of),
+    })
+}
+
+/// Deletes the session stored at `path`, so the next lap starts a new one
+///
+/// # Errors
+///
+/// Returns an error if the state file exists but cannot be deleted
+pub fn reset(path: &Path) -> anyhow::Result<()> {
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> anyhow::Result<Vec<OffsetDateTime>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    fs::read_to_string(path)?
+        .lines()
+        .filter(|st| !st.trim().is_empty())
+        .map(|st| {
+            let io = st.trim().parse::<i128>().map_err(|er| {
+                anyhow::anyhow!("State file \"{}\" is corrupt: {er}", path.display())
+            })?;
+
+            Ok(OffsetDateTime::from_unix_timestamp_nanos(io)?)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lap;
+    use std::{env, process};
+    use time::{macros::datetime, Duration};
+
+    #[test]
+    fn test_record() -> anyhow::Result<()> {
+        let path = env::temp_dir().join(format!("readtimestamp-test-lap-{}", process::id()));
+
+        lap::reset(&path)?;
+
+        let first = lap::record(&path, datetime!(2024-05-01 12:00 UTC))?;
+
+        anyhow::ensure!(first.number == 1_usize);
+        anyhow::ensure!(first.since_previous.is_none());
+
+        lap::record(&path, datetime!(2024-05-01 12:00:30 UTC))?;
+
+        let third = lap::record(&path, datetime!(2024-05-01 12:01:15.5 UTC))?;
+
+        anyhow::ensure!(third.number == 3_usize);
+        anyhow::ensure!(third.since_previous == Some(Duration::milliseconds(45_500_i64)));
+        anyhow::ensure!(third.since_start == Duration::milliseconds(75_500_i64));
+
+        lap::reset(&path)?;
+
+        anyhow::ensure!(lap::session_path("../escape").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+//! The leap seconds inserted into UTC since 1972, for time scales like TAI and GPS time that do not have them
+//!
+//! A table is compiled in, and a newer one can be loaded from a `leap-seconds.list` file as published by the IERS and
+//! NIST.
+
+use std::{fs, path::Path, sync::OnceLock};
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000_i128;
+/// How close to a leap second an instant has to be for conversions across it to be worth pointing out
+const VICINITY_NANOSECONDS: i128 = 60_i128 * NANOSECONDS_PER_SECOND;
+
+/// The table loaded with [`load`], which replaces the compiled-in one
+static LOADED_TABLE: OnceLock<Vec<(i128, i128)>> = OnceLock::new();
+
+/// The start of each UTC day (in seconds since the Unix epoch) from which TAI − UTC had the given number of seconds,
+/// from IERS Bulletin C
+///
+/// No leap second has been announced after the one at the end of 2016.
+const COMPILED_IN_TABLE: [(i128, i128); 28_usize] = [
+    (63_072_000_i128, 10_i128),    // 1972-01-01
+    (78_796_800_i128, 11_i128),    // 1972-07-01
+    (94_694_400_i128, 12_i128),    // 1973-01-01
+    (126_230_400_i128, 13_i128),   // 1974-01-01
+    (157_766_400_i128, 14_i128),   // 1975-01-01
+    (189_302_400_i128, 15_i128),   // 1976-01-01
+    (220_924_800_i128, 16_i128),   // 1977-01-01
+    (252_460_800_i128, 17_i128),   // 1978-01-01
+    (283_996_800_i128, 18_i128),   // 1979-01-01
+    (315_532_800_i128, 19_i128),   // 1980-01-01
+    (362_793_600_i128, 20_i128),   // 1981-07-01
+    (394_329_600_i128, 21_i128),   // 1982-07-01
+    (425_865_600_i128, 22_i128),   // 1983-07-01
+    (489_024_000_i128, 23_i128),   // 1985-07-01
+    (567_993_600_i128, 24_i128),   // 1988-01-01
+    (631_152_000_i128, 25_i128),   // 1990-01-01
+    (662_688_000_i128, 26_i128),   // 1991-01-01
+    (709_948_800_i128, 27_i128),   // 1992-07-01
+    (741_484_800_i128, 28_i128),   // 1993-07-01
+    (773_020_800_i128, 29_i128),   // 1994-07-01
+    (820_454_400_i128, 30_i128),   // 1996-01-01
+    (867_715_200_i128, 31_i128),   // 1997-07-01
+    (915_148_800_i128, 32_i128),   // 1999-01-01
+    (1_136_073_600_i128, 33_i128), // 2006-01-01
+    (1_230_768_000_i128, 34_i128), // 2009-01-01
+    (1_341_100_800_i128, 35_i128), // 2012-07-01
+    (1_435_708_800_i128, 36_i128), // 2015-07-01
+    (1_483_228_800_i128, 37_i128), // 2017-01-01
+];
+
+/// Replaces the compiled-in table with the one in the `leap-seconds.list` file at `path`
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed, or if a table was already loaded
+pub fn load(path: &Path) -> anyhow::Result<()> {
+    let string = fs::read_to_string(path)
+        .map_err(|er| anyhow::anyhow!("Could not read \"{}\": {er}", path.display()))?;
+
+    let table = parse(&string)
+        .map_err(|er| anyhow::anyhow!("Could not parse \"{}\": {er}", path.display()))?;
+
+    LOADED_TABLE
+        .set(table)
+        .map_err(|_| anyhow::anyhow!("A leap second table was already loaded"))
+}
+
+/// Parses the `leap-seconds.list` format: lines of NTP seconds and TAI − UTC in seconds, with comments starting with
+/// "#"
+///
+/// # Errors
+///
+/// Returns an error if a line is malformed, or if the entries are missing or out of order
+pub fn parse(string: &str) -> anyhow::Result<Vec<(i128, i128)>> {
+    let mut table = Vec::<(i128, i128)>::new();
+
+    for st in string.lines() {
+        let line = st.split('#').next().unwrap_or_default().trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut split = line.split_whitespace();
+
+        let (Some(ntp_seconds), Some(offset)) = (split.next(), split.next()) else {
+            anyhow::bail!("Line \"{st}\" does not have an NTP timestamp and an offset");
+        };
+
+        // Counted from the NTP epoch
+        let start = ntp_seconds.parse::<i128>()?
+            + crate::NTP_EPOCH_OFFSET_NANOSECONDS / NANOSECONDS_PER_SECOND;
+
+        anyhow::ensure!(
+            table.last().is_none_or(|(sta, _)| *sta < start),
+            "Line \"{st}\" is out of order"
+        );
+
+        table.push((start, offset.parse::<i128>()?));
+    }
+
+    anyhow::ensure!(!table.is_empty(), "There are no leap second entries");
+
+    Ok(table)
+}
+
+fn table() -> &'static [(i128, i128)] {
+    LOADED_TABLE.get().map_or(&COMPILED_IN_TABLE, Vec::as_slice)
+}
+
+/// TAI − UTC in nanoseconds at the UTC instant `unix_nanoseconds` (zero before 1972, when UTC started counting whole
+/// leap seconds)
+#[must_use]
+pub fn tai_minus_utc(unix_nanoseconds: i128) -> i128 {
+    table()
+        .iter()
+        .rev()
+        .find(|(start, _)| unix_nanoseconds >= start * NANOSECONDS_PER_SECOND)
+        .map_or(0_i128, |(_, offset)| offset * NANOSECONDS_PER_SECOND)
+}
+
+/// TAI − UTC in nanoseconds at the instant that is `tai_nanoseconds` since 1970-01-01 00:00:00 on the TAI scale
+///
+/// A leap second itself has no UTC instant of its own, so it gets the offset from before it, which makes it read as the
+/// first second of the next day.
+#[must_use]
+pub fn tai_minus_utc_at_tai(tai_nanoseconds: i128) -> i128 {
+    table()
+        .iter()
+        .rev()
+        .find(|(start, offset)| tai_nanoseconds >= (start + offset) * NANOSECONDS_PER_SECOND)
+        .map_or(0_i128, |(_, offset)| offset * NANOSECONDS_PER_SECOND)
+}
+
+/// The leap second (as the Unix seconds of the UTC midnight that follows it) within a minute of the UTC instant
+/// `unix_nanoseconds`, if there is one
+#[must_use]
+pub fn nearby(unix_nanoseconds: i128) -> Option<i128> {
+    table()
+        .iter()
+        // The first entry is where UTC started, not a leap second
+        .skip(1_usize)
+        .map(|(start, _)| *start)
+        .find(|start| {
+            (unix_nanoseconds - start * NANOSECONDS_PER_SECOND).abs() <= VICINITY_NANOSECONDS
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leap_seconds::{nearby, parse, tai_minus_utc, tai_minus_utc_at_tai};
+
+    const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000_i128;
+
+    #[test]
+    fn test_tai_minus_utc() -> anyhow::Result<()> {
+        anyhow::ensure!(tai_minus_utc(0_i128) == 0_i128);
+        anyhow::ensure!(
+            tai_minus_utc(1_483_228_799_999_999_999_i128) == 36_i128 * NANOSECONDS_PER_SECOND
+        );
+        anyhow::ensure!(
+            tai_minus_utc(1_483_228_800_000_000_000_i128) == 37_i128 * NANOSECONDS_PER_SECOND
+        );
+
+        // 2016-12-31 23:59:60 UTC is 2017-01-01 00:00:36 TAI
+        let leap_second = (1_483_228_800_i128 + 36_i128) * NANOSECONDS_PER_SECOND;
+
+        anyhow::ensure!(tai_minus_utc_at_tai(leap_second) == 36_i128 * NANOSECONDS_PER_SECOND);
+        anyhow::ensure!(
+            tai_minus_utc_at_tai(leap_second + NANOSECONDS_PER_SECOND)
+                == 37_i128 * NANOSECONDS_PER_SECOND
+        );
+
+        anyhow::ensure!(nearby(1_483_228_790_000_000_000_i128) == Some(1_483_228_800_i128));
+        anyhow::ensure!(nearby(1_483_228_700_000_000_000_i128).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> anyhow::Result<()> {
+        let table = parse(
+            "#@	3960057600\n\
+             2272060800	10	# 1 Jan 1972\n\
+             3692217600	37	# 1 Jan 2017\n",
+        )?;
+
+        anyhow::ensure!(table == [(63_072_000_i128, 10_i128), (1_483_228_800_i128, 37_i128)]);
+
+        anyhow::ensure!(parse("# nothing but comments").is_err());
+        anyhow::ensure!(parse("3692217600 37\n2272060800 10").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,677 @@
+#![deny(clippy::all)]
+#![warn(clippy::pedantic)]
+
+pub mod annotate;
+pub mod binary;
+pub mod boot;
+pub mod bounds;
+pub mod color;
+pub mod column;
+pub mod config;
+pub mod countdown;
+pub mod date_time;
+pub mod dos;
+pub mod every;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fixture;
+pub mod ics;
+pub mod identifier;
+pub mod input;
+pub mod interpret;
+pub mod jwt;
+pub mod lap;
+pub mod leap_seconds;
+pub mod lifetime;
+pub mod locale;
+pub mod number;
+pub mod output;
+pub mod plugin;
+pub mod precision;
+pub mod preset;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "script")]
+pub mod script;
+pub mod series;
+#[cfg(feature = "cli")]
+pub mod serve;
+pub mod snowflake;
+pub mod sun;
+pub mod template;
+pub mod timing;
+pub mod totp;
+#[cfg(feature = "cli")]
+pub mod tui;
+pub mod tzinfo;
+pub mod validity;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod x509;
+pub mod zone;
+
+pub use zone::Zone;
+
+use std::{env, path::PathBuf, str::FromStr};
+use time::{Date, Month, OffsetDateTime};
+
+pub const COCOA: &str = "cocoa";
+pub const COCOA_NANOSECONDS: &str = "cocoa-nanos";
+pub const DAYS: &str = "days";
+pub const DOS: &str = "dos";
+pub const EXCEL: &str = "excel";
+pub const FILETIME: &str = "filetime";
+pub const GPS: &str = "gps";
+pub const HFS: &str = "hfs";
+pub const JULIAN_DAY: &str = "jd";
+pub const MICROSECONDS: &str = "microseconds";
+pub const MILLISECONDS: &str = "milliseconds";
+pub const MODIFIED_JULIAN_DAY: &str = "mjd";
+pub const NANOSECONDS: &str = "nanoseconds";
+pub const NTP: &str = "ntp";
+pub const SECONDS: &str = "seconds";
+pub const TAI: &str = "tai";
+pub const WEBKIT: &str = "webkit";
+
+const NANOSECONDS_PER_DAY: i128 = 86_400_000_000_000_i128;
+/// How many fractional digits values in units that count days are shown with (a millionth of a day is 86.4 ms)
+const DAY_FRACTION_DIGITS: u32 = 6_u32;
+/// Nanoseconds from the Unix epoch to 2001-01-01 00:00:00 UTC, which Apple's Cocoa and Core Data count from
+const COCOA_EPOCH_OFFSET_NANOSECONDS: i128 = 978_307_200_000_000_000_i128;
+/// Nanoseconds from the Unix epoch to 1899-12-30 00:00:00 UTC, which Excel and OLE Automation count days from
+const EXCEL_EPOCH_OFFSET_NANOSECONDS: i128 = -2_209_161_600_000_000_000_i128;
+/// Nanoseconds from the Excel epoch to 1900-02-28, after which Excel counts a 1900-02-29 that did not exist
+const EXCEL_LEAP_DAY_NANOSECONDS: i128 = 60_i128 * NANOSECONDS_PER_DAY;
+/// Nanoseconds from the Unix epoch to 1980-01-06 00:00:00 UTC, which GPS time counts from
+const GPS_EPOCH_OFFSET_NANOSECONDS: i128 = 315_964_800_000_000_000_i128;
+/// TAI − GPS time, which has not changed since GPS time started
+const TAI_MINUS_GPS_NANOSECONDS: i128 = 19_000_000_000_i128;
+/// Nanoseconds from the Unix epoch to 1904-01-01 00:00:00 UTC, which the classic Mac OS and HFS+ count from
+const HFS_EPOCH_OFFSET_NANOSECONDS: i128 = -2_082_844_800_000_000_000_i128;
+/// Nanoseconds from the Unix epoch to noon UTC on 4714-11-24 BC (proleptic Gregorian), Julian Day 0
+const JULIAN_DAY_EPOCH_OFFSET_NANOSECONDS: i128 = -210_866_760_000_000_000_000_i128;
+/// Nanoseconds from the Unix epoch to 1858-11-17 00:00:00 UTC, Modified Julian Day 0 (Julian Day 2400000.5)
+const MODIFIED_JULIAN_DAY_EPOCH_OFFSET_NANOSECONDS: i128 = -3_506_716_800_000_000_000_i128;
+/// Nanoseconds from the Unix epoch to 1900-01-01 00:00:00 UTC, which NTP counts from (in its first era, until 2036)
+const NTP_EPOCH_OFFSET_NANOSECONDS: i128 = -2_208_988_800_000_000_000_i128;
+/// NTP timestamps are 32.32 fixed point, so each tick is 2^-32 seconds
+const NTP_TICKS_PER_SECOND: i128 = 4_294_967_296_i128;
+/// Nanoseconds from the Unix epoch to 1601-01-01 00:00:00 UTC, the start of the Gregorian calendar's 400-year cycle
+/// that Windows (and Chrome) count from
+const WINDOWS_EPOCH_OFFSET_NANOSECONDS: i128 = -11_644_473_600_000_000_000_i128;
+
+/// A unit that a timestamp can be expressed in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Unit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+    /// Windows FILETIME: 100-nanosecond intervals since 1601-01-01 00:00:00 UTC, also used by Active Directory
+    /// attributes like `pwdLastSet` and `lastLogonTimestamp`
+    Filetime,
+    /// Microseconds since 1601-01-01 00:00:00 UTC, used by Chrome's history and cookie databases
+    Webkit,
+    /// Seconds since 2001-01-01 00:00:00 UTC, used by macOS and iOS property lists and Core Data stores
+    Cocoa,
+    /// Seconds since 1904-01-01 00:00:00 UTC, used by HFS+ and classic Mac OS file and resource fork timestamps
+    Hfs,
+    /// Days since 1970-01-01 00:00:00 UTC, used by the date fields of `/etc/shadow` (e.g. `sp_lstchg`)
+    Days,
+    /// Days (usually fractional) since 1899-12-30 00:00:00 UTC, the serial dates of Excel and OLE Automation, which
+    /// are never guessed
+    ///
+    /// Excel treats 1900 as a leap year, so serial dates before 60 (the nonexistent 1900-02-29) are a day later than
+    /// the epoch suggests.
+    Excel,
+    /// Seconds since 1980-01-06 00:00:00 UTC without leap seconds, used by GNSS receivers, which is never guessed
+    ///
+    /// GPS time is ahead of UTC by the leap seconds inserted since 1980 (18 since 2017).
+    Gps,
+    /// Seconds since 1970-01-01 00:00:00 TAI (International Atomic Time, as kept by `CLOCK_TAI`), which is never
+    /// guessed
+    ///
+    /// TAI is ahead of UTC by 10 seconds plus the leap seconds inserted since 1972 (37 seconds since 2017).
+    Tai,
+    /// NTP's 64-bit timestamps: 32.32 fixed point seconds since 1900-01-01 00:00:00 UTC, read as one integer (e.g.
+    /// 0xE9A1C3B580000000), which are never guessed
+    Ntp,
+    /// Days (usually fractional) since noon UTC on 4714-11-24 BC, the Julian Day used in astronomy, which is never
+    /// guessed
+    JulianDay,
+    /// Days (usually fractional) since 1858-11-17 00:00:00 UTC, the Modified Julian Day used in satellite telemetry,
+    /// which is never guessed
+    ModifiedJulianDay,
+    /// Nanoseconds since 2001-01-01 00:00:00 UTC, which is never guessed (any number up to 19 digits is a plausible
+    /// value)
+    CocoaNanoseconds,
+    /// MS-DOS packed dates and times, as stored by FAT file systems and ZIP archives (see [`dos`]), which are never
+    /// guessed
+    ///
+    /// Values are not counted from an epoch, so they pass through [`Unit::unix_nanoseconds`] unscaled and are
+    /// unpacked there.
+    Dos,
+}
+
+impl Unit {
+    pub const ALL: [Self; 17_usize] = [
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+        Self::Filetime,
+        Self::Webkit,
+        Self::Cocoa,
+        Self::CocoaNanoseconds,
+        Self::Hfs,
+        Self::Days,
+        Self::Excel,
+        Self::Gps,
+        Self::Tai,
+        Self::Ntp,
+        Self::JulianDay,
+        Self::ModifiedJulianDay,
+        Self::Dos,
+    ];
+
+    /// The unit this one is an alternative reading of, if it is only worth suggesting when that unit's reading is
+    /// implausible
+    #[must_use]
+    pub const fn alternative_to(self) -> Option<Self> {
+        match self {
+            Self::Webkit => Some(Self::Microseconds),
+            Self::Cocoa | Self::Days => Some(Self::Seconds),
+            _ => None,
+        }
+    }
+
+    /// Whether this unit counts the leap seconds that UTC skips over, so that conversions near a leap second are worth
+    /// pointing out
+    #[must_use]
+    pub const fn counts_leap_seconds(self) -> bool {
+        matches!(self, Self::Gps | Self::Tai)
+    }
+
+    /// Whether this unit is only used when asked for, because nearly every number would be plausible in it
+    #[must_use]
+    pub const fn is_only_on_request(self) -> bool {
+        matches!(
+            self,
+            Self::CocoaNanoseconds
+                | Self::Excel
+                | Self::Gps
+                | Self::Tai
+                | Self::Ntp
+                | Self::JulianDay
+                | Self::ModifiedJulianDay
+                | Self::Dos
+        )
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Seconds => SECONDS,
+            Self::Milliseconds => MILLISECONDS,
+            Self::Microseconds => MICROSECONDS,
+            Self::Nanoseconds => NANOSECONDS,
+            Self::Filetime => FILETIME,
+            Self::Webkit => WEBKIT,
+            Self::Cocoa => COCOA,
+            Self::CocoaNanoseconds => COCOA_NANOSECONDS,
+            Self::Hfs => HFS,
+            Self::Days => DAYS,
+            Self::Excel => EXCEL,
+            Self::Gps => GPS,
+            Self::Tai => TAI,
+            Self::Ntp => NTP,
+            Self::JulianDay => JULIAN_DAY,
+            Self::ModifiedJulianDay => MODIFIED_JULIAN_DAY,
+            Self::Dos => DOS,
+        }
+    }
+
+    /// What one of this unit is called, in the singular
+    #[must_use]
+    pub const fn tick_name(self) -> &'static str {
+        match self {
+            Self::Seconds | Self::Cocoa | Self::Hfs | Self::Gps | Self::Tai => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds | Self::Webkit => "microsecond",
+            Self::Nanoseconds | Self::CocoaNanoseconds => "nanosecond",
+            Self::Filetime => "100-nanosecond interval",
+            Self::Days | Self::Excel | Self::JulianDay | Self::ModifiedJulianDay => "day",
+            Self::Ntp => "2^32 ticks",
+            Self::Dos => "packed date and time",
+        }
+    }
+
+    /// The instant this unit counts from
+    #[must_use]
+    pub const fn epoch_name(self) -> &'static str {
+        match self {
+            Self::Seconds
+            | Self::Milliseconds
+            | Self::Microseconds
+            | Self::Nanoseconds
+            | Self::Days => "the Unix epoch (1970-01-01 00:00:00 UTC)",
+            Self::Filetime | Self::Webkit => "the Windows epoch (1601-01-01 00:00:00 UTC)",
+            Self::Cocoa | Self::CocoaNanoseconds => "the Cocoa epoch (2001-01-01 00:00:00 UTC)",
+            Self::Hfs => "the classic Mac OS epoch (1904-01-01 00:00:00 UTC)",
+            Self::Excel => "the Excel epoch (1899-12-30 00:00:00 UTC)",
+            Self::Gps => "the GPS epoch (1980-01-06 00:00:00 UTC)",
+            Self::Tai => "1970-01-01 00:00:00 TAI",
+            Self::Ntp => "the NTP epoch (1900-01-01 00:00:00 UTC)",
+            Self::JulianDay => {
+                "the Julian Day epoch (4714-11-24 BC 12:00:00 UTC, proleptic Gregorian)"
+            }
+            Self::ModifiedJulianDay => "the Modified Julian Day epoch (1858-11-17 00:00:00 UTC)",
+            Self::Dos => "1980-01-01 00:00:00, the earliest packed date (read as UTC)",
+        }
+    }
+
+    /// Nanoseconds from the Unix epoch to the instant this unit counts from
+    #[must_use]
+    pub const fn epoch_offset_nanoseconds(self) -> i128 {
+        match self {
+            Self::Seconds
+            | Self::Milliseconds
+            | Self::Microseconds
+            | Self::Nanoseconds
+            | Self::Days
+            | Self::Tai
+            | Self::Dos => 0_i128,
+            Self::Filetime | Self::Webkit => WINDOWS_EPOCH_OFFSET_NANOSECONDS,
+            Self::Cocoa | Self::CocoaNanoseconds => COCOA_EPOCH_OFFSET_NANOSECONDS,
+            Self::Hfs => HFS_EPOCH_OFFSET_NANOSECONDS,
+            Self::Excel => EXCEL_EPOCH_OFFSET_NANOSECONDS,
+            Self::Gps => GPS_EPOCH_OFFSET_NANOSECONDS,
+            Self::Ntp => NTP_EPOCH_OFFSET_NANOSECONDS,
+            Self::JulianDay => JULIAN_DAY_EPOCH_OFFSET_NANOSECONDS,
+            Self::ModifiedJulianDay => MODIFIED_JULIAN_DAY_EPOCH_OFFSET_NANOSECONDS,
+        }
+    }
+
+    /// What [`Unit::unix_nanoseconds`] corrects for on top of the epoch offset, for units that need a correction
+    #[must_use]
+    pub const fn correction_name(self) -> Option<&'static str> {
+        match self {
+            Self::Excel => Some("Excel counting a 1900-02-29 that did not exist"),
+            Self::Gps => Some("the leap seconds inserted into UTC, which GPS time does not have"),
+            Self::Tai => Some("the leap seconds inserted into UTC, which TAI does not have"),
+            _ => None,
+        }
+    }
+
+    /// Nanoseconds since the Unix epoch of the instant `nanoseconds` after this unit's epoch, or `None` if that does
+    /// not fit in an `i128`
+    #[must_use]
+    pub fn unix_nanoseconds(self, nanoseconds: i128) -> Option<i128> {
+        let corrected = match self {
+            Self::Excel if nanoseconds < EXCEL_LEAP_DAY_NANOSECONDS => {
+                nanoseconds.checked_add(NANOSECONDS_PER_DAY)?
+            }
+            _ => nanoseconds,
+        };
+
+        let linear = corrected.checked_add(self.epoch_offset_nanoseconds())?;
+
+        match self {
+            // GPS time keeps counting through the leap seconds that UTC inserts
+            Self::Gps => linear.checked_add(TAI_MINUS_GPS_NANOSECONDS).map(|io| {
+                linear - (leap_seconds::tai_minus_utc_at_tai(io) - TAI_MINUS_GPS_NANOSECONDS)
+            }),
+            Self::Tai => Some(linear - leap_seconds::tai_minus_utc_at_tai(linear)),
+            Self::Dos => dos::decode(nanoseconds)
+                .ok()
+                .map(OffsetDateTime::unix_timestamp_nanos),
+            _ => Some(linear),
+        }
+    }
+
+    /// The value of the instant `unix_nanoseconds` (nanoseconds since the Unix epoch) in this unit, rounded down
+    ///
+    /// DOS packed dates and times are clamped to the range they can represent.
+    #[must_use]
+    pub fn from_unix_nanoseconds(self, unix_nanoseconds: i128) -> i128 {
+        if self == Self::Dos {
+            let clamped = unix_nanoseconds.clamp(
+                dos::MINIMUM.unix_timestamp_nanos(),
+                dos::MAXIMUM.unix_timestamp_nanos(),
+            );
+
+            return OffsetDateTime::from_unix_timestamp_nanos(clamped)
+                .map_or(0_i128, |of| i128::from(dos::encode(of)));
+        }
+
+        let nanoseconds = unix_nanoseconds - self.epoch_offset_nanoseconds();
+
+        let corrected = match self {
+            Self::Excel if nanoseconds < EXCEL_LEAP_DAY_NANOSECONDS + NANOSECONDS_PER_DAY => {
+                nanoseconds - NANOSECONDS_PER_DAY
+            }
+            Self::Gps => {
+                nanoseconds + leap_seconds::tai_minus_utc(unix_nanoseconds)
+                    - TAI_MINUS_GPS_NANOSECONDS
+            }
+            Self::Tai => nanoseconds + leap_seconds::tai_minus_utc(unix_nanoseconds),
+            _ => nanoseconds,
+        };
+
+        (corrected * self.tick_divisor()).div_euclid(self.nanoseconds_per_tick())
+    }
+
+    #[must_use]
+    pub const fn nanoseconds_per_tick(self) -> i128 {
+        match self {
+            Self::Seconds | Self::Cocoa | Self::Hfs | Self::Gps | Self::Tai | Self::Ntp => {
+                1_000_000_000_i128
+            }
+            Self::Milliseconds => 1_000_000_i128,
+            Self::Microseconds | Self::Webkit => 1_000_i128,
+            Self::Nanoseconds | Self::CocoaNanoseconds | Self::Dos => 1_i128,
+            Self::Filetime => 100_i128,
+            Self::Days | Self::Excel | Self::JulianDay | Self::ModifiedJulianDay => {
+                NANOSECONDS_PER_DAY
+            }
+        }
+    }
+
+    /// What [`Unit::nanoseconds_per_tick`] is divided by, for units whose ticks are not a whole number of nanoseconds
+    #[must_use]
+    pub const fn tick_divisor(self) -> i128 {
+        match self {
+            Self::Ntp => NTP_TICKS_PER_SECOND,
+            _ => 1_i128,
+        }
+    }
+
+    /// Like [`Unit::from_unix_nanoseconds`], but units that count days keep up to six fractional digits, rounded to the
+    /// nearest (e.g. "2460432.5" rather than "2460432"), and DOS packed dates and times are also shown in hexadecimal
+    #[must_use]
+    pub fn format_from_unix_nanoseconds(self, unix_nanoseconds: i128) -> String {
+        if self == Self::Dos {
+            if !(dos::MINIMUM.unix_timestamp_nanos()
+                ..=dos::MAXIMUM.unix_timestamp_nanos() + 1_999_999_999_i128)
+                .contains(&unix_nanoseconds)
+            {
+                return "out of range (1980 through 2107)".to_owned();
+            }
+
+            let packed = self.from_unix_nanoseconds(unix_nanoseconds);
+
+            return format!("{packed} (0x{packed:08X})");
+        }
+
+        if self.nanoseconds_per_tick() != NANOSECONDS_PER_DAY {
+            return self.from_unix_nanoseconds(unix_nanoseconds).to_string();
+        }
+
+        let scale = 10_u128.pow(DAY_FRACTION_DIGITS);
+
+        #[expect(clippy::cast_possible_wrap, reason = "Unimportant")]
+        let scaled = self.scaled_from_unix_nanoseconds(unix_nanoseconds, scale as i128);
+
+        let sign = if scaled < 0_i128 { "-" } else { "" };
+
+        let whole = scaled.unsigned_abs() / scale;
+        let fraction = scaled.unsigned_abs() % scale;
+
+        if fraction == 0_u128 {
+            return format!("{sign}{whole}");
+        }
+
+        let fraction_string = format!("{fraction:0width$}", width = DAY_FRACTION_DIGITS as usize);
+
+        format!("{sign}{whole}.{}", fraction_string.trim_end_matches('0'))
+    }
+
+    /// The value of `unix_nanoseconds` in this unit multiplied by `scale`, rounded to the nearest (halves up)
+    fn scaled_from_unix_nanoseconds(self, unix_nanoseconds: i128, scale: i128) -> i128 {
+        let per_tick = self.nanoseconds_per_tick();
+
+        let ticks = self.from_unix_nanoseconds(unix_nanoseconds);
+
+        // What is left over after the whole ticks, which is the same for every unit since the leap day and leap second
+        // corrections are whole ticks
+        let remainder = (unix_nanoseconds - self.epoch_offset_nanoseconds()).rem_euclid(per_tick);
+
+        ticks * scale + (remainder * scale + per_tick / 2_i128) / per_tick
+    }
+}
+
+impl FromStr for Unit {
+    type Err = anyhow::Error;
+
+    /// Parses a unit's name or its abbreviation ("s", "ms", "us", or "ns", "ad" or "ldap" for FILETIME, "chrome" for
+    /// `WebKit`, "apple" or "core-data" for Cocoa, "cocoa-ns" for Cocoa nanoseconds, "hfs+" or "mac" for HFS+, "d",
+    /// "day", or "shadow" for days, "ole" for Excel, "julian-day" or "modified-julian-day" for Julian Days, and "fat"
+    /// or "zip" for DOS packed dates and times)
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let unit = match st.to_ascii_lowercase().as_str() {
+            "s" | SECONDS => Self::Seconds,
+            "ms" | MILLISECONDS => Self::Milliseconds,
+            "us" | MICROSECONDS => Self::Microseconds,
+            "ns" | NANOSECONDS => Self::Nanoseconds,
+            "ad" | "ldap" | FILETIME => Self::Filetime,
+            "chrome" | WEBKIT => Self::Webkit,
+            "apple" | "core-data" | COCOA => Self::Cocoa,
+            "cocoa-ns" | COCOA_NANOSECONDS => Self::CocoaNanoseconds,
+            "hfs+" | "mac" | HFS => Self::Hfs,
+            "d" | "day" | "shadow" | DAYS => Self::Days,
+            "ole" | EXCEL => Self::Excel,
+            GPS => Self::Gps,
+            TAI => Self::Tai,
+            NTP => Self::Ntp,
+            "julian-day" | JULIAN_DAY => Self::JulianDay,
+            "modified-julian-day" | MODIFIED_JULIAN_DAY => Self::ModifiedJulianDay,
+            "fat" | "zip" | DOS => Self::Dos,
+            _ => anyhow::bail!(
+                "Unrecognized unit \"{st}\" (expected \"{SECONDS}\", \"{MILLISECONDS}\", \"{MICROSECONDS}\", \"{NANOSECONDS}\", \"{FILETIME}\", \"{WEBKIT}\", \"{COCOA}\", \"{COCOA_NANOSECONDS}\", \"{HFS}\", \"{DAYS}\", \"{EXCEL}\", \"{GPS}\", \"{TAI}\", \"{NTP}\", \"{JULIAN_DAY}\", \"{MODIFIED_JULIAN_DAY}\", or \"{DOS}\")"
+            ),
+        };
+
+        Ok(unit)
+    }
+}
+
+/// The values in `unit` that correspond to instants from the start of `start_year` through the end of `end_year` (UTC)
+pub struct UnitRange {
+    pub unit: Unit,
+    pub start: i128,
+    pub end: i128,
+}
+
+impl UnitRange {
+    #[must_use]
+    pub fn start_digits(&self) -> usize {
+        count_digits(self.start)
+    }
+
+    #[must_use]
+    pub fn end_digits(&self) -> usize {
+        count_digits(self.end)
+    }
+}
+
+/// Computes, for every supported unit, the range of values that fall in the years `start_year` through `end_year`
+///
+/// # Errors
+///
+/// Returns an error if `start_year` is greater than `end_year`, or if either year cannot be represented
+pub fn unit_ranges(start_year: i32, end_year: i32) -> anyhow::Result<Vec<UnitRange>> {
+    anyhow::ensure!(
+        start_year <= end_year,
+        "Start year {start_year} is after end year {end_year}"
+    );
+
+    let start_nanos = year_start_nanos(start_year)?;
+    let end_nanos_exclusive = year_start_nanos(
+        end_year
+            .checked_add(1_i32)
+            .ok_or_else(|| anyhow::anyhow!("End year {end_year} is too large"))?,
+    )?;
+
+    let unit_range_vec = Unit::ALL
+        .into_iter()
+        .map(|un| UnitRange {
+            unit: un,
+            start: un.from_unix_nanoseconds(start_nanos),
+            end: un.from_unix_nanoseconds(end_nanos_exclusive - 1_i128),
+        })
+        .collect();
+
+    Ok(unit_range_vec)
+}
+
+fn year_start_nanos(year: i32) -> anyhow::Result<i128> {
+    let date = Date::from_calendar_date(year, Month::January, 1_u8)?;
+
+    Ok(date.midnight().assume_utc().unix_timestamp_nanos())
+}
+
+fn count_digits(value: i128) -> usize {
+    value.unsigned_abs().to_string().len()
+}
+
+/// `$XDG_STATE_HOME/readtimestamp` (or `~/.local/state/readtimestamp`), where state that outlives a single run is kept
+fn state_directory() -> anyhow::Result<PathBuf> {
+    let state_directory = match env::var_os("XDG_STATE_HOME") {
+        Some(os) if !os.is_empty() => PathBuf::from(os),
+        _ => PathBuf::from(
+            env::var_os("HOME")
+                .ok_or_else(|| anyhow::anyhow!("Could not determine the home directory"))?,
+        )
+        .join(".local")
+        .join("state"),
+    };
+
+    Ok(state_directory.join("readtimestamp"))
+}
+
+/// The file the lines entered in interactive mode are kept in, `$XDG_STATE_HOME/readtimestamp/history` (or
+/// `~/.local/state/readtimestamp/history`)
+///
+/// # Errors
+///
+/// Returns an error if the home directory cannot be determined
+pub fn history_path() -> anyhow::Result<PathBuf> {
+    Ok(state_directory()?.join("history"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Unit;
+
+    #[test]
+    fn test_unit_from_str() -> anyhow::Result<()> {
+        anyhow::ensure!("ms".parse::<Unit>()? == Unit::Milliseconds);
+        anyhow::ensure!("Seconds".parse::<Unit>()? == Unit::Seconds);
+        anyhow::ensure!("ad".parse::<Unit>()? == Unit::Filetime);
+        anyhow::ensure!("excel".parse::<Unit>()? == Unit::Excel);
+        anyhow::ensure!("MJD".parse::<Unit>()? == Unit::ModifiedJulianDay);
+        anyhow::ensure!("zip".parse::<Unit>()? == Unit::Dos);
+        anyhow::ensure!("shadow".parse::<Unit>()? == Unit::Days);
+        anyhow::ensure!("weeks".parse::<Unit>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unit_ranges() -> anyhow::Result<()> {
+        let unit_range_vec = crate::unit_ranges(2000_i32, 2099_i32)?;
+
+        let seconds = &unit_range_vec[0_usize];
+
+        anyhow::ensure!(seconds.start == 946_684_800_i128);
+        anyhow::ensure!(seconds.end == 4_102_444_799_i128);
+
+        let nanoseconds = &unit_range_vec[3_usize];
+
+        anyhow::ensure!(nanoseconds.start == 946_684_800_000_000_000_i128);
+        anyhow::ensure!(nanoseconds.end == 4_102_444_799_999_999_999_i128);
+
+        let filetime = &unit_range_vec[4_usize];
+
+        anyhow::ensure!(filetime.start == 125_911_584_000_000_000_i128);
+
+        anyhow::ensure!(crate::unit_ranges(2099_i32, 2000_i32).is_err());
+        anyhow::ensure!(crate::unit_ranges(2000_i32, i32::MAX).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leap_second_units() -> anyhow::Result<()> {
+        anyhow::ensure!(Unit::Gps.unix_nanoseconds(0_i128) == Some(315_964_800_000_000_000_i128));
+
+        // 18 leap seconds behind by 2024
+        anyhow::ensure!(
+            Unit::Gps.unix_nanoseconds(1_400_000_000_000_000_000_i128)
+                == Some(1_715_964_782_000_000_000_i128)
+        );
+        anyhow::ensure!(
+            Unit::Gps.from_unix_nanoseconds(1_715_964_782_000_000_000_i128) == 1_400_000_000_i128
+        );
+
+        // TAI is 19 seconds ahead of GPS time
+        anyhow::ensure!(
+            Unit::Tai.unix_nanoseconds(1_715_964_837_000_000_000_i128)
+                == Some(1_715_964_800_000_000_000_i128)
+        );
+        anyhow::ensure!(
+            Unit::Tai.from_unix_nanoseconds(1_715_964_800_000_000_000_i128) == 1_715_964_837_i128
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ntp() -> anyhow::Result<()> {
+        // 0xE9A1C3B5.80000000, half a second past 2024-03-17 19:32:05 UTC
+        let unix_nanoseconds = 1_710_703_925_500_000_000_i128;
+
+        anyhow::ensure!(
+            crate::number::Number::from_integer(0xE9A1_C3B5_8000_0000_i128)
+                .unix_nanoseconds(Unit::Ntp)
+                == Some(unix_nanoseconds)
+        );
+        anyhow::ensure!(
+            Unit::Ntp.from_unix_nanoseconds(unix_nanoseconds) == 0xE9A1_C3B5_8000_0000_i128
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_from_unix_nanoseconds() -> anyhow::Result<()> {
+        // 2024-05-01 00:00:00 UTC
+        let unix_nanoseconds = 1_714_521_600_000_000_000_i128;
+
+        anyhow::ensure!(
+            Unit::JulianDay.format_from_unix_nanoseconds(unix_nanoseconds) == "2460431.5"
+        );
+        anyhow::ensure!(
+            Unit::ModifiedJulianDay.format_from_unix_nanoseconds(unix_nanoseconds) == "60431"
+        );
+        anyhow::ensure!(
+            Unit::ModifiedJulianDay
+                .format_from_unix_nanoseconds(unix_nanoseconds + 21_600_000_000_000_i128)
+                == "60431.25"
+        );
+        // 2023-11-14 22:13:20 UTC is 0.9259259... days past midnight
+        anyhow::ensure!(
+            Unit::ModifiedJulianDay.format_from_unix_nanoseconds(1_700_000_000_000_000_000_i128)
+                == "60262.925926"
+        );
+        anyhow::ensure!(
+            Unit::ModifiedJulianDay.format_from_unix_nanoseconds(-3_528_316_800_000_000_000_i128)
+                == "-250"
+        );
+        anyhow::ensure!(
+            Unit::Seconds.format_from_unix_nanoseconds(unix_nanoseconds + 1_i128) == "1714521600"
+        );
+
+        Ok(())
+    }
+}
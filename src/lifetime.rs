@@ -0,0 +1,132 @@
+use time::{Duration, OffsetDateTime};
+
+/// Where a token is in its lifetime
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// Issued in the future
+    NotYetValid,
+    Valid,
+    /// Valid, but more of its lifetime has been consumed than the warning threshold
+    Warning,
+    /// More of its lifetime has been consumed than the critical threshold (100% by default)
+    Expired,
+}
+
+impl Status {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::NotYetValid => "NOT YET VALID",
+            Self::Valid => "VALID",
+            Self::Warning => "WARNING",
+            Self::Expired => "EXPIRED",
+        }
+    }
+}
+
+/// The lifetime budget of a session, token, or anything else that is issued at one instant and valid for a fixed
+/// duration
+pub struct Lifetime {
+    pub issued: OffsetDateTime,
+    pub expires: OffsetDateTime,
+    /// Negative once the lifetime has been used up
+    pub remaining: Duration,
+    pub consumed_percent: f64,
+    pub status: Status,
+}
+
+impl Lifetime {
+    /// Computes how much of `lifetime` has been consumed at `now`
+    ///
+    /// `warn_percent` and `critical_percent` are the percentages of the lifetime after which the status becomes
+    /// [`Status::Warning`] and [`Status::Expired`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lifetime would end after the last instant that can be represented
+    pub fn new(
+        issued: OffsetDateTime,
+        lifetime: Duration,
+        now: OffsetDateTime,
+        warn_percent: f64,
+        critical_percent: f64,
+    ) -> anyhow::Result<Self> {
+        let expires = issued
+            .checked_add(lifetime)
+            .ok_or_else(|| anyhow::anyhow!("The lifetime ends too far in the future"))?;
+
+        let remaining = expires - now;
+
+        let lifetime_seconds = lifetime.as_seconds_f64();
+
+        let consumed_percent = if lifetime_seconds > 0_f64 {
+            (now - issued).as_seconds_f64() / lifetime_seconds * 100_f64
+        } else {
+            100_f64
+        };
+
+        let status = if now < issued {
+            Status::NotYetValid
+        } else if consumed_percent >= critical_percent {
+            Status::Expired
+        } else if consumed_percent >= warn_percent {
+            Status::Warning
+        } else {
+            Status::Valid
+        };
+
+        Ok(Self {
+            issued,
+            expires,
+            remaining,
+            consumed_percent,
+            status,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lifetime::{Lifetime, Status};
+    use time::{macros::datetime, Duration};
+
+    #[test]
+    fn test_lifetime() -> anyhow::Result<()> {
+        let issued = datetime!(2024-05-01 12:00 UTC);
+
+        let lifetime = Lifetime::new(
+            issued,
+            Duration::HOUR,
+            datetime!(2024-05-01 12:45 UTC),
+            80_f64,
+            100_f64,
+        )?;
+
+        anyhow::ensure!(lifetime.expires == datetime!(2024-05-01 13:00 UTC));
+        anyhow::ensure!(lifetime.remaining == Duration::minutes(15_i64));
+        anyhow::ensure!((lifetime.consumed_percent - 75_f64).abs() < 0.001_f64);
+        anyhow::ensure!(lifetime.status == Status::Valid);
+
+        let late = Lifetime::new(
+            issued,
+            Duration::HOUR,
+            datetime!(2024-05-01 13:30 UTC),
+            80_f64,
+            100_f64,
+        )?;
+
+        anyhow::ensure!(late.status == Status::Expired);
+        anyhow::ensure!(late.remaining.is_negative());
+
+        anyhow::ensure!(Lifetime::new(
+            datetime!(9999-12-31 23:46:40 UTC),
+            Duration::HOUR,
+            issued,
+            80_f64,
+            100_f64,
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! Dates and times written the way a locale writes them, with its weekday and month names and its order of fields
+
+use crate::precision::Precision;
+use icu_calendar::DateTime;
+use icu_datetime::{options::length, DateTimeFormatter};
+use std::{env, fmt, str::FromStr};
+use time::OffsetDateTime;
+
+/// The environment variable the locale is read from when none is given
+pub const LOCALE_ENVIRONMENT_VARIABLE: &str = "LC_TIME";
+
+/// A locale, e.g. "de-DE"
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Locale(icu_locid::Locale);
+
+impl Locale {
+    /// The locale in [`LOCALE_ENVIRONMENT_VARIABLE`], unless it is unset, empty, "C", "POSIX", or invalid
+    ///
+    /// Only `LC_TIME` is read (not `LC_ALL` or `LANG`), since it is rarely set without meaning to change how dates
+    /// are written.
+    #[must_use]
+    pub fn from_environment() -> Option<Self> {
+        env::var(LOCALE_ENVIRONMENT_VARIABLE)
+            .ok()
+            .and_then(|st| st.parse::<Self>().ok())
+    }
+
+    /// Formats `offset_date_time` in its own offset, with a weekday and month name (e.g. "Dienstag, 14. November
+    /// 2023, 22:13:20" in "de-DE"), leaving out the seconds if `precision` is [`Precision::Minutes`]
+    ///
+    /// Fractions of a second are never shown, since locales do not say how to write them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the year is out of the range that can be formatted
+    pub fn format(
+        &self,
+        offset_date_time: OffsetDateTime,
+        precision: Option<Precision>,
+    ) -> anyhow::Result<String> {
+        let time = if precision == Some(Precision::Minutes) {
+            length::Time::Short
+        } else {
+            length::Time::Medium
+        };
+
+        let date_time_formatter = DateTimeFormatter::try_new(
+            &(&self.0).into(),
+            length::Bag::from_date_time_style(length::Date::Full, time).into(),
+        )
+        .map_err(|er| anyhow::anyhow!("Could not format dates in \"{self}\": {er}"))?;
+
+        let date_time = DateTime::try_new_iso_datetime(
+            offset_date_time.year(),
+            u8::from(offset_date_time.month()),
+            offset_date_time.day(),
+            offset_date_time.hour(),
+            offset_date_time.minute(),
+            offset_date_time.second(),
+        )
+        .map_err(|er| anyhow::anyhow!("Could not format {offset_date_time}: {er}"))?;
+
+        date_time_formatter
+            .format_to_string(&date_time.to_any())
+            .map_err(|er| anyhow::anyhow!("Could not format {offset_date_time}: {er}"))
+    }
+}
+
+impl FromStr for Locale {
+    type Err = anyhow::Error;
+
+    /// Accepts BCP 47 language tags (`de-DE`) and POSIX locale names (`de_DE.UTF-8` or `de_DE@euro`)
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let language_tag = st
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('_', "-");
+
+        anyhow::ensure!(
+            !matches!(language_tag.as_str(), "" | "C" | "POSIX"),
+            "\"{st}\" does not name a language"
+        );
+
+        let locale = language_tag
+            .parse::<icu_locid::Locale>()
+            .map_err(|er| anyhow::anyhow!("Unrecognized locale \"{st}\": {er}"))?;
+
+        Ok(Self(locale))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(fo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{locale::Locale, precision::Precision};
+    use time::macros::datetime;
+
+    #[test]
+    fn test_locale() -> anyhow::Result<()> {
+        let german = "de_DE.UTF-8".parse::<Locale>()?;
+
+        anyhow::ensure!(german == "de-DE".parse::<Locale>()?);
+        anyhow::ensure!(
+            german.format(datetime!(2023-11-14 22:13:20.5 UTC), None)?
+                == "Dienstag, 14. November 2023, 22:13:20"
+        );
+        anyhow::ensure!(
+            "fr".parse::<Locale>()?
+                .format(datetime!(2023-11-14 22:13:20 UTC), Some(Precision::Minutes))?
+                == "mardi 14 novembre 2023, 22:13"
+        );
+
+        anyhow::ensure!("C.UTF-8".parse::<Locale>().is_err());
+        anyhow::ensure!("not a locale".parse::<Locale>().is_err());
+
+        Ok(())
+    }
+}
@@ -1,456 +1,3946 @@
 #![deny(clippy::all)]
 #![warn(clippy::pedantic)]
 
-use clap::Parser;
-use owo_colors::OwoColorize;
+use arboard::Clipboard;
+use clap::{builder::FalseyValueParser, ArgAction, CommandFactory as _, Parser, Subcommand};
+use clap_complete::Shell;
+use readtimestamp::{
+    annotate::{Annotator, DeltaSelector},
+    binary::Endian,
+    bounds::Truncation,
+    color::Colorize,
+    column::Direction,
+    config::Config,
+    countdown::State,
+    date_time::{
+        business_days_between, fiscal_quarter, format_calendar_info, parse_date_time,
+        parse_duration,
+    },
+    every::Schedule,
+    fixture::Shape,
+    ics::Event,
+    identifier::{parse_identifier, parse_uuid_version},
+    input::Base64Timestamp,
+    interpret::{Candidate, CandidateError, Settings},
+    jwt::Claims,
+    lifetime::{Lifetime, Status},
+    locale::Locale,
+    number::Number,
+    output::{OutputFormat, Record},
+    plugin::TimestampFormat,
+    precision::Precision,
+    preset::{Preset, PRESETS},
+    snowflake::{Bits, Layout, Snowflake},
+    sun::{Daylight, Location},
+    template::Template,
+    timing::Timing,
+    tzinfo::{LocalType, Transition},
+    validity::Verdict,
+    zone::{format_offset, WallClock},
+    Unit, Zone, COCOA, COCOA_NANOSECONDS, DAYS, DOS, EXCEL, FILETIME, GPS, HFS, JULIAN_DAY,
+    MICROSECONDS, MILLISECONDS, MODIFIED_JULIAN_DAY, NANOSECONDS, NTP, SECONDS, TAI, WEBKIT,
+};
 use regex::Regex;
-use std::env;
+use rustyline::{error::ReadlineError, DefaultEditor};
+use std::{
+    env,
+    fmt::Write as _,
+    fs::{self, File},
+    io::{self, BufRead as _, Write as _},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Instant,
+};
+use terminal_size::Width;
 use time::{
-    error::ComponentRange, format_description::FormatItem, macros::format_description,
-    OffsetDateTime, UtcOffset,
+    format_description::{well_known::Rfc3339, FormatItem},
+    macros::format_description,
+    Date, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset,
 };
-use timeago::{Formatter, TimeUnit};
+use timeago::{
+    languages::{
+        chinese::Chinese, english::English, french::French, german::German, italian::Italian,
+        japanese::Japanese, polish::Polish, portuguese::Portuguese, russian::Russian,
+        swedish::Swedish, ukrainian::Ukrainian,
+    },
+    BoxedLanguage, Formatter, TimeUnit,
+};
+use tracing::level_filters::LevelFilter;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 /// Pretty print a Unix timestamp (seconds or milliseconds)
 #[derive(Parser)]
-#[command(author, version, about)]
+#[expect(clippy::struct_excessive_bools, reason = "Unimportant")]
+#[command(
+    allow_negative_numbers = true,
+    args_conflicts_with_subcommands = true,
+    author,
+    subcommand_negates_reqs = true,
+    version,
+    about
+)]
 struct ReadtimestampArgs {
+    #[command(subcommand)]
+    command: Option<ReadtimestampCommand>,
+
     /// The Unix timestamp to parse (can be in seconds, milliseconds, or nanoseconds)
-    timestamp: String,
+    #[arg(required_unless_present_any = ["cert", "clipboard", "generate_man", "interactive"])]
+    timestamp: Option<String>,
+
+    /// Show the validity period of the X.509 certificates in this PEM file ("-" for standard input) instead
+    #[arg(conflicts_with = "timestamp", long)]
+    cert: Option<PathBuf>,
+
+    /// Keep running and read timestamps one per line, with line editing and history (kept in
+    /// ~/.local/state/readtimestamp/history), until Ctrl+D
+    #[arg(conflicts_with_all = ["cert", "timestamp"], long, short)]
+    interactive: bool,
+
+    /// Parse the text on the system clipboard instead, e.g. a value just copied from a log viewer
+    #[arg(conflicts_with_all = ["cert", "interactive", "timestamp"], long)]
+    clipboard: bool,
+
+    /// Show the arithmetic performed for each interpretation (scale, epoch origin, and offset applied)
+    #[arg(env = "READTIMESTAMP_EXPLAIN", long, value_parser = FalseyValueParser::new())]
+    explain: bool,
+
+    /// Adapt the displayed precision to how far away each instant is: milliseconds (and an exact offset) within a few
+    /// seconds of now, and no seconds at all a year or more away
+    #[arg(env = "READTIMESTAMP_AUTO_PRECISION", long, value_parser = FalseyValueParser::new())]
+    auto_precision: bool,
+
+    /// The smallest unit relative times are broken down into (ns, us, ms, s, min, h, d, w, mo, or y)
+    #[arg(default_value = "ms", env = "READTIMESTAMP_PRECISION", long, value_parser = parse_time_unit)]
+    precision: TimeUnit,
+
+    /// How many units relative times are broken down into at most (e.g. 1 for "1 year ago" instead of "1 year 2
+    /// months 3 days 4 hours 5 minutes ago")
+    #[arg(default_value_t = 5_u8, env = "READTIMESTAMP_RELATIVE_UNITS", long, value_parser = clap::value_parser!(u8).range(1_i64..))]
+    relative_units: u8,
+
+    /// The language relative times are written in (en, de, fr, it, ja, pl, pt, ru, sv, uk, or zh)
+    #[arg(default_value = "en", env = "READTIMESTAMP_LANG", long)]
+    lang: RelativeLanguage,
+
+    /// Also show each instant's Julian Day, Modified Julian Day, ISO 8601 week date, ordinal date (e.g. "2024-W18-3"
+    /// and "2024-122"), and quarter
+    #[arg(env = "READTIMESTAMP_CALENDAR_INFO", long, value_parser = FalseyValueParser::new())]
+    calendar_info: bool,
+
+    /// Also show each instant's ISO 8601 week date (e.g. "2024-W18-3"), without the rest of --calendar-info
+    #[arg(env = "READTIMESTAMP_WEEK", long, value_parser = FalseyValueParser::new())]
+    week: bool,
+
+    /// Also show each instant's ordinal date (e.g. "2024-122", day 122 of 366), without the rest of --calendar-info
+    #[arg(env = "READTIMESTAMP_ORDINAL", long, value_parser = FalseyValueParser::new())]
+    ordinal: bool,
+
+    /// Also show how many business days (Monday through Friday, except the holidays listed in the configuration file)
+    /// each instant is from now, counting by the local date
+    #[arg(env = "READTIMESTAMP_BUSINESS_DAYS", long, value_parser = FalseyValueParser::new())]
+    business_days: bool,
+
+    /// Also show the best candidate's Modified Julian Date and Julian Date
+    #[arg(env = "READTIMESTAMP_MJD", long, visible_alias = "jd", value_parser = FalseyValueParser::new())]
+    mjd: bool,
+
+    /// Also show the best candidate as Unix seconds, milliseconds, microseconds, and nanoseconds
+    #[arg(env = "READTIMESTAMP_CONVERT", long, value_parser = FalseyValueParser::new())]
+    convert: bool,
+
+    /// Copy the best candidate to the clipboard as RFC 3339 in UTC (or, for a date and time, its value in the unit
+    /// given with --unit, or in seconds)
+    #[arg(env = "READTIMESTAMP_COPY", long, value_parser = FalseyValueParser::new())]
+    copy: bool,
+
+    /// Print a calendar event in iCalendar format starting at the best candidate, instead of the candidates, e.g. to
+    /// add an instant from a ticket to a calendar
+    #[arg(conflicts_with_all = ["output", "porcelain", "template"], env = "READTIMESTAMP_ICS", long, value_parser = FalseyValueParser::new())]
+    ics: bool,
+
+    /// How long the event printed with --ics lasts (e.g. "30m" or "1h30m"; default: no time at all)
+    #[arg(long, requires = "ics", value_parser = parse_duration)]
+    duration: Option<time::Duration>,
+
+    /// The title of the event printed with --ics (default: the timestamp and the unit it was read in)
+    #[arg(long, requires = "ics")]
+    summary: Option<String>,
+
+    /// Also show each instant's fiscal year and quarter, for fiscal years starting in this month (1 through 12, e.g.
+    /// "04" for April), named for the calendar year they end in
+    #[arg(env = "READTIMESTAMP_FISCAL_START", long, value_parser = clap::value_parser!(u8).range(1_i64..=12_i64))]
+    fiscal_start: Option<u8>,
+
+    /// Also show whether each instant is in daylight at this latitude and longitude (e.g. "52.52,13.40"), and that
+    /// day's sunrise and sunset, which can also be made the default in the configuration file
+    #[arg(allow_hyphen_values = true, env = "READTIMESTAMP_LOCATION", long)]
+    location: Option<Location>,
+
+    /// Also show each instant's TOTP time step (the counter two-factor authentication codes are generated from) and
+    /// how many seconds are left in it
+    #[arg(env = "READTIMESTAMP_TOTP", long, value_parser = FalseyValueParser::new())]
+    totp: bool,
+
+    /// The length of TOTP time steps, in seconds
+    #[arg(default_value_t = readtimestamp::totp::DEFAULT_PERIOD, env = "READTIMESTAMP_TOTP_PERIOD", long, requires = "totp", value_parser = clap::value_parser!(u64).range(1_u64..))]
+    totp_period: u64,
+
+    /// Floor each instant to the start of its hour, day, week (from Monday), month, or year in the time zone given
+    /// with --tz before it is shown, and show the floored value
+    #[arg(env = "READTIMESTAMP_TRUNCATE", long)]
+    truncate: Option<Truncation>,
+
+    /// Only interpret the timestamp in this unit (seconds, milliseconds, microseconds, nanoseconds, filetime, which can
+    /// also be given as "ad" for Active Directory timestamps, webkit, for Chrome's microseconds since 1601, cocoa or
+    /// cocoa-nanos, for Apple's seconds or nanoseconds since 2001, hfs, for HFS+ seconds since 1904, days, for days
+    /// since 1970 like the dates in /etc/shadow, excel, for spreadsheet serial dates like 45234.5, gps or tai, for GPS
+    /// seconds since 1980 or TAI seconds since 1970 without leap seconds, ntp, for NTP's 64-bit fixed point timestamps,
+    /// jd or mjd, for astronomical Julian Days and Modified Julian Days, or dos, for the packed 32-bit dates and times
+    /// of FAT file systems and ZIP archives, in decimal or hexadecimal)
+    #[arg(env = "READTIMESTAMP_UNIT", long)]
+    unit: Option<Unit>,
+
+    /// The product the timestamp comes from, whose unit is preferred as the best candidate (ad, cassandra, chrome,
+    /// excel, firefox, go, influxdb, ios, java, javascript, kafka, mongo, or python)
+    #[arg(conflicts_with = "unit", env = "READTIMESTAMP_PRESET", long)]
+    preset: Option<Preset>,
+
+    /// Measure relative times from this instant instead of the current time (Unix timestamp in seconds, or RFC 3339)
+    #[arg(env = "READTIMESTAMP_NOW", long, value_parser = parse_now)]
+    now: Option<OffsetDateTime>,
+
+    /// Write dates the way this locale does (e.g. "de-DE" or "fr_FR.UTF-8"), with its weekday and month names and its
+    /// order of fields (default: the `LC_TIME` environment variable, if it is set)
+    #[arg(env = "READTIMESTAMP_LOCALE", long)]
+    locale: Option<Locale>,
+
+    /// Show times on a 24-hour clock (e.g. "22:13:20") instead of with AM and PM
+    #[arg(env = "READTIMESTAMP_24H", long = "24h", value_parser = FalseyValueParser::new())]
+    twenty_four_hour: bool,
+
+    /// The time zone local times are displayed in ("UTC", "local", a name like "Europe/Berlin", or an offset like
+    /// "+05:30")
+    #[arg(env = "READTIMESTAMP_TZ", long)]
+    tz: Option<Zone>,
+
+    /// A leap second table in the `leap-seconds.list` format published by the IERS and NIST, to use instead of the
+    /// compiled-in one for TAI and GPS time
+    #[arg(env = "READTIMESTAMP_LEAP_SECONDS", long)]
+    leap_seconds: Option<PathBuf>,
+
+    /// Also try every integer as a snowflake ID counting milliseconds from this epoch (Unix time in milliseconds, or
+    /// RFC 3339), labeled "Custom"
+    #[arg(env = "READTIMESTAMP_SNOWFLAKE_EPOCH", long, value_parser = readtimestamp::snowflake::parse_epoch)]
+    snowflake_epoch: Option<i64>,
+
+    /// The widths of the custom snowflake's timestamp, worker ID, and sequence number
+    #[arg(
+        default_value = "41,10,12",
+        env = "READTIMESTAMP_SNOWFLAKE_BITS",
+        long,
+        requires = "snowflake_epoch"
+    )]
+    snowflake_bits: Bits,
+
+    /// The configuration file to read defaults and snowflake presets from (default:
+    /// ~/.config/readtimestamp/config.toml)
+    #[arg(env = "READTIMESTAMP_CONFIG", long)]
+    config: Option<PathBuf>,
+
+    /// The directory to read timestamp format definitions from (default: ~/.config/readtimestamp/formats)
+    #[arg(env = "READTIMESTAMP_FORMATS", long)]
+    formats: Option<PathBuf>,
+
+    /// A Rhai script to run the input through first, which returns what to parse instead (a string, a number, an array
+    /// of them, or nothing to parse the input as it is)
+    #[cfg(feature = "script")]
+    #[arg(env = "READTIMESTAMP_SCRIPT", long)]
+    script: Option<PathBuf>,
+
+    /// Do not color the output
+    #[arg(env = "READTIMESTAMP_NO_COLOR", long, value_parser = FalseyValueParser::new())]
+    no_color: bool,
+
+    /// Do not print notes (e.g. about non-digit characters or multiple possible timestamps), only the results and
+    /// warnings
+    #[arg(env = "READTIMESTAMP_QUIET", long, short, value_parser = FalseyValueParser::new())]
+    quiet: bool,
+
+    /// Print only the best candidate, without the header, notes, or other candidates (e.g. for a shell prompt)
+    #[arg(env = "READTIMESTAMP_BEST", long, value_parser = FalseyValueParser::new())]
+    best: bool,
+
+    /// Leave out the candidates that could not be read (e.g. "number was too large"), which can also be made the
+    /// default in the configuration file
+    #[arg(env = "READTIMESTAMP_NO_ERRORS", long, value_parser = FalseyValueParser::new())]
+    no_errors: bool,
+
+    /// The order readable candidates are printed in ("score", most likely first; "delta", closest to now first;
+    /// "unit", from seconds to nanoseconds, then snowflakes and custom formats; or "chronological", earliest first)
+    #[arg(default_value = "score", env = "READTIMESTAMP_SORT", long)]
+    sort: SortOrder,
+
+    /// How many years from now a candidate can be and still be plausible; when every candidate is further away, a
+    /// warning that the input is probably not a timestamp is printed
+    #[arg(default_value_t = DEFAULT_PLAUSIBLE_YEARS, env = "READTIMESTAMP_PLAUSIBLE_YEARS", long)]
+    plausible_years: u32,
+
+    /// Print the candidates in this format ("human", "table", "csv", "yaml", or "jsonl"), e.g. to load them into a
+    /// spreadsheet
+    #[arg(
+        conflicts_with = "porcelain",
+        default_value = "human",
+        env = "READTIMESTAMP_OUTPUT",
+        long
+    )]
+    output: OutputFormat,
+
+    /// Print a line per readable candidate shaped by this template, whose placeholders are `{{unit}}`, `{{utc}}`,
+    /// `{{local}}`, `{{relative}}`, `{{delta_seconds}}`, and `{{best}}` (e.g. "{{unit}}: {{utc}} ({{relative}})")
+    #[arg(conflicts_with_all = ["output", "porcelain"], env = "READTIMESTAMP_TEMPLATE", long)]
+    template: Option<Template>,
+
+    /// Print one tab-separated line per readable candidate (unit, RFC 3339 instant in UTC, and seconds from now), in a
+    /// format that will not change between minor versions
+    #[arg(env = "READTIMESTAMP_PORCELAIN", long, value_parser = FalseyValueParser::new())]
+    porcelain: bool,
+
+    /// The most verbose level of diagnostics to log ("off", "error", "warn", "info", "debug", or "trace"; default:
+    /// "warn", or the `RUST_LOG` environment variable if it is set)
+    #[arg(env = "READTIMESTAMP_LOG_LEVEL", global = true, long)]
+    log_level: Option<LevelFilter>,
+
+    /// Log more diagnostics, one level more per use (e.g. "-vv" for "debug")
+    #[arg(action = ArgAction::Count, global = true, long, short)]
+    verbose: u8,
+
+    /// Report how long parsing, time zone resolution, and formatting took (in microseconds) on standard error
+    #[arg(env = "READTIMESTAMP_TIMING", long, value_parser = FalseyValueParser::new())]
+    timing: bool,
+
+    /// Produce the same output on every machine and every run, for snapshot tests
+    ///
+    /// Implies --no-color, and unless they are given, "--now 2000-01-01T00:00:00Z" and "--tz UTC"
+    #[arg(env = "READTIMESTAMP_DETERMINISTIC", long, value_parser = FalseyValueParser::new())]
+    deterministic: bool,
+
+    /// Print a man page in roff format, for packagers
+    #[arg(hide = true, long)]
+    generate_man: bool,
+}
+
+#[derive(Subcommand)]
+enum ReadtimestampCommand {
+    /// Append the interpretation of every timestamp in each line of standard input to the end of the line
+    ///
+    /// A summary of how many timestamps were parsed, were ambiguous, or failed is printed to standard error at the end
+    Annotate {
+        /// Regular expression that finds timestamps; every capture group is a timestamp (the whole match is used if
+        /// there are no capture groups)
+        #[arg(long)]
+        pattern: Option<String>,
+
+        /// Also append the difference between two timestamps on the same line, selected by position or by capture
+        /// group name (e.g. "1,2" or "start,end")
+        #[arg(long)]
+        delta: Option<DeltaSelector>,
+
+        /// Use the pattern, unit, and time zone of a profile defined in the configuration file
+        #[arg(conflicts_with = "pattern", long)]
+        profile: Option<String>,
+
+        /// The configuration file to read profiles from (default: ~/.config/readtimestamp/config.toml)
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// When the system booted (Unix timestamp in seconds, or RFC 3339), which times since boot (e.g. the offsets of
+        /// the built-in "dmesg" profile) are added to (default: the boot time of this system, on Linux)
+        #[arg(long, value_parser = parse_now)]
+        boot_time: Option<OffsetDateTime>,
+
+        /// The date times of day (e.g. those of the built-in "tcpdump" profile) are on, formatted as YYYY-MM-DD; they
+        /// move on to the next day when they wrap around midnight
+        #[arg(long, value_parser = parse_date)]
+        date: Option<Date>,
+
+        /// Exit with code 5 if more than this many timestamps could not be interpreted
+        #[arg(long)]
+        max_failed: Option<usize>,
+
+        /// Exit with code 5 if more than this many timestamps had more than one plausible interpretation
+        #[arg(long)]
+        max_ambiguous: Option<usize>,
+
+        /// Report how long parsing, time zone resolution, and formatting took in total (in microseconds) on standard
+        /// error at the end
+        #[arg(long)]
+        timing: bool,
+
+        /// Print the annotated lines ("human"), or a JSON object per line with the timestamps found on it ("jsonl")
+        #[arg(default_value = "human", long)]
+        output: OutputFormat,
+    },
+    /// Read a raw integer at an offset in a binary file and interpret it as a timestamp
+    Binary {
+        /// The file to read
+        file: PathBuf,
+
+        /// Where the integer starts, in bytes (decimal, or hexadecimal with a "0x" prefix)
+        #[arg(default_value = "0", long, value_parser = readtimestamp::binary::parse_offset)]
+        offset: u64,
+
+        /// How many bytes long the integer is (1, 2, 4, 8, or 16)
+        #[arg(default_value_t = 8_usize, long)]
+        width: usize,
+
+        /// The byte order of the integer ("little" or "big")
+        #[arg(default_value = "little", long)]
+        endian: Endian,
+
+        /// Read the integer as two's complement, so it can be negative
+        #[arg(long)]
+        signed: bool,
+
+        /// Only interpret the integer in this unit, instead of in every unit
+        #[arg(long)]
+        unit: Option<Unit>,
+    },
+    /// Print the upcoming instants of a simple recurring schedule
+    Every {
+        /// The schedule, e.g. "tuesday 09:00", "weekdays 8:30am", "daily 17:00", or "15th 12:00"
+        schedule: Schedule,
+
+        /// How many instants to print
+        #[arg(default_value_t = 8_usize, long)]
+        count: usize,
+
+        /// The time zone the schedule's wall clock times are in ("UTC", "local", a name like "Europe/Berlin", or an
+        /// offset like "+05:30")
+        #[arg(default_value_t = Zone::Local, long)]
+        tz: Zone,
+    },
+    /// Print the first and last epoch seconds/milliseconds of the day, week, month, and year containing a date
+    Bounds {
+        /// The date, formatted as YYYY-MM-DD
+        #[arg(value_parser = parse_date)]
+        date: Date,
+
+        /// The time zone the periods are resolved in ("UTC", "local", a name like "Europe/Berlin", or an offset like
+        /// "+05:30")
+        #[arg(default_value_t = Zone::Utc, long)]
+        tz: Zone,
+    },
+    /// Count down to an instant, redrawing the time remaining in place until it is reached
+    ///
+    /// The line is laid out again when the terminal is resized (SIGWINCH), and the current state is written as JSON
+    /// to the state file when SIGUSR1 is received
+    Countdown {
+        /// The instant to count down to (Unix timestamp in seconds, or RFC 3339)
+        #[arg(value_parser = parse_now)]
+        target: OffsetDateTime,
+
+        /// Where to write the state on SIGUSR1 (default: ~/.local/state/readtimestamp/countdown.json)
+        #[arg(long)]
+        state_file: Option<PathBuf>,
+    },
+    /// Generate JSON objects with related timestamp fields, for seeding mocks and tests
+    Fixture {
+        /// The fields of each object and their formats (iso, s, ms, us, or ns), e.g. "created:iso,updated:ms"
+        ///
+        /// Each field is at or after the field before it
+        #[arg(long)]
+        shape: Shape,
+
+        /// How many objects to generate
+        #[arg(default_value_t = 10_usize, long)]
+        count: usize,
+
+        /// How many days before now the earliest timestamp can be
+        #[arg(default_value_t = 365_u16, long)]
+        days: u16,
+
+        /// Seed for the generator, to make the output reproducible
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Record the current instant in a stopwatch session and print the time since the previous lap and since the start
+    Lap {
+        /// The name of the session, so that several stopwatches can run at once
+        #[arg(default_value = "default", long)]
+        session: String,
+
+        /// Start the session over, with this lap as its first
+        #[arg(long)]
+        reset: bool,
+    },
+    /// Report how much of a session's or token's lifetime has been consumed
+    ///
+    /// Exits with code 3 once the warning threshold is reached, and 4 once the critical threshold is reached
+    Lifetime {
+        /// When the session or token was issued (Unix timestamp in seconds)
+        #[arg(allow_negative_numbers = true, long)]
+        issued: Number,
+
+        /// How long the session or token is valid for, in seconds
+        #[arg(long)]
+        lifetime: Number,
+
+        /// Percentage of the lifetime after which to warn
+        #[arg(default_value_t = 80_f64, long)]
+        warn_percent: f64,
+
+        /// Percentage of the lifetime after which the session or token is considered expired
+        #[arg(default_value_t = 100_f64, long)]
+        critical_percent: f64,
+    },
+    /// Print the offset and abbreviation of an IANA time zone, and the transitions around an instant
+    Tzinfo {
+        /// The name of the zone, e.g. "Europe/Dublin"
+        zone: String,
+
+        /// The instant to look around (Unix timestamp in seconds, or RFC 3339), instead of the current time
+        #[arg(long, value_parser = parse_now)]
+        around: Option<OffsetDateTime>,
+
+        /// How many transitions to print before and after the instant
+        #[arg(default_value_t = 3_usize, long)]
+        count: usize,
+    },
+    /// List the IANA time zone names that can be given to --tz, with the offset and abbreviation each has now
+    Zones {
+        /// Only list the zones whose names contain this, ignoring case (e.g. "america" or "york")
+        pattern: Option<String>,
+    },
+    /// Print evenly spaced instants from a start to an end, each as a timestamp and as RFC 3339, e.g. for a load test
+    Range {
+        /// The first instant (Unix timestamp in seconds, or RFC 3339)
+        #[arg(value_parser = parse_now)]
+        start: OffsetDateTime,
+
+        /// The last instant, which is only printed if a step lands on it (Unix timestamp in seconds, or RFC 3339)
+        #[arg(value_parser = parse_now)]
+        end: OffsetDateTime,
+
+        /// How far apart the instants are (e.g. "30s", "15m", "1h", or "1d")
+        #[arg(default_value = "1h", long, value_parser = parse_duration)]
+        step: time::Duration,
+
+        /// The unit the timestamps are printed in (default: seconds)
+        #[arg(long)]
+        unit: Option<Unit>,
+
+        /// The time zone the instants are written in ("UTC", "local", a name like "Europe/Berlin", or an offset like
+        /// "+05:30")
+        #[arg(default_value_t = Zone::Utc, long)]
+        tz: Zone,
+    },
+    /// Print, for each unit, the range of values that correspond to a range of years
+    Ranges {
+        /// First year of the range
+        #[arg(default_value_t = 2000_i32, long)]
+        start_year: i32,
+
+        /// Last year of the range (inclusive)
+        #[arg(default_value_t = 2099_i32, long)]
+        end_year: i32,
+    },
+    /// Print a shell completion script to standard output
+    Completions {
+        /// The shell to complete in (bash, elvish, fish, powershell, or zsh)
+        shell: Shell,
+    },
+    /// Rewrite one column of a CSV or TSV file from timestamps to dates (or from dates to timestamps), row by row
+    ///
+    /// Cells that cannot be converted are left as they were, and a summary is printed to standard error at the end
+    Csv {
+        /// The file to read ("-" for standard input)
+        file: PathBuf,
+
+        /// The column to rewrite, counting from 1
+        #[arg(long)]
+        column: NonZeroUsize,
+
+        /// The unit the timestamps are in
+        #[arg(default_value = "seconds", long)]
+        unit: Unit,
+
+        /// Rewrite dates and times (e.g. RFC 3339) to timestamps instead
+        #[arg(long)]
+        to_timestamps: bool,
+
+        /// The character between fields (default: a tab for ".tsv" files, and a comma otherwise)
+        #[arg(long)]
+        delimiter: Option<char>,
+
+        /// Copy the first row unchanged, since it names the columns
+        #[arg(long)]
+        header: bool,
+    },
+    /// Serve a JSON API over HTTP, where "GET /parse?ts=..." returns every readable candidate, best first
+    Serve {
+        /// The port to listen on
+        #[arg(default_value_t = 8080_u16, long)]
+        port: u16,
+
+        /// The address to listen on ("0.0.0.0" to accept requests from other machines)
+        #[arg(default_value = "127.0.0.1", long)]
+        bind: String,
+
+        /// The time zone of the "local" field ("UTC", "local", a name like "Europe/Berlin", or an offset like "+05:30")
+        #[arg(default_value_t = Zone::Utc, long)]
+        tz: Zone,
+    },
+    /// Interpret timestamps as they are typed, showing every readable candidate in several time zones
+    ///
+    /// Enter copies the selected instant to the clipboard, and Esc quits
+    Tui {
+        /// A time zone to show the instants in ("UTC", "local", a name like "Europe/Berlin", or an offset like
+        /// "+05:30"), which can be given more than once (default: UTC and local)
+        #[arg(long)]
+        tz: Vec<Zone>,
+    },
+}
+
+/// A readable instant, for the formats meant for programs
+struct Reading<'a> {
+    /// The unit, or what the input was read as (e.g. "an RFC 3339 date and time" or "iat")
+    label: &'a str,
+    instant: OffsetDateTime,
+    delta: time::Duration,
+    is_best: bool,
 }
 
 struct Data {
+    /// Only set when business days were requested
+    business_days: Option<String>,
+    /// Only set when calendar information was requested
+    calendar_info: Option<String>,
+    /// Only set when conversions were requested, and only shown for the best candidate
+    conversions: Vec<String>,
+    /// Only set when a location was given
+    daylight: Option<String>,
     delta: Option<time::Duration>,
     description: String,
+    explanation: Vec<String>,
+    /// Only set when the candidate could be read
+    instant: Option<OffsetDateTime>,
+    /// Whether this is the best candidate, which is not necessarily the first one printed
+    is_best: bool,
+    /// Only set when Julian Dates were requested, and only shown for the best candidate
+    julian_dates: Option<String>,
+    /// How likely this is to be the right reading (see [`readtimestamp::interpret::score`]), or 0 if it could not be
+    /// read
+    score: f64,
+    /// Only set when TOTP time steps were requested
+    totp_step: Option<String>,
+    /// Only set when the local time is ambiguous or skipped because of a daylight saving time transition
+    transition: Option<String>,
+    /// Only set when truncation was requested
+    truncation: Option<String>,
     unit: String,
+    /// Where the unit is in [`Unit::ALL`], or after every unit for snowflakes and custom formats
+    unit_position: usize,
 }
 
-struct DataWithDelta {
-    delta: time::Duration,
-    description: String,
-    unit: String,
+/// How relative times (e.g. "in 2 hours 5 minutes") are broken down
+#[derive(Clone, Copy)]
+struct RelativeFormat {
+    language: RelativeLanguage,
+    /// The smallest unit
+    min_unit: TimeUnit,
+    /// The largest number of units
+    num_items: usize,
+}
+
+impl Default for RelativeFormat {
+    fn default() -> Self {
+        Self {
+            language: RelativeLanguage::English,
+            min_unit: TimeUnit::Milliseconds,
+            num_items: 5_usize,
+        }
+    }
+}
+
+/// The order readable candidates are printed in
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum SortOrder {
+    /// Most likely first
+    #[default]
+    Score,
+    /// Closest to now first
+    Delta,
+    /// In the order of [`Unit::ALL`], then snowflakes and custom formats
+    Unit,
+    /// Earliest first
+    Chronological,
+}
+
+impl FromStr for SortOrder {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        match st.to_ascii_lowercase().as_str() {
+            "score" => Ok(Self::Score),
+            "delta" => Ok(Self::Delta),
+            "unit" => Ok(Self::Unit),
+            "chronological" => Ok(Self::Chronological),
+            _ => anyhow::bail!(
+                "Unrecognized sort order \"{st}\" (expected \"score\", \"delta\", \"unit\", or \"chronological\")"
+            ),
+        }
+    }
+}
+
+/// A language relative times can be written in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RelativeLanguage {
+    Chinese,
+    English,
+    French,
+    German,
+    Italian,
+    Japanese,
+    Polish,
+    Portuguese,
+    Russian,
+    Swedish,
+    Ukrainian,
+}
+
+impl RelativeLanguage {
+    fn boxed(self) -> BoxedLanguage {
+        match self {
+            Self::Chinese => Box::new(Chinese),
+            Self::English => Box::new(English),
+            Self::French => Box::new(French),
+            Self::German => Box::new(German),
+            Self::Italian => Box::new(Italian),
+            Self::Japanese => Box::new(Japanese),
+            Self::Polish => Box::new(Polish),
+            Self::Portuguese => Box::new(Portuguese),
+            Self::Russian => Box::new(Russian),
+            Self::Swedish => Box::new(Swedish),
+            Self::Ukrainian => Box::new(Ukrainian),
+        }
+    }
+}
+
+impl FromStr for RelativeLanguage {
+    type Err = anyhow::Error;
+
+    /// Accepts ISO 639-1 codes, on their own or at the start of a locale (e.g. "fr" or "fr-CA")
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let code = st
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let relative_language = match code.as_str() {
+            "de" => Self::German,
+            "en" => Self::English,
+            "fr" => Self::French,
+            "it" => Self::Italian,
+            "ja" => Self::Japanese,
+            "pl" => Self::Polish,
+            "pt" => Self::Portuguese,
+            "ru" => Self::Russian,
+            "sv" => Self::Swedish,
+            "uk" => Self::Ukrainian,
+            "zh" => Self::Chinese,
+            _ => anyhow::bail!(
+                "Unsupported language \"{st}\" (expected en, de, fr, it, ja, pl, pt, ru, sv, uk, or zh)"
+            ),
+        };
+
+        Ok(relative_language)
+    }
+}
+
+/// Writes relative times in a language
+struct RelativeFormatter {
+    /// Never adds "ago", which [`format_relative`] adds itself in English, along with "in"
+    formatter: Formatter<BoxedLanguage>,
+    /// Adds the language's own "ago" (e.g. "il y a 3 jours"), for languages other than English
+    ago_formatter: Option<Formatter<BoxedLanguage>>,
+}
+
+impl RelativeFormatter {
+    /// A length of time, without "in" or "ago"
+    fn convert(&self, duration: std::time::Duration) -> String {
+        self.formatter.convert(duration)
+    }
+}
+
+/// Settings that affect how timestamps are displayed
+#[derive(Clone)]
+#[expect(clippy::struct_excessive_bools, reason = "Unimportant")]
+struct Options {
+    auto_precision: bool,
+    /// Print only the best candidate
+    best: bool,
+    /// The holidays to leave out, when business days were requested
+    business_days: Option<Vec<Date>>,
+    calendar_info: bool,
+    convert: bool,
+    copy: bool,
+    explain: bool,
+    /// The month fiscal years start in, when fiscal quarters were requested
+    fiscal_start: Option<Month>,
+    /// Print a calendar event starting at the best candidate, instead of the candidates
+    ics: bool,
+    /// How long the calendar event lasts
+    ics_duration: Option<time::Duration>,
+    /// The title of the calendar event, instead of the timestamp and its unit
+    ics_summary: Option<String>,
+    julian_dates: bool,
+    /// Write dates the way this locale does, instead of in the fixed format
+    locale: Option<Locale>,
+    /// Where to tell whether instants are in daylight
+    location: Option<Location>,
+    /// Leave out the candidates that could not be read
+    no_errors: bool,
+    /// Show the ordinal date
+    ordinal_date: bool,
+    output: OutputFormat,
+    /// How many years from now a candidate can be and still be plausible
+    plausible_years: u32,
+    /// Prefer the unit of this product's timestamps as the best candidate
+    preset: Option<Preset>,
+    /// Print the stable, tab-separated format instead of the one meant for people
+    porcelain: bool,
+    /// Leave out the notes
+    quiet: bool,
+    /// How relative times are broken down
+    relative_format: RelativeFormat,
+    settings: Settings,
+    /// The order readable candidates are printed in
+    sort: SortOrder,
+    /// Print a line per candidate shaped by this, instead of the format meant for people
+    template: Option<Template>,
+    /// The length of TOTP time steps in seconds, when they were requested
+    totp_period: Option<u64>,
+    /// What to floor instants to before they are shown
+    truncate: Option<Truncation>,
+    /// Format times on a 24-hour clock instead of with AM and PM
+    twenty_four_hour: bool,
+    /// Interpret timestamps only in this unit, instead of in every unit
+    unit: Option<Unit>,
+    /// Show the ISO week date
+    week_date: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            auto_precision: false,
+            best: false,
+            business_days: None,
+            calendar_info: false,
+            convert: false,
+            copy: false,
+            explain: false,
+            fiscal_start: None,
+            ics: false,
+            ics_duration: None,
+            ics_summary: None,
+            julian_dates: false,
+            locale: None,
+            location: None,
+            no_errors: false,
+            ordinal_date: false,
+            output: OutputFormat::default(),
+            plausible_years: DEFAULT_PLAUSIBLE_YEARS,
+            preset: None,
+            porcelain: false,
+            quiet: false,
+            relative_format: RelativeFormat::default(),
+            settings: Settings::default(),
+            sort: SortOrder::default(),
+            template: None,
+            totp_period: None,
+            truncate: None,
+            twenty_four_hour: false,
+            unit: None,
+            week_date: false,
+        }
+    }
+}
+
+impl Options {
+    /// Whether the candidates are printed for people to read, with a header and notes between them
+    const fn is_for_people(&self) -> bool {
+        !self.porcelain
+            && !self.ics
+            && matches!(self.output, OutputFormat::Human)
+            && self.template.is_none()
+    }
 }
 
 const ARGUMENT_NAME: &str = "<TIMESTAMP>";
+/// How often a countdown checks the clock and whether a signal has arrived
+const COUNTDOWN_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100_u64);
+const EXIT_CODE_LIFETIME_EXPIRED: u8 = 4_u8;
+const EXIT_CODE_LIFETIME_WARNING: u8 = 3_u8;
+const EXIT_CODE_THRESHOLD_EXCEEDED: u8 = 5_u8;
+const EXIT_CODE_NO_TIMESTAMP: u8 = 6_u8;
+const EXIT_CODE_OUT_OF_RANGE: u8 = 7_u8;
+const EXIT_CODE_UNPARSABLE: u8 = 8_u8;
+const DATE_FORMAT_DESCRIPTION: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+/// How many years from now a candidate can be and still be plausible, unless `--plausible-years` was given
+const DEFAULT_PLAUSIBLE_YEARS: u32 = 100_u32;
+/// Used when standard output is not a terminal
+const DEFAULT_TERMINAL_WIDTH: usize = 80_usize;
 const FORMAT_DESCRIPTION: &[FormatItem<'_>] = format_description!(
     version = 2,
-    "[year]-[month]-[day] @ [hour repr:12]:[minute]:[second] [period]"
+    "[weekday repr:short] [year]-[month]-[day] @ [hour repr:12]:[minute]:[second] [period]"
+);
+const FORMAT_DESCRIPTION_MILLISECOND: &[FormatItem<'_>] = format_description!(
+    version = 2,
+    "[weekday repr:short] [year]-[month]-[day] @ [hour repr:12]:[minute]:[second].[subsecond digits:3] [period]"
+);
+const FORMAT_DESCRIPTION_MINUTE: &[FormatItem<'_>] = format_description!(
+    version = 2,
+    "[weekday repr:short] [year]-[month]-[day] @ [hour repr:12]:[minute] [period]"
+);
+const FORMAT_DESCRIPTION_SUBSECOND: &[FormatItem<'_>] = format_description!(
+    version = 2,
+    "[weekday repr:short] [year]-[month]-[day] @ [hour repr:12]:[minute]:[second].[subsecond digits:1+] [period]"
+);
+const FORMAT_DESCRIPTION_TIME_OF_DAY: &[FormatItem<'_>] =
+    format_description!(version = 2, "[hour repr:12 padding:none]:[minute] [period]");
+const FORMAT_DESCRIPTION_24_HOUR: &[FormatItem<'_>] = format_description!(
+    version = 2,
+    "[weekday repr:short] [year]-[month]-[day] @ [hour]:[minute]:[second]"
+);
+const FORMAT_DESCRIPTION_24_HOUR_MILLISECOND: &[FormatItem<'_>] = format_description!(
+    version = 2,
+    "[weekday repr:short] [year]-[month]-[day] @ [hour]:[minute]:[second].[subsecond digits:3]"
+);
+const FORMAT_DESCRIPTION_24_HOUR_MINUTE: &[FormatItem<'_>] = format_description!(
+    version = 2,
+    "[weekday repr:short] [year]-[month]-[day] @ [hour]:[minute]"
+);
+const FORMAT_DESCRIPTION_24_HOUR_SUBSECOND: &[FormatItem<'_>] = format_description!(
+    version = 2,
+    "[weekday repr:short] [year]-[month]-[day] @ [hour]:[minute]:[second].[subsecond digits:1+]"
 );
+const FORMAT_DESCRIPTION_24_HOUR_TIME_OF_DAY: &[FormatItem<'_>] =
+    format_description!(version = 2, "[hour]:[minute]");
 // The largest number that can be parsed by "OffsetDateTime::from_unix_timestamp_nanos" is 253402300799999999999
+// The smallest is -377705116800000000000
 const MAXIMUM_NUMBER_OF_DIGITS: usize = 21_usize;
 const MAXIMUM_NUMBER: i128 = 253_402_300_799_999_999_999_i128;
-const MICROSECONDS: &str = "microseconds";
-const MILLISECONDS: &str = "milliseconds";
-const NANOSECONDS: &str = "nanoseconds";
-const SECONDS: &str = "seconds";
-const WIDTH: usize = 12_usize;
 
-fn main() -> Result<(), i32> {
-    // TODO
-    env::set_var("RUST_BACKTRACE", "1");
-    // TODO
-    env::set_var("RUST_LOG", "debug");
+const MINIMUM_NUMBER: i128 = -377_705_116_800_000_000_000_i128;
+/// The label of snowflake readings in the candidate list
+const SNOWFLAKE: &str = "snowflake";
+/// How wide unit labels are padded to: as wide as the longest one, or the longest name a custom format can have
+const WIDTH: usize = {
+    let label_width = longest(&[
+        COCOA,
+        COCOA_NANOSECONDS,
+        DAYS,
+        DOS,
+        EXCEL,
+        FILETIME,
+        GPS,
+        HFS,
+        JULIAN_DAY,
+        MICROSECONDS,
+        MILLISECONDS,
+        MODIFIED_JULIAN_DAY,
+        NANOSECONDS,
+        NTP,
+        SECONDS,
+        SNOWFLAKE,
+        TAI,
+        WEBKIT,
+    ]);
+
+    if label_width > readtimestamp::plugin::MAXIMUM_NAME_LENGTH {
+        label_width
+    } else {
+        readtimestamp::plugin::MAXIMUM_NAME_LENGTH
+    }
+};
+
+fn main() -> ExitCode {
+    let readtimestamp_args = ReadtimestampArgs::parse();
 
     tracing_subscriber::registry()
-        .with(EnvFilter::from_default_env())
+        .with(get_env_filter(
+            readtimestamp_args.log_level,
+            readtimestamp_args.verbose,
+        ))
         .with(tracing_subscriber::fmt::layer().pretty())
         .init();
 
-    let result = start();
+    let result = start(readtimestamp_args);
 
-    if let Err(er) = result {
-        tracing::error!(
-            backtrace = %er.backtrace(),
-            error = %er,
-        );
+    match result {
+        Ok(ex) => ex,
+        Err(er) => {
+            tracing::error!(
+                backtrace = %er.backtrace(),
+                error = %er,
+            );
 
-        return Err(1_i32);
+            ExitCode::FAILURE
+        }
     }
-
-    Ok(())
 }
 
-#[expect(clippy::too_many_lines, reason = "Unimportant")]
-fn start() -> anyhow::Result<()> {
-    const DATA_ARRAY_LEN: usize = 4_usize;
-
-    let readtimestamp_args = ReadtimestampArgs::parse();
-
-    let timestamp = readtimestamp_args.timestamp;
+/// `RUST_LOG` decides what is logged unless a level was given, in which case it is `log_level` (or "warn") raised by
+/// `verbose` levels
+fn get_env_filter(log_level: Option<LevelFilter>, verbose: u8) -> EnvFilter {
+    const LEVELS: [LevelFilter; 6_usize] = [
+        LevelFilter::OFF,
+        LevelFilter::ERROR,
+        LevelFilter::WARN,
+        LevelFilter::INFO,
+        LevelFilter::DEBUG,
+        LevelFilter::TRACE,
+    ];
 
-    let (attempting_to_parse_string, attempting_to_parse_string_plain_length) =
-        get_attempting_to_parse_string(&timestamp);
+    if log_level.is_none() && verbose == 0_u8 {
+        if let Ok(ef) = EnvFilter::try_from_default_env() {
+            return ef;
+        }
+    }
 
-    println!(
-        "{attempting_to_parse_string}\n{}",
-        "-".repeat(attempting_to_parse_string_plain_length)
-    );
+    let level = log_level.unwrap_or(LevelFilter::WARN);
 
-    let mut has_printed_note = false;
+    let index = LEVELS
+        .iter()
+        .position(|le| *le == level)
+        .unwrap_or_default()
+        .saturating_add(usize::from(verbose))
+        .min(LEVELS.len() - 1_usize);
 
-    // Fast path
-    let mut timestamp_is_numeric = true;
-    let mut number_of_digits = 0_usize;
+    EnvFilter::default().add_directive(LEVELS[index].into())
+}
 
-    for ch in timestamp.chars() {
-        if ch.is_ascii_digit() {
-            number_of_digits += 1_usize;
-        } else {
-            timestamp_is_numeric = false;
+/// The current time and time zone from `--now` and `--tz`, falling back to the fixed ones under `--deterministic`
+fn get_settings(deterministic: bool, now: Option<OffsetDateTime>, tz: Option<Zone>) -> Settings {
+    let default_settings = if deterministic {
+        Settings::deterministic()
+    } else {
+        Settings::default()
+    };
 
-            break;
-        }
+    Settings {
+        now: now.or(default_settings.now),
+        zone: tz.unwrap_or(default_settings.zone),
     }
+}
 
-    if timestamp_is_numeric && number_of_digits > MAXIMUM_NUMBER_OF_DIGITS {
-        eprintln!(
-            "{}",
-            format!(
-                "ERROR: {ARGUMENT_NAME} is too long (more than {MAXIMUM_NUMBER_OF_DIGITS} digits)"
-            )
-            .red()
-        );
-
-        // TODO
-        // Return code
-        return Ok(());
+#[expect(clippy::too_many_lines, reason = "Unimportant")]
+fn start(readtimestamp_args: ReadtimestampArgs) -> anyhow::Result<ExitCode> {
+    if readtimestamp_args.no_color || readtimestamp_args.deterministic {
+        readtimestamp::color::set_enabled(false);
     }
 
-    let str_to_parse = if timestamp_is_numeric {
-        &timestamp
-    } else {
-        eprintln!(
-            "{}",
-            format!("NOTE: {ARGUMENT_NAME} contains non-digit characters, attempting to find something that looks like a timestamp").yellow()
-        );
+    match readtimestamp_args.command {
+        Some(ReadtimestampCommand::Annotate {
+            pattern,
+            delta,
+            profile,
+            config,
+            boot_time,
+            date,
+            max_failed,
+            max_ambiguous,
+            timing,
+            output,
+        }) => {
+            let annotator = get_annotator(
+                pattern.as_deref(),
+                delta,
+                profile.as_deref(),
+                config,
+                boot_time,
+                date,
+            )?;
+
+            return annotate(annotator, max_failed, max_ambiguous, timing, output);
+        }
+        Some(ReadtimestampCommand::Binary {
+            file,
+            offset,
+            width,
+            endian,
+            signed,
+            unit,
+        }) => {
+            let io =
+                readtimestamp::binary::read_file_integer(&file, offset, width, endian, signed)?;
+
+            let config = Config::load(readtimestamp_args.config)?;
+
+            let options = Options {
+                location: config.defaults.location,
+                no_errors: config.defaults.no_errors,
+                settings: get_settings(
+                    readtimestamp_args.deterministic,
+                    readtimestamp_args.now,
+                    readtimestamp_args.tz,
+                ),
+                twenty_four_hour: readtimestamp_args.twenty_four_hour,
+                unit,
+                quiet: readtimestamp_args.quiet,
+                ..Options::default()
+            };
 
-        has_printed_note = true;
+            print_note(
+                &format!(
+                    "NOTE: Read {io} from the {width} bytes at offset {offset:#x} of \"{}\"",
+                    file.display()
+                ),
+                &options,
+            );
 
-        let regex = Regex::new("[0-9]+")?;
+            return read_timestamp(&io.to_string(), &options, &mut Timing::default());
+        }
+        Some(ReadtimestampCommand::Bounds { date, tz }) => {
+            print_bounds(date, tz, readtimestamp_args.twenty_four_hour)?;
+        }
+        Some(ReadtimestampCommand::Countdown { target, state_file }) => {
+            run_countdown(target, state_file)?;
+        }
+        Some(ReadtimestampCommand::Every {
+            schedule,
+            count,
+            tz,
+        }) => print_every(schedule, count, tz, readtimestamp_args.twenty_four_hour)?,
+        Some(ReadtimestampCommand::Fixture {
+            shape,
+            count,
+            days,
+            seed,
+        }) => print_fixture(&shape, count, days, seed)?,
+        Some(ReadtimestampCommand::Lap { session, reset }) => {
+            print_lap(&session, reset, readtimestamp_args.twenty_four_hour)?;
+        }
+        Some(ReadtimestampCommand::Lifetime {
+            issued,
+            lifetime,
+            warn_percent,
+            critical_percent,
+        }) => {
+            return print_lifetime(
+                issued,
+                lifetime,
+                warn_percent,
+                critical_percent,
+                readtimestamp_args.twenty_four_hour,
+            );
+        }
+        Some(ReadtimestampCommand::Tzinfo {
+            zone,
+            around,
+            count,
+        }) => print_tzinfo(&zone, around, count, readtimestamp_args.twenty_four_hour)?,
+        Some(ReadtimestampCommand::Zones { pattern }) => print_zones(pattern.as_deref())?,
+        Some(ReadtimestampCommand::Range {
+            start,
+            end,
+            step,
+            unit,
+            tz,
+        }) => print_range(start, end, step, unit.unwrap_or(Unit::Seconds), tz)?,
+        Some(ReadtimestampCommand::Ranges {
+            start_year,
+            end_year,
+        }) => print_ranges(start_year, end_year)?,
+        Some(ReadtimestampCommand::Csv {
+            file,
+            column,
+            unit,
+            to_timestamps,
+            delimiter,
+            header,
+        }) => convert_csv(&file, column, unit, to_timestamps, delimiter, header)?,
+        Some(ReadtimestampCommand::Serve { port, bind, tz }) => {
+            eprintln!("{}", format!("Listening on http://{bind}:{port}").dimmed());
+
+            readtimestamp::serve::serve(
+                &format!("{bind}:{port}"),
+                Settings {
+                    now: None,
+                    zone: tz,
+                },
+            )?;
+        }
+        Some(ReadtimestampCommand::Tui { tz }) => readtimestamp::tui::run(
+            if tz.is_empty() {
+                vec![Zone::Utc, Zone::Local]
+            } else {
+                tz
+            },
+            Settings::default(),
+        )?,
+        Some(ReadtimestampCommand::Completions { shell }) => {
+            clap_complete::generate(
+                shell,
+                &mut ReadtimestampArgs::command(),
+                env!("CARGO_PKG_NAME"),
+                &mut io::stdout(),
+            );
+        }
+        None => {
+            if readtimestamp_args.generate_man {
+                print_man_page()?;
 
-        let mut longest_valid_match = None;
-        let mut longest_valid_match_length = 0_usize;
-        let mut valid_match_count = 0_u32;
+                return Ok(ExitCode::SUCCESS);
+            }
 
-        for ma in regex.find_iter(&timestamp) {
-            let ma_len = ma.len();
+            let timestamp = if readtimestamp_args.clipboard {
+                read_clipboard()?
+            } else {
+                readtimestamp_args.timestamp.unwrap_or_default()
+            };
 
-            if ma_len <= MAXIMUM_NUMBER_OF_DIGITS {
-                valid_match_count += 1_u32;
+            let deterministic = readtimestamp_args.deterministic;
 
-                if ma_len > longest_valid_match_length {
-                    longest_valid_match_length = ma_len;
-                    longest_valid_match = Some(ma);
-                }
+            if let Some(pa) = &readtimestamp_args.leap_seconds {
+                readtimestamp::leap_seconds::load(pa)?;
             }
-        }
 
-        if let Some(ma) = longest_valid_match {
-            if valid_match_count > 1_u32 {
-                eprintln!(
-                    "{}",
-                    format!("NOTE: {valid_match_count} possible timestamps were found in {ARGUMENT_NAME}. Parsing the longest one that is not too long to be parsed (if two possible timestamps of the same length were found, the first one will be parsed).").yellow()
-                );
+            let config = Config::load(readtimestamp_args.config)?;
 
-                has_printed_note = true;
-            }
+            add_snowflake_layouts(
+                readtimestamp_args.snowflake_epoch,
+                readtimestamp_args.snowflake_bits,
+                &config,
+            )?;
 
-            let st = ma.as_str();
+            readtimestamp::plugin::add_formats(
+                readtimestamp::plugin::load_formats(readtimestamp_args.formats)?
+                    .into_iter()
+                    .map(|ep| Box::new(ep) as Box<dyn TimestampFormat>)
+                    .collect(),
+            )?;
 
-            // TODO
-            #[expect(clippy::format_in_format_args, reason = "Unimportant")]
-            {
-                eprintln!(
-                    "{}",
-                    format!(
-                        "{}{}{}",
-                        format!("Parsing possible timestamp found in {ARGUMENT_NAME}: \"").yellow(),
-                        st.bold().yellow(),
-                        '"'.yellow()
-                    )
-                );
+            #[cfg(feature = "script")]
+            if let Some(pa) = &readtimestamp_args.script {
+                readtimestamp::script::set(readtimestamp::script::Script::load(pa)?)?;
             }
 
-            st
-        } else {
-            eprintln!(
-                "{}",
-                format!("ERROR: {ARGUMENT_NAME} does not contain any possible timestamps (groups of numbers of the appropriate length)").red());
+            let options = Options {
+                auto_precision: readtimestamp_args.auto_precision,
+                best: readtimestamp_args.best,
+                business_days: readtimestamp_args
+                    .business_days
+                    .then(|| config.holidays.clone()),
+                calendar_info: readtimestamp_args.calendar_info
+                    || readtimestamp_args.fiscal_start.is_some(),
+                convert: readtimestamp_args.convert,
+                copy: readtimestamp_args.copy,
+                explain: readtimestamp_args.explain,
+                fiscal_start: readtimestamp_args
+                    .fiscal_start
+                    .map(Month::try_from)
+                    .transpose()?,
+                ics: readtimestamp_args.ics,
+                ics_duration: readtimestamp_args.duration,
+                ics_summary: readtimestamp_args.summary,
+                julian_dates: readtimestamp_args.mjd,
+                locale: if deterministic {
+                    readtimestamp_args.locale
+                } else {
+                    readtimestamp_args.locale.or_else(Locale::from_environment)
+                },
+                location: readtimestamp_args.location.or(config.defaults.location),
+                no_errors: readtimestamp_args.no_errors || config.defaults.no_errors,
+                ordinal_date: readtimestamp_args.ordinal,
+                output: readtimestamp_args.output,
+                plausible_years: readtimestamp_args.plausible_years,
+                preset: readtimestamp_args.preset,
+                porcelain: readtimestamp_args.porcelain,
+                quiet: readtimestamp_args.quiet || readtimestamp_args.best,
+                relative_format: RelativeFormat {
+                    language: readtimestamp_args.lang,
+                    min_unit: readtimestamp_args.precision,
+                    num_items: usize::from(readtimestamp_args.relative_units),
+                },
+                settings: get_settings(
+                    deterministic,
+                    readtimestamp_args.now,
+                    readtimestamp_args.tz,
+                ),
+                sort: readtimestamp_args.sort,
+                template: readtimestamp_args.template,
+                totp_period: readtimestamp_args
+                    .totp
+                    .then_some(readtimestamp_args.totp_period),
+                truncate: readtimestamp_args.truncate,
+                twenty_four_hour: readtimestamp_args.twenty_four_hour,
+                unit: readtimestamp_args.unit,
+                week_date: readtimestamp_args.week,
+            };
 
-            // TODO
-            // Return code
-            return Ok(());
-        }
-    };
+            if let Some(pa) = &readtimestamp_args.cert {
+                print_certificates(pa, &options)?;
 
-    let str_to_parse_i_six_four = str_to_parse.parse::<i128>();
+                return Ok(ExitCode::SUCCESS);
+            }
 
-    match str_to_parse_i_six_four {
-        Ok(io) => {
-            if io > MAXIMUM_NUMBER {
-                eprintln!(
-                    "{}",
-                    format!("ERROR: Timestamp candidate {io} is too large (greater than {MAXIMUM_NUMBER})").red());
+            if readtimestamp_args.interactive {
+                run_interactive(&options)?;
 
-                // TODO
-                // Return code
-                return Ok(());
+                return Ok(ExitCode::SUCCESS);
             }
 
-            let nanos = Some(OffsetDateTime::from_unix_timestamp_nanos(io));
+            let mut timing = Timing::default();
 
-            let result = i64::try_from(io);
+            let instant = Instant::now();
 
-            let (micros_option, millis_option, seconds_option) = if let Ok(is) = result {
-                let micros_for_nanos = io * 1_000_i128;
+            let exit_code = read_input(&timestamp, &options, &mut timing)?;
 
-                let millis_for_nanos = micros_for_nanos * 1_000_i128;
+            if readtimestamp_args.timing {
+                // Everything that was not time zone resolution or formatting was parsing
+                timing.parsing = instant
+                    .elapsed()
+                    .saturating_sub(timing.zone_resolution + timing.formatting);
 
-                let micros = OffsetDateTime::from_unix_timestamp_nanos(micros_for_nanos);
-                let millis = OffsetDateTime::from_unix_timestamp_nanos(millis_for_nanos);
-                let seconds = OffsetDateTime::from_unix_timestamp(is);
+                eprintln!("{}", format!("Timing: {timing}").dimmed());
+            }
 
-                (Some(micros), Some(millis), Some(seconds))
-            } else {
-                (None, None, None)
-            };
+            return Ok(exit_code);
+        }
+    }
 
-            let now_utc = OffsetDateTime::now_utc();
+    Ok(ExitCode::SUCCESS)
+}
 
-            let result = UtcOffset::current_local_offset();
+/// The text on the system clipboard, without the whitespace around it
+fn read_clipboard() -> anyhow::Result<String> {
+    let text = Clipboard::new()
+        .and_then(|mut cl| cl.get_text())
+        .map_err(|er| anyhow::anyhow!("Could not read the clipboard: {er}"))?;
 
-            let offset = match result {
-                Ok(ut) => Some(ut),
-                Err(ind) => {
-                    eprintln!(
-                        "{}",
-                        format!("NOTE: Could not determine current time zone offset. Dates will only be displayed in UTC. Error reported: \"{ind}\".").yellow()
-                    );
+    Ok(text.trim().to_owned())
+}
 
-                    has_printed_note = true;
+/// Puts `text` on the system clipboard, and says whether it worked (without failing, so the results are still printed)
+fn copy_to_clipboard(text: &str, options: &Options) {
+    match Clipboard::new().and_then(|mut cl| cl.set_text(text)) {
+        Ok(()) => print_note(&format!("NOTE: Copied {text} to the clipboard"), options),
+        Err(er) => eprintln!(
+            "{}",
+            format!("ERROR: Could not write to the clipboard: {er}").red()
+        ),
+    }
+}
 
-                    None
-                }
-            };
+/// Reads timestamps one per line until end of input (Ctrl+D) or Ctrl+C, keeping the history between sessions
+fn run_interactive(options: &Options) -> anyhow::Result<()> {
+    let mut editor = DefaultEditor::new()?;
 
-            let formatter = {
-                let mut fo = Formatter::new();
+    let history_path = readtimestamp::history_path()?;
 
-                fo.ago("");
-                fo.min_unit(TimeUnit::Milliseconds);
-                fo.num_items(5);
+    // There is no history before the first session
+    if history_path.exists() {
+        editor.load_history(&history_path)?;
+    }
 
-                fo
-            };
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(st) => st,
+            Err(ReadlineError::Eof | ReadlineError::Interrupted) => break,
+            Err(er) => return Err(er.into()),
+        };
 
-            // TODO
-            #[cfg(debug_assertions)]
-            {
-                check_width()?;
-            }
+        let trimmed = line.trim();
 
-            let microseconds_str = pad_to_left(WIDTH, MICROSECONDS);
-            let milliseconds_str = pad_to_left(WIDTH, MILLISECONDS);
-            let nanoseconds_str = pad_to_left(WIDTH, NANOSECONDS);
-            let seconds_str = pad_to_left(WIDTH, SECONDS);
-
-            let microseconds_data =
-                get_data(&formatter, now_utc, offset, micros_option, microseconds_str)?;
-            let milliseconds_data =
-                get_data(&formatter, now_utc, offset, millis_option, milliseconds_str)?;
-            let nanoseconds_data = get_data(&formatter, now_utc, offset, nanos, nanoseconds_str)?;
-            let seconds_data = get_data(&formatter, now_utc, offset, seconds_option, seconds_str)?;
-
-            let data_array: [Data; DATA_ARRAY_LEN] = [
-                seconds_data,
-                milliseconds_data,
-                microseconds_data,
-                nanoseconds_data,
-            ];
-
-            let mut has_none = Vec::<Data>::with_capacity(DATA_ARRAY_LEN);
-            let mut has_some = Vec::<DataWithDelta>::with_capacity(DATA_ARRAY_LEN);
-
-            for da in data_array {
-                if let Some(du) = da.delta {
-                    has_some.push(DataWithDelta {
-                        delta: du,
-                        description: da.description,
-                        unit: da.unit,
-                    });
-                } else {
-                    has_none.push(da);
-                }
-            }
+        if trimmed.is_empty() {
+            continue;
+        }
 
-            if has_printed_note {
-                // TODO
-                println!();
-            }
+        editor.add_history_entry(trimmed)?;
 
-            let has_none_is_not_empty = !has_none.is_empty();
-            let has_some_is_not_empty = !has_some.is_empty();
+        // A timestamp that cannot be read has already been reported, and should not end the session
+        read_input(trimmed, options, &mut Timing::default())?;
 
-            if has_some_is_not_empty {
-                has_some.sort_by(|da, dat| da.delta.abs().cmp(&dat.delta.abs()));
+        println!();
+    }
 
-                for (us, da) in has_some.into_iter().enumerate() {
-                    let description = &da.description;
-                    let unit = &da.unit;
+    if let Some(pa) = history_path.parent() {
+        fs::create_dir_all(pa)?;
+    }
 
-                    // TODO
-                    let is_best_candidate_unit = us == 0_usize;
+    editor.save_history(&history_path)?;
 
-                    let unit_description = format!("({unit}) {description}");
+    Ok(())
+}
 
-                    println!(
-                        "{}{}{}",
-                        if us == 1_usize { "\n" } else { "" },
-                        if is_best_candidate_unit {
-                            format!("{}\n", "Best candidate unit:".bold().green())
-                        } else {
-                            String::new()
-                        },
-                        if is_best_candidate_unit {
-                            unit_description.bold().to_string()
-                        } else {
-                            unit_description
-                        }
-                    );
-                }
-            }
+/// Runs `input` through the script given with `--script`, if any, and reads each timestamp it returns in turn
+///
+/// The exit code is that of the first timestamp that could not be read.
+fn read_input(input: &str, options: &Options, timing: &mut Timing) -> anyhow::Result<ExitCode> {
+    #[cfg(feature = "script")]
+    let input_vec = readtimestamp::script::extract(input)?;
+    #[cfg(not(feature = "script"))]
+    let input_vec = [input.to_owned()];
+
+    #[cfg(feature = "script")]
+    if input_vec.is_empty() {
+        eprintln!(
+            "{}",
+            format!("ERROR: The script found no timestamp in {ARGUMENT_NAME}").red()
+        );
 
-            if has_none_is_not_empty {
-                if has_some_is_not_empty {
-                    // TODO
-                    println!();
-                }
+        return Ok(ExitCode::from(EXIT_CODE_NO_TIMESTAMP));
+    }
 
-                for da in has_none {
-                    println!("({}) {}", da.unit, da.description);
-                }
-            }
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for (us, st) in input_vec.iter().enumerate() {
+        if us > 0_usize {
+            println!();
         }
-        Err(pa) => {
-            eprintln!(
-                "{}",
-                format!("ERROR: Could not parse possible timestamp into a 128 bit signed integer. The number is probably too long. Error reported: \"{pa}\".").red()
-            );
+
+        let ex = read_timestamp(st, options, timing)?;
+
+        if exit_code == ExitCode::SUCCESS {
+            exit_code = ex;
         }
     }
 
-    Ok(())
+    Ok(exit_code)
 }
 
-fn get_attempting_to_parse_string(timestamp: &str) -> (String, usize) {
-    const PREFIX: &str = "Attempting to parse \"";
-    const SUFFIX: &str = "\"";
+#[expect(clippy::too_many_lines, reason = "Unimportant")]
+fn read_timestamp(
+    timestamp: &str,
+    options: &Options,
+    timing: &mut Timing,
+) -> anyhow::Result<ExitCode> {
+    let (attempting_to_parse_string, attempting_to_parse_string_plain_length) =
+        get_attempting_to_parse_string(timestamp);
 
-    // TODO
-    // Unchecked arithmetic
-    const PREFIX_LEN_PLUS_SUFFIX_LEN: usize = PREFIX.len() + SUFFIX.len();
+    if options.is_for_people() && !options.best {
+        println!(
+            "{attempting_to_parse_string}\n{}",
+            "-".repeat(attempting_to_parse_string_plain_length)
+        );
+    }
 
-    let attempting_to_parse_string = format!("{PREFIX}{}{SUFFIX}", timestamp.bold());
+    let mut has_printed_note = false;
 
-    (
-        attempting_to_parse_string,
-        PREFIX_LEN_PLUS_SUFFIX_LEN + timestamp.len(),
-    )
-}
+    if let Some((da, of)) = parse_date_time(timestamp) {
+        print_note(
+            &format!("NOTE: {ARGUMENT_NAME} looks like {}", da.name()),
+            options,
+        );
 
-fn get_data(
-    formatter: &Formatter,
-    now_utc: OffsetDateTime,
-    offset: Option<UtcOffset>,
-    other: Option<Result<OffsetDateTime, ComponentRange>>,
-    unit: String,
-) -> anyhow::Result<Data> {
-    let data = if let Some(re) = other {
-        match re {
-            Ok(of) => {
-                let duration = of - now_utc;
+        if da.assumes_utc() {
+            print_note("NOTE: No UTC offset was given, so UTC is assumed", options);
+        }
 
-                let date_formatted = of.format(FORMAT_DESCRIPTION)?;
+        print_date_time(da.name(), of, options, timing)?;
 
-                let local_string = if let Some(ut) = offset {
-                    let local = of.to_offset(ut);
+        return Ok(ExitCode::SUCCESS);
+    }
 
-                    let local_formatted = local.format(FORMAT_DESCRIPTION)?;
+    if readtimestamp::jwt::looks_like_jwt(timestamp) {
+        print_note(&format!("NOTE: {ARGUMENT_NAME} looks like a JWT, showing the instants in its claims (the signature is not checked)"), options);
 
-                    format!(" local: {}", local_formatted.purple())
-                } else {
-                    String::new()
-                };
+        return match readtimestamp::jwt::parse_claims(timestamp) {
+            Ok(cl) => {
+                print_jwt_claims(cl, options, timing)?;
 
-                let duration_unsigned_abs = duration.unsigned_abs();
+                Ok(ExitCode::SUCCESS)
+            }
+            Err(er) => {
+                eprintln!(
+                    "{}",
+                    format!("ERROR: Could not read the claims of {ARGUMENT_NAME}. Error reported: \"{er}\".")
+                        .red()
+                );
 
-                let duration_is_positive = duration.is_positive();
+                Ok(ExitCode::from(EXIT_CODE_UNPARSABLE))
+            }
+        };
+    }
 
-                let relative = format!(
-                    "{}{}{}",
-                    if duration_is_positive { "in " } else { "" },
-                    formatter.convert(duration_unsigned_abs),
-                    if duration_is_positive { "" } else { " ago" }
-                );
+    if let Some((id, of)) = parse_identifier(timestamp) {
+        print_note(
+            &format!(
+                "NOTE: {ARGUMENT_NAME} looks like {}, which was generated at the instant below",
+                id.name()
+            ),
+            options,
+        );
 
-                let description = format!(
-                    "UTC: {}{local_string} ({})",
-                    date_formatted.blue(),
-                    relative.cyan(),
-                );
+        print_date_time(id.name(), of, options, timing)?;
 
-                let delta = Some(duration);
+        return Ok(ExitCode::SUCCESS);
+    }
 
-                Data {
-                    delta,
-                    description,
-                    unit,
-                }
-            }
-            Err(co) => Data {
-                description: format!("error reported: \"{co}\""),
-                delta: None,
-                unit,
-            },
+    if let Some(ve) = parse_uuid_version(timestamp) {
+        eprintln!(
+            "{}",
+            format!(
+                "ERROR: {ARGUMENT_NAME} is a version {ve} UUID, which does not contain a timestamp"
+            )
+            .red()
+        );
+
+        return Ok(ExitCode::from(EXIT_CODE_NO_TIMESTAMP));
+    }
+
+    if let Some(io) = readtimestamp::input::parse_hexadecimal_ntp(timestamp) {
+        print_note(&format!("NOTE: {ARGUMENT_NAME} looks like a 64-bit NTP timestamp in hexadecimal, parsing it as {io}"), options);
+
+        let ntp_options = Options {
+            unit: Some(Unit::Ntp),
+            ..options.clone()
+        };
+
+        return print_candidates(Number::from_integer(io), true, &ntp_options, timing);
+    }
+
+    if options.unit == Some(Unit::Dos) {
+        if let Some(io) = readtimestamp::input::parse_hexadecimal_dos(timestamp) {
+            print_note(&format!("NOTE: {ARGUMENT_NAME} looks like a hexadecimal packed date and time, parsing it as {io}"), options);
+
+            return print_candidates(Number::from_integer(io), true, options, timing);
+        }
+    }
+
+    if let Some((ra, re)) = readtimestamp::input::parse_prefixed_integer(timestamp) {
+        return match re {
+            Ok(io) => {
+                print_note(
+                    &format!(
+                        "NOTE: {ARGUMENT_NAME} is a {} number, parsing it as {io}",
+                        ra.name()
+                    ),
+                    options,
+                );
+
+                has_printed_note = true;
+
+                print_candidates(Number::from_integer(io), has_printed_note, options, timing)
+            }
+            Err(pa) => {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "ERROR: Could not parse {ARGUMENT_NAME} as a {} number. Error reported: \"{pa}\".",
+                        ra.name()
+                    )
+                    .red()
+                );
+
+                Ok(ExitCode::from(EXIT_CODE_UNPARSABLE))
+            }
+        };
+    }
+
+    if timestamp.contains(['e', 'E']) {
+        if let Ok(nu) = timestamp.parse::<Number>() {
+            print_note(
+                &format!("NOTE: {ARGUMENT_NAME} is in scientific notation, parsing it as {nu}"),
+                options,
+            );
+
+            has_printed_note = true;
+
+            return print_candidates(nu, has_printed_note, options, timing);
+        }
+    }
+
+    match readtimestamp::input::parse_base64(timestamp) {
+        Some(Base64Timestamp::Integer { width, value }) => {
+            print_note(&format!("NOTE: {ARGUMENT_NAME} looks like a big-endian integer ({width} bytes) in base64, parsing it as {value}"), options);
+
+            return print_candidates(Number::from_integer(value), true, options, timing);
+        }
+        Some(Base64Timestamp::Digits(st)) => {
+            if let Ok(nu) = st.parse::<Number>() {
+                print_note(
+                    &format!(
+                        "NOTE: {ARGUMENT_NAME} looks like a number in base64, parsing it as {st}"
+                    ),
+                    options,
+                );
+
+                return print_candidates(nu, true, options, timing);
+            }
+        }
+        None => {}
+    }
+
+    // Fast path
+    let mut timestamp_is_numeric = true;
+    let mut number_of_digits = 0_usize;
+
+    let unsigned_timestamp = timestamp.strip_prefix('-').unwrap_or(timestamp);
+
+    // Everything after a decimal point is a fraction, which does not count toward the digit limit
+    let (integer_digits, fraction_digits) = unsigned_timestamp
+        .split_once('.')
+        .unwrap_or((unsigned_timestamp, ""));
+
+    if integer_digits.is_empty() || !fraction_digits.bytes().all(|by| by.is_ascii_digit()) {
+        timestamp_is_numeric = false;
+    }
+
+    for ch in integer_digits.chars() {
+        if ch.is_ascii_digit() {
+            number_of_digits += 1_usize;
+        } else {
+            timestamp_is_numeric = false;
+
+            break;
         }
+    }
+
+    if timestamp_is_numeric && number_of_digits > MAXIMUM_NUMBER_OF_DIGITS {
+        eprintln!(
+            "{}",
+            format!(
+                "ERROR: {ARGUMENT_NAME} is too long (more than {MAXIMUM_NUMBER_OF_DIGITS} digits)"
+            )
+            .red()
+        );
+
+        return Ok(ExitCode::from(EXIT_CODE_OUT_OF_RANGE));
+    }
+
+    let str_to_parse = if timestamp_is_numeric {
+        timestamp
     } else {
-        Data {
-            description: format!("error: number was too large to interpret as {unit}"),
-            delta: None,
-            unit,
+        print_note(&format!("NOTE: {ARGUMENT_NAME} contains non-digit characters, attempting to find something that looks like a timestamp"), options);
+
+        has_printed_note = true;
+
+        let regex = Regex::new(r"-?[0-9]+(\.[0-9]+)?")?;
+
+        let mut longest_valid_match = None;
+        let mut longest_valid_match_length = 0_usize;
+        let mut valid_match_count = 0_u32;
+
+        for ma in regex.find_iter(timestamp) {
+            let ma_str = ma.as_str();
+
+            // A "-" is only a sign if it is not joining the number to a preceding word (e.g. "file-1704772140")
+            let is_signed = ma_str.starts_with('-')
+                && timestamp[..ma.start()]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|ch| {
+                        ch.is_whitespace() || matches!(ch, '(' | ',' | ':' | '=' | '[')
+                    });
+
+            let unsigned_str = ma_str.trim_start_matches('-');
+
+            let ma_len = unsigned_str
+                .split_once('.')
+                .map_or(unsigned_str, |(st, _)| st)
+                .len();
+
+            if ma_len <= MAXIMUM_NUMBER_OF_DIGITS {
+                valid_match_count += 1_u32;
+
+                if ma_len > longest_valid_match_length {
+                    longest_valid_match_length = ma_len;
+                    longest_valid_match = Some(if is_signed { ma_str } else { unsigned_str });
+                }
+            }
+        }
+
+        if let Some(st) = longest_valid_match {
+            if valid_match_count > 1_u32 {
+                print_note(&format!("NOTE: {valid_match_count} possible timestamps were found in {ARGUMENT_NAME}. Parsing the longest one that is not too long to be parsed (if two possible timestamps of the same length were found, the first one will be parsed)."), options);
+
+                has_printed_note = true;
+            }
+
+            // TODO
+            #[expect(clippy::format_in_format_args, reason = "Unimportant")]
+            if !options.quiet {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "{}{}{}",
+                        format!("Parsing possible timestamp found in {ARGUMENT_NAME}: \"").yellow(),
+                        st.bold().yellow(),
+                        '"'.yellow()
+                    )
+                );
+            }
+
+            st
+        } else {
+            eprintln!(
+                "{}",
+                format!("ERROR: {ARGUMENT_NAME} does not contain any possible timestamps (groups of numbers of the appropriate length)").red());
+
+            return Ok(ExitCode::from(EXIT_CODE_NO_TIMESTAMP));
         }
     };
 
-    Ok(data)
+    let str_to_parse_number = str_to_parse.parse::<Number>();
+
+    match str_to_parse_number {
+        Ok(nu) => print_candidates(nu, has_printed_note, options, timing),
+        Err(er) => {
+            eprintln!(
+                "{}",
+                format!("ERROR: Could not parse possible timestamp into a 128 bit signed integer. The number is probably too long. Error reported: \"{er}\".").red()
+            );
+
+            Ok(ExitCode::from(EXIT_CODE_UNPARSABLE))
+        }
+    }
 }
 
-fn pad_to_left(width: usize, input: &str) -> String {
-    format!("{}{input}", " ".repeat(width - input.len()))
+/// Prints an instant that was given as a date and time (or was read from an identifier), described by `label`, along
+/// with its value in every unit
+fn print_date_time(
+    label: &str,
+    offset_date_time: OffsetDateTime,
+    options: &Options,
+    timing: &mut Timing,
+) -> anyhow::Result<()> {
+    let now = options.settings.now();
+
+    if options.unit.is_none_or(Unit::counts_leap_seconds) {
+        print_leap_second_note(
+            offset_date_time.unix_timestamp_nanos(),
+            "TAI and GPS time",
+            options,
+        )?;
+    }
+
+    let offset_date_time = truncate(offset_date_time, options)?;
+
+    if options.ics {
+        return print_ics(
+            offset_date_time,
+            &offset_date_time
+                .to_offset(UtcOffset::UTC)
+                .format(&Rfc3339)?,
+            options,
+        );
+    }
+
+    let offset = Timing::measure(&mut timing.zone_resolution, || get_offset(options));
+
+    let instant = Instant::now();
+
+    let formatter = get_formatter(options.relative_format);
+
+    let reading = Reading {
+        label,
+        instant: offset_date_time,
+        delta: offset_date_time - now,
+        is_best: true,
+    };
+
+    if print_readings(&[reading], offset, &formatter, options)? {
+        timing.formatting += instant.elapsed();
+
+        return Ok(());
+    }
+
+    if !options.best {
+        println!();
+    }
+
+    println!(
+        "{}",
+        get_description(&formatter, now, offset, offset_date_time, options)?
+    );
+
+    if let Some(st) = get_calendar_info(
+        offset_date_time,
+        local_offset_at(offset_date_time, offset, &options.settings),
+        options,
+    )? {
+        println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+    }
+
+    if let Some(st) = get_business_days(offset_date_time, now, offset, options) {
+        println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+    }
+
+    if let Some(st) = get_daylight(offset_date_time, offset, options)? {
+        println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+    }
+
+    if let Some(st) = get_totp_step(offset_date_time, options) {
+        println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+    }
+
+    if let Some(st) = get_truncation(offset_date_time, Unit::Seconds, options) {
+        println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+    }
+
+    if let Some(st) = get_transition(offset_date_time, &options.settings) {
+        println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+    }
+
+    let unix_nanoseconds = offset_date_time.unix_timestamp_nanos();
+
+    // With --best, the instant is its own best candidate, so its value in every unit is left out
+    for un in Unit::ALL
+        .into_iter()
+        .filter(|un| !options.best && options.unit.is_none_or(|uni| uni == *un))
+    {
+        println!(
+            "({}) {}",
+            pad_to_left(WIDTH, un.name()),
+            un.format_from_unix_nanoseconds(unix_nanoseconds).blue()
+        );
+    }
+
+    if options.copy {
+        copy_to_clipboard(
+            &options
+                .unit
+                .unwrap_or(Unit::Seconds)
+                .from_unix_nanoseconds(unix_nanoseconds)
+                .to_string(),
+            options,
+        );
+    }
+
+    timing.formatting += instant.elapsed();
+
+    Ok(())
+}
+
+/// Prints a calendar event starting at `offset_date_time`, titled `summary` unless another title was given
+fn print_ics(
+    offset_date_time: OffsetDateTime,
+    summary: &str,
+    options: &Options,
+) -> anyhow::Result<()> {
+    let event = Event {
+        start: offset_date_time,
+        duration: options.ics_duration,
+        summary: options
+            .ics_summary
+            .clone()
+            .unwrap_or_else(|| summary.to_owned()),
+    };
+
+    print!("{}", event.to_ics(options.settings.now())?);
+
+    Ok(())
 }
 
-#[allow(dead_code, reason = "Unimportant")]
-fn check_width() -> anyhow::Result<()> {
-    use anyhow::Context;
+/// Prints the `iat`, `nbf`, and `exp` claims of a JWT, and whether it is valid now
+fn print_jwt_claims(claims: Claims, options: &Options, timing: &mut Timing) -> anyhow::Result<()> {
+    let now = options.settings.now();
+
+    let offset = Timing::measure(&mut timing.zone_resolution, || get_offset(options));
 
-    const LEN_ARRAY: [usize; 4_usize] = [
-        MICROSECONDS.len(),
-        MILLISECONDS.len(),
-        NANOSECONDS.len(),
-        SECONDS.len(),
+    let instant = Instant::now();
+
+    let formatter = get_formatter(options.relative_format);
+
+    let claim_array = [
+        ("iat", claims.iat),
+        ("nbf", claims.nbf),
+        ("exp", claims.exp),
     ];
 
-    let correct_width = LEN_ARRAY.into_iter().max().context("TODO")?;
+    // None of the claims is a better reading than the others
+    let reading_vec = claim_array
+        .iter()
+        .filter_map(|(st, of)| {
+            let of = (*of)?;
+
+            Some(Reading {
+                label: st,
+                instant: of,
+                delta: of - now,
+                is_best: false,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if print_readings(&reading_vec, offset, &formatter, options)? {
+        timing.formatting += instant.elapsed();
+
+        return Ok(());
+    }
+
+    println!();
+
+    for (st, of) in claim_array {
+        let description = match of {
+            Some(off) => get_description(&formatter, now, offset, off, options)?,
+            None => "not set".dimmed().to_string(),
+        };
+
+        println!("({}) {description}", pad_to_left(WIDTH, st));
+    }
+
+    println!(
+        "\n{}",
+        format_verdict(&formatter, claims.verdict(now)).bold()
+    );
+
+    timing.formatting += instant.elapsed();
+
+    Ok(())
+}
+
+/// Prints the validity period of each certificate in the PEM file at `path` (standard input if it is "-"), and whether
+/// it is valid now
+fn print_certificates(path: &Path, options: &Options) -> anyhow::Result<()> {
+    let pem = if path == Path::new("-") {
+        io::read_to_string(io::stdin().lock())?
+    } else {
+        fs::read_to_string(path)
+            .map_err(|er| anyhow::anyhow!("Could not read \"{}\": {er}", path.display()))?
+    };
+
+    let validity_vec = readtimestamp::x509::parse_pem(&pem)?;
+
+    let now = options.settings.now();
+
+    let offset = get_offset(options);
+
+    let formatter = get_formatter(options.relative_format);
+
+    let validity_vec_len = validity_vec.len();
+
+    for (us, va) in validity_vec.into_iter().enumerate() {
+        if validity_vec_len > 1_usize {
+            println!(
+                "{}{}",
+                if us == 0_usize { "" } else { "\n" },
+                format!("Certificate {} of {validity_vec_len}", us + 1_usize).bold()
+            );
+        }
+
+        for (st, of) in [("notBefore", va.not_before), ("notAfter", va.not_after)] {
+            println!(
+                "({}) {} {}",
+                pad_to_left(WIDTH, st),
+                of.unix_timestamp(),
+                get_description(&formatter, now, offset, of, options)?
+            );
+        }
 
-    anyhow::ensure!(WIDTH == correct_width);
+        println!("{}", format_verdict(&formatter, va.verdict(now)).bold());
+    }
 
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_check_width() -> anyhow::Result<()> {
-        crate::check_width()
+/// e.g. "Valid, expires in 3 days" or "Expired 2 hours ago"
+fn format_verdict(formatter: &RelativeFormatter, verdict: Verdict) -> String {
+    match verdict {
+        Verdict::NotYetValid(du) => format!(
+            "Not valid yet, becomes valid {}",
+            format_relative(formatter, du)
+        )
+        .yellow()
+        .to_string(),
+        Verdict::Valid(Some(du)) => format!("Valid, expires {}", format_relative(formatter, du))
+            .green()
+            .to_string(),
+        Verdict::Valid(None) => "Valid, never expires".green().to_string(),
+        Verdict::Expired(du) => format!("Expired {}", format_relative(formatter, -du))
+            .red()
+            .to_string(),
+    }
+}
+
+#[expect(clippy::too_many_lines, reason = "Unimportant")]
+fn print_candidates(
+    number: Number,
+    mut has_printed_note: bool,
+    options: &Options,
+    timing: &mut Timing,
+) -> anyhow::Result<ExitCode> {
+    const DATA_ARRAY_LEN: usize = Unit::ALL.len();
+
+    let integer_part = number.integer_part().unwrap_or(i128::MAX);
+
+    if integer_part > MAXIMUM_NUMBER {
+        eprintln!(
+            "{}",
+            format!(
+                "ERROR: Timestamp candidate {number} is too large (greater than {MAXIMUM_NUMBER})"
+            )
+            .red()
+        );
+
+        return Ok(ExitCode::from(EXIT_CODE_OUT_OF_RANGE));
+    }
+
+    if integer_part < MINIMUM_NUMBER {
+        eprintln!(
+            "{}",
+            format!(
+                "ERROR: Timestamp candidate {number} is too small (less than {MINIMUM_NUMBER})"
+            )
+            .red()
+        );
+
+        return Ok(ExitCode::from(EXIT_CODE_OUT_OF_RANGE));
+    }
+
+    let settings = &options.settings;
+
+    let now = settings.now();
+
+    let offset = Timing::measure(&mut timing.zone_resolution, || get_offset(options));
+
+    if offset.is_none() {
+        has_printed_note = true;
+    }
+
+    let formatter = get_formatter(options.relative_format);
+
+    let candidate_vec = match (options.unit, options.preset) {
+        (Some(un), _) => vec![readtimestamp::interpret::interpret_in(number, un, settings)],
+        (None, Some(pr)) => {
+            readtimestamp::interpret::interpret_preferring(number, pr.unit, settings)
+        }
+        (None, None) => readtimestamp::interpret::interpret(number, settings),
+    };
+
+    if let Some(pr) = options.preset {
+        if !pr.has_expected_digits(number) {
+            print_note(
+                &format!(
+                    "NOTE: {ARGUMENT_NAME} does not have the {} digits {} usually have",
+                    pr.digits, pr.description
+                ),
+                options,
+            );
+
+            has_printed_note = true;
+        }
+    }
+
+    let instant = Instant::now();
+
+    if print_active_directory_note(integer_part, &candidate_vec, options) {
+        has_printed_note = true;
     }
+
+    if options.unit == Some(Unit::Dos) {
+        print_note(
+            "NOTE: DOS dates and times are in an unknown time zone, so UTC is assumed",
+            options,
+        );
+
+        has_printed_note = true;
+    }
+
+    for ca in &candidate_vec {
+        if let (true, Ok(of)) = (ca.unit.counts_leap_seconds(), ca.result) {
+            if print_leap_second_note(
+                of.unix_timestamp_nanos(),
+                &ca.unit.name().to_uppercase(),
+                options,
+            )? {
+                has_printed_note = true;
+            }
+        }
+    }
+
+    let mut has_none = Vec::<Data>::with_capacity(DATA_ARRAY_LEN);
+    let mut has_some = Vec::<Data>::with_capacity(DATA_ARRAY_LEN);
+
+    // Already sorted by score, best first (`--sort` reorders them below)
+    for ca in candidate_vec {
+        let da = get_data(&formatter, now, offset, number, ca, options)?;
+
+        if da.delta.is_some() {
+            has_some.push(da);
+        } else {
+            has_none.push(da);
+        }
+    }
+
+    // A preset says what the timestamp is, so it is neither a snowflake nor in a custom format
+    if options.unit.is_none() && options.preset.is_none() {
+        if !number.has_fraction() {
+            for (st, la, sn) in readtimestamp::snowflake::interpret(integer_part) {
+                has_some.push(get_snowflake_data(
+                    &formatter, now, offset, st, la, sn, options,
+                )?);
+            }
+        }
+
+        for (ti, of) in readtimestamp::plugin::interpret(number) {
+            has_some.push(get_format_data(
+                &formatter, now, offset, number, ti, of, options,
+            )?);
+        }
+
+        // Snowflakes and custom formats go among the other candidates, by score
+        has_some.sort_by(|da, dat| dat.score.total_cmp(&da.score));
+    }
+
+    if let Some(da) = has_some.first_mut() {
+        da.is_best = true;
+    }
+
+    if print_implausible_note(&has_some, now, options.plausible_years)? {
+        has_printed_note = true;
+    }
+
+    // Errors are still worth printing when nothing could be read
+    if options.best && !has_some.is_empty() {
+        has_some.truncate(1_usize);
+        has_none.clear();
+    }
+
+    // The sorts are stable, so ties stay in order of score
+    match options.sort {
+        SortOrder::Score => {}
+        SortOrder::Delta => has_some.sort_by_key(|da| da.delta.map(time::Duration::abs)),
+        SortOrder::Unit => has_some.sort_by_key(|da| da.unit_position),
+        SortOrder::Chronological => has_some.sort_by_key(|da| da.instant),
+    }
+
+    if options.copy {
+        if let Some(of) = has_some.first().and_then(|da| da.instant) {
+            copy_to_clipboard(&of.to_offset(UtcOffset::UTC).format(&Rfc3339)?, options);
+
+            has_printed_note = true;
+        }
+    }
+
+    if options.ics {
+        if let Some((da, of)) = has_some
+            .iter()
+            .find(|da| da.is_best)
+            .and_then(|da| Some((da, da.instant?)))
+        {
+            print_ics(of, &format!("{number} ({})", da.unit.trim_start()), options)?;
+        }
+
+        timing.formatting += instant.elapsed();
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let reading_vec = has_some
+        .iter()
+        .filter_map(|da| {
+            Some(Reading {
+                label: da.unit.trim_start(),
+                instant: da.instant?,
+                delta: da.delta?,
+                is_best: da.is_best,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if print_readings(&reading_vec, offset, &formatter, options)? {
+        timing.formatting += instant.elapsed();
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if has_printed_note && !options.quiet {
+        // TODO
+        println!();
+    }
+
+    let has_none_is_not_empty = !has_none.is_empty();
+    let has_some_is_not_empty = !has_some.is_empty();
+
+    // Confidence only means something when the candidates were ranked against each other
+    let total_score =
+        if options.unit.is_none() && options.preset.is_none() && has_some.len() > 1_usize {
+            has_some.iter().map(|da| da.score).sum::<f64>()
+        } else {
+            0_f64
+        };
+
+    if has_some_is_not_empty {
+        for (us, da) in has_some.into_iter().enumerate() {
+            let description = &da.description;
+            let unit = &da.unit;
+
+            let is_best_candidate_unit = da.is_best;
+            // In other orders the best candidate is only in bold, since it may not come first
+            let has_header =
+                is_best_candidate_unit && options.sort == SortOrder::Score && !options.best;
+
+            let unit_description = if total_score > 0_f64 {
+                format!(
+                    "({unit}) {description} {}",
+                    format!("[{:.0}%]", da.score / total_score * 100_f64).dimmed()
+                )
+            } else {
+                format!("({unit}) {description}")
+            };
+
+            println!(
+                "{}{}{}",
+                if us == 1_usize && options.sort == SortOrder::Score {
+                    "\n"
+                } else {
+                    ""
+                },
+                if has_header {
+                    format!("{}\n", "Best candidate unit:".bold().green())
+                } else {
+                    String::new()
+                },
+                if is_best_candidate_unit {
+                    unit_description.bold().to_string()
+                } else {
+                    unit_description
+                }
+            );
+
+            if let Some(st) = &da.julian_dates {
+                if is_best_candidate_unit {
+                    println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+                }
+            }
+
+            if is_best_candidate_unit {
+                for st in &da.conversions {
+                    println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+                }
+            }
+
+            if let Some(st) = &da.calendar_info {
+                println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+            }
+
+            if let Some(st) = &da.business_days {
+                println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+            }
+
+            if let Some(st) = &da.daylight {
+                println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+            }
+
+            if let Some(st) = &da.totp_step {
+                println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+            }
+
+            if let Some(st) = &da.truncation {
+                println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+            }
+
+            if let Some(st) = &da.transition {
+                println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+            }
+
+            for st in &da.explanation {
+                println!("{}{}", " ".repeat(WIDTH + 3_usize), st.dimmed());
+            }
+        }
+    }
+
+    if has_none_is_not_empty && !options.no_errors {
+        if has_some_is_not_empty {
+            // TODO
+            println!();
+        }
+
+        for da in has_none {
+            println!("({}) {}", da.unit, da.description);
+        }
+    }
+
+    timing.formatting += instant.elapsed();
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints `readings` in the format for programs given with `--porcelain`, `--template`, or `--output`, with relative
+/// times written by `formatter`. Returns whether they were printed, or are left to be printed in the format meant for
+/// people.
+fn print_readings(
+    readings: &[Reading<'_>],
+    offset: Option<UtcOffset>,
+    formatter: &RelativeFormatter,
+    options: &Options,
+) -> anyhow::Result<bool> {
+    if options.porcelain {
+        for re in readings {
+            println!(
+                "{}\t{}\t{}",
+                re.label,
+                readtimestamp::date_time::format_rfc3339(re.instant.to_offset(UtcOffset::UTC)),
+                re.delta.whole_seconds()
+            );
+        }
+
+        return Ok(true);
+    }
+
+    let records = readings
+        .iter()
+        .map(|re| {
+            Record::new(
+                re.label,
+                re.instant,
+                local_offset_at(re.instant, offset, &options.settings),
+                re.delta,
+                re.is_best,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(te) = &options.template {
+        for (re, record) in readings.iter().zip(&records) {
+            println!(
+                "{}",
+                te.render(|st| match st {
+                    "unit" => record.unit.clone(),
+                    "utc" => record.utc.clone(),
+                    "local" => record.local.clone(),
+                    "relative" => format_relative(formatter, re.delta),
+                    "delta_seconds" => record.delta_seconds.to_string(),
+                    _ => record.best.to_string(),
+                })
+            );
+        }
+
+        return Ok(true);
+    }
+
+    match options.output {
+        OutputFormat::Human => return Ok(false),
+        OutputFormat::Table => readtimestamp::output::write_table(
+            &records,
+            |re| format_relative(formatter, time::Duration::seconds(re.delta_seconds)),
+            io::stdout().lock(),
+        )?,
+        OutputFormat::Csv => readtimestamp::output::write_csv(&records, io::stdout().lock())?,
+        OutputFormat::Yaml => readtimestamp::output::write_yaml(&records, io::stdout().lock())?,
+        OutputFormat::Jsonl => readtimestamp::output::write_jsonl(&records, io::stdout().lock())?,
+    }
+
+    Ok(true)
+}
+
+/// Warns that the input is probably not a timestamp at all (e.g. a random ID) when every readable candidate is more
+/// than `plausible_years` from `now`, pointing out the nearest one. Unlike other notes, this is printed even with
+/// `--quiet`. Returns whether a note was printed.
+fn print_implausible_note(
+    has_some: &[Data],
+    now: OffsetDateTime,
+    plausible_years: u32,
+) -> anyhow::Result<bool> {
+    let nearest = has_some
+        .iter()
+        .filter_map(|da| Some((da, da.instant?)))
+        .min_by_key(|(_, of)| (*of - now).abs());
+
+    let Some((da, of)) = nearest else {
+        return Ok(false);
+    };
+
+    if readtimestamp::interpret::years_between(of, now) <= f64::from(plausible_years) {
+        return Ok(false);
+    }
+
+    eprintln!(
+        "{}",
+        format!(
+            "WARNING: Every candidate is more than {plausible_years} years from now, so {ARGUMENT_NAME} is probably not a timestamp (the nearest reading is {}, in {})",
+            of.to_offset(UtcOffset::UTC).format(&Rfc3339)?,
+            da.unit.trim_start()
+        )
+        .yellow()
+    );
+
+    Ok(true)
+}
+
+/// Points out values that are probably Active Directory timestamps, and the special values Active Directory uses for
+/// "never". Returns whether a note was printed.
+fn print_active_directory_note(
+    integer_part: i128,
+    candidate_vec: &[Candidate],
+    options: &Options,
+) -> bool {
+    match options.unit {
+        Some(Unit::Filetime) => {
+            if integer_part == 0_i128 || integer_part == i128::from(i64::MAX) {
+                print_note("NOTE: In Active Directory, this value means \"never\" (e.g. an account that never expires)", options);
+
+                return true;
+            }
+        }
+        None => {
+            let is_plausible_filetime = candidate_vec
+                .iter()
+                .any(|ca| ca.unit == Unit::Filetime && ca.is_plausible());
+
+            if is_plausible_filetime && integer_part.unsigned_abs().to_string().len() == 18_usize {
+                print_note(&format!(
+                        "NOTE: {ARGUMENT_NAME} looks like a Windows FILETIME or Active Directory timestamp (e.g. pwdLastSet or lastLogonTimestamp), pass \"--unit ad\" to only interpret it that way"
+                    ), options);
+
+                return true;
+            }
+        }
+        Some(_) => {}
+    }
+
+    false
+}
+
+/// Prints `note` to standard error, unless notes are left out
+fn print_note(note: &str, options: &Options) {
+    if !options.quiet {
+        eprintln!("{}", note.yellow());
+    }
+}
+
+/// Points out instants within a minute of a leap second, where conversions between UTC and `time_scales` (which count
+/// leap seconds) are easily off by a second. Returns whether a note was printed.
+fn print_leap_second_note(
+    unix_nanoseconds: i128,
+    time_scales: &str,
+    options: &Options,
+) -> anyhow::Result<bool> {
+    let Some(io) = readtimestamp::leap_seconds::nearby(unix_nanoseconds) else {
+        return Ok(false);
+    };
+
+    // The leap second is the last second of the day before
+    let date = OffsetDateTime::from_unix_timestamp(i64::try_from(io - 1_i128)?)?.date();
+
+    print_note(&format!(
+            "NOTE: This instant is within a minute of the leap second at the end of {} (23:59:60 UTC), so {time_scales} conversions across it are easily off by a second",
+            date.format(DATE_FORMAT_DESCRIPTION)?
+        ), options);
+
+    Ok(true)
+}
+
+/// Makes the snowflake presets in the configuration file, and the layout given on the command line (if any), be tried
+/// along with the known ones
+fn add_snowflake_layouts(
+    snowflake_epoch: Option<i64>,
+    snowflake_bits: Bits,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let mut layout_vec = config
+        .snowflakes
+        .iter()
+        .map(|(st, sn)| (st.clone(), sn.layout()))
+        .collect::<Vec<_>>();
+
+    if let Some(io) = snowflake_epoch {
+        layout_vec.push(("Custom".to_owned(), Layout::new(io, snowflake_bits)));
+    }
+
+    readtimestamp::snowflake::add_layouts(layout_vec)
+}
+
+fn get_annotator(
+    pattern: Option<&str>,
+    delta: Option<DeltaSelector>,
+    profile: Option<&str>,
+    config: Option<PathBuf>,
+    boot_time: Option<OffsetDateTime>,
+    date: Option<Date>,
+) -> anyhow::Result<Annotator> {
+    // Only needed if the pattern has times since boot, which is checked by Annotator::new
+    let boot_time = boot_time.or_else(|| readtimestamp::boot::boot_time().ok());
+
+    let annotator = match profile {
+        Some(st) => {
+            let config = Config::load(config)?;
+
+            let pr = config.profile(st)?;
+
+            Annotator::new(
+                Some(&pr.pattern),
+                pr.unit,
+                pr.zone,
+                delta,
+                boot_time,
+                date,
+                Settings::default(),
+            )?
+        }
+        None => Annotator::new(
+            pattern,
+            None,
+            None,
+            delta,
+            boot_time,
+            date,
+            Settings::default(),
+        )?,
+    };
+
+    Ok(annotator)
+}
+
+fn annotate(
+    mut annotator: Annotator,
+    max_failed: Option<usize>,
+    max_ambiguous: Option<usize>,
+    timing: bool,
+    output: OutputFormat,
+) -> anyhow::Result<ExitCode> {
+    anyhow::ensure!(
+        matches!(output, OutputFormat::Human | OutputFormat::Jsonl),
+        "annotate can only print \"human\" or \"jsonl\" output"
+    );
+
+    // Not lines(), since journal exports have binary fields that are not UTF-8
+    for re in io::stdin().lock().split(b'\n') {
+        let by = re?;
+
+        let line = String::from_utf8_lossy(by.strip_suffix(b"\r").unwrap_or(&by));
+
+        if output == OutputFormat::Jsonl {
+            println!("{}", annotator.annotate_line_json(&line)?);
+        } else {
+            println!("{}", annotator.annotate_line(&line)?);
+        }
+    }
+
+    let tally = annotator.tally();
+
+    eprintln!("{}", format!("Summary: {tally}").dimmed());
+
+    if timing {
+        eprintln!("{}", format!("Timing: {}", annotator.timing()).dimmed());
+    }
+
+    let exceeds = |count: usize, maximum: Option<usize>| maximum.is_some_and(|us| count > us);
+
+    if exceeds(tally.failed, max_failed) || exceeds(tally.ambiguous, max_ambiguous) {
+        eprintln!(
+            "{}",
+            "ERROR: More timestamps failed or were ambiguous than allowed".red()
+        );
+
+        return Ok(ExitCode::from(EXIT_CODE_THRESHOLD_EXCEEDED));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn convert_csv(
+    path: &Path,
+    column: NonZeroUsize,
+    unit: Unit,
+    to_timestamps: bool,
+    delimiter: Option<char>,
+    header: bool,
+) -> anyhow::Result<()> {
+    let delimiter = match delimiter {
+        Some(ch) => u8::try_from(ch).ok().filter(u8::is_ascii).ok_or_else(|| {
+            anyhow::anyhow!("The delimiter has to be an ASCII character, not '{ch}'")
+        })?,
+        None if path
+            .extension()
+            .is_some_and(|os| os.eq_ignore_ascii_case("tsv")) =>
+        {
+            b'\t'
+        }
+        None => b',',
+    };
+
+    let direction = if to_timestamps {
+        Direction::ToTimestamps
+    } else {
+        Direction::ToDates
+    };
+
+    let column = column.get() - 1_usize;
+
+    let stdout = io::stdout().lock();
+
+    let tally = if path == Path::new("-") {
+        readtimestamp::column::convert_column(
+            io::stdin().lock(),
+            stdout,
+            column,
+            unit,
+            direction,
+            delimiter,
+            header,
+        )?
+    } else {
+        let file = File::open(path)
+            .map_err(|er| anyhow::anyhow!("Could not open \"{}\": {er}", path.display()))?;
+
+        readtimestamp::column::convert_column(
+            file, stdout, column, unit, direction, delimiter, header,
+        )?
+    };
+
+    eprintln!("{}", format!("Summary: {tally}").dimmed());
+
+    Ok(())
+}
+
+fn print_bounds(date: Date, zone: Zone, twenty_four_hour_clock: bool) -> anyhow::Result<()> {
+    let bounds_vec = readtimestamp::bounds::bounds(date, zone)?;
+
+    println!(
+        "{}",
+        format!(
+            "Bounds of the periods containing {} ({zone}):",
+            date.format(DATE_FORMAT_DESCRIPTION)?
+        )
+        .bold()
+    );
+
+    for bo in bounds_vec {
+        let period = pad_to_left(5_usize, bo.period.name());
+
+        println!(
+            "({period}) {} to {}",
+            bo.start
+                .format(on_clock(
+                    FORMAT_DESCRIPTION,
+                    FORMAT_DESCRIPTION_24_HOUR,
+                    twenty_four_hour_clock,
+                ))?
+                .purple(),
+            (bo.end_exclusive - time::Duration::SECOND)
+                .format(on_clock(
+                    FORMAT_DESCRIPTION,
+                    FORMAT_DESCRIPTION_24_HOUR,
+                    twenty_four_hour_clock,
+                ))?
+                .purple()
+        );
+        println!(
+            "             seconds: {} to {}",
+            bo.start_seconds().blue(),
+            bo.end_seconds().blue()
+        );
+        println!(
+            "        milliseconds: {} to {}",
+            bo.start_milliseconds().blue(),
+            bo.end_milliseconds().blue()
+        );
+    }
+
+    Ok(())
+}
+
+fn run_countdown(target: OffsetDateTime, state_file: Option<PathBuf>) -> anyhow::Result<()> {
+    let state_path = match state_file {
+        Some(pa) => pa,
+        None => readtimestamp::countdown::default_state_path()?,
+    };
+
+    let resized = Arc::new(AtomicBool::new(false));
+    let dump_requested = Arc::new(AtomicBool::new(false));
+
+    #[cfg(unix)]
+    {
+        signal_hook::flag::register(signal_hook::consts::SIGWINCH, Arc::clone(&resized))?;
+        signal_hook::flag::register(signal_hook::consts::SIGUSR1, Arc::clone(&dump_requested))?;
+    }
+
+    let mut width = get_terminal_width();
+
+    let mut previous_line = String::new();
+
+    loop {
+        let state = State {
+            target,
+            now: OffsetDateTime::now_utc(),
+        };
+
+        if resized.swap(false, Ordering::Relaxed) {
+            width = get_terminal_width();
+
+            // Force a redraw, even if the line fits the new width unchanged
+            previous_line.clear();
+        }
+
+        if dump_requested.swap(false, Ordering::Relaxed) {
+            readtimestamp::countdown::dump(&state_path, &state)?;
+        }
+
+        let line = readtimestamp::countdown::fit_to_width(&state.line()?, width);
+
+        if line != previous_line {
+            // Erase the whole line first, in case the previous one was longer
+            print!("\r\x1b[2K{line}");
+
+            io::stdout().flush()?;
+
+            previous_line = line;
+        }
+
+        if state.is_finished() {
+            println!();
+
+            return Ok(());
+        }
+
+        thread::sleep(COUNTDOWN_POLL_INTERVAL);
+    }
+}
+
+fn get_terminal_width() -> usize {
+    terminal_size::terminal_size().map_or(DEFAULT_TERMINAL_WIDTH, |(Width(us), _)| usize::from(us))
+}
+
+fn print_every(
+    schedule: Schedule,
+    count: usize,
+    zone: Zone,
+    twenty_four_hour_clock: bool,
+) -> anyhow::Result<()> {
+    let now_utc = OffsetDateTime::now_utc();
+
+    let instant_vec = schedule.upcoming(now_utc, zone, count)?;
+
+    let formatter = get_formatter(RelativeFormat::default());
+
+    println!("{}", format!("Next {count} instants ({zone}):").bold());
+
+    for of in instant_vec {
+        let local = of.to_offset(zone.offset_at(of)?);
+
+        println!(
+            "{} {} ({})",
+            local
+                .format(on_clock(
+                    FORMAT_DESCRIPTION,
+                    FORMAT_DESCRIPTION_24_HOUR,
+                    twenty_four_hour_clock,
+                ))?
+                .purple(),
+            of.unix_timestamp().blue(),
+            format_relative(&formatter, of - now_utc).cyan()
+        );
+    }
+
+    Ok(())
+}
+
+fn print_fixture(shape: &Shape, count: usize, days: u16, seed: Option<u64>) -> anyhow::Result<()> {
+    let now_utc = OffsetDateTime::now_utc();
+
+    // Truncating the current time is fine for a seed
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "Unimportant"
+    )]
+    let seed = seed.unwrap_or(now_utc.unix_timestamp_nanos() as u64);
+
+    let value = readtimestamp::fixture::generate(
+        shape,
+        count,
+        now_utc - time::Duration::days(i64::from(days)),
+        now_utc,
+        seed,
+    )?;
+
+    println!("{}", serde_json::to_string_pretty(&value)?);
+
+    Ok(())
+}
+
+fn print_lap(session: &str, reset: bool, twenty_four_hour_clock: bool) -> anyhow::Result<()> {
+    let path = readtimestamp::lap::session_path(session)?;
+
+    if reset {
+        readtimestamp::lap::reset(&path)?;
+    }
+
+    let now_utc = OffsetDateTime::now_utc();
+
+    let la = readtimestamp::lap::record(&path, now_utc)?;
+
+    let formatter = get_formatter(RelativeFormat::default());
+
+    println!(
+        "Lap {}: UTC: {}",
+        la.number.bold(),
+        format_date_time(la.instant, twenty_four_hour_clock)?.blue()
+    );
+
+    match la.since_previous {
+        Some(du) => {
+            println!(
+                "  since lap {}: {}",
+                la.number - 1_usize,
+                formatter.convert(du.unsigned_abs()).cyan()
+            );
+            println!(
+                "  since start: {}",
+                formatter.convert(la.since_start.unsigned_abs()).cyan()
+            );
+        }
+        None => {
+            println!("  started session \"{session}\"");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_lifetime(
+    issued: Number,
+    lifetime: Number,
+    warn_percent: f64,
+    critical_percent: f64,
+    twenty_four_hour_clock: bool,
+) -> anyhow::Result<ExitCode> {
+    let issued_nanos = issued
+        .nanoseconds(Unit::Seconds)
+        .ok_or_else(|| anyhow::anyhow!("Issue time {issued} is too large"))?;
+
+    let lifetime_nanos = lifetime
+        .nanoseconds(Unit::Seconds)
+        .ok_or_else(|| anyhow::anyhow!("Lifetime {lifetime} is too large"))?;
+
+    let lifetime_duration = time::Duration::new(
+        i64::try_from(lifetime_nanos.div_euclid(1_000_000_000_i128))?,
+        i32::try_from(lifetime_nanos.rem_euclid(1_000_000_000_i128))?,
+    );
+
+    let now_utc = OffsetDateTime::now_utc();
+
+    let li = Lifetime::new(
+        OffsetDateTime::from_unix_timestamp_nanos(issued_nanos)?,
+        lifetime_duration,
+        now_utc,
+        warn_percent,
+        critical_percent,
+    )?;
+
+    let formatter = get_formatter(RelativeFormat::default());
+
+    println!(
+        "   Issued: UTC: {} ({})",
+        format_date_time(li.issued, twenty_four_hour_clock)?.blue(),
+        format_relative(&formatter, li.issued - now_utc).cyan()
+    );
+    println!(
+        "  Expires: UTC: {} ({})",
+        format_date_time(li.expires, twenty_four_hour_clock)?.blue(),
+        format_relative(&formatter, li.expires - now_utc).cyan()
+    );
+    println!(
+        "Remaining: {} ({:.1}% of lifetime consumed)",
+        if li.remaining.is_positive() {
+            formatter.convert(li.remaining.unsigned_abs())
+        } else {
+            "none".to_owned()
+        },
+        li.consumed_percent
+    );
+
+    let status = li.status.name();
+
+    let exit_code = match li.status {
+        Status::NotYetValid | Status::Valid => {
+            println!("   Status: {}", status.bold().green());
+
+            ExitCode::SUCCESS
+        }
+        Status::Warning => {
+            println!("   Status: {}", status.bold().yellow());
+
+            ExitCode::from(EXIT_CODE_LIFETIME_WARNING)
+        }
+        Status::Expired => {
+            println!("   Status: {}", status.bold().red());
+
+            ExitCode::from(EXIT_CODE_LIFETIME_EXPIRED)
+        }
+    };
+
+    Ok(exit_code)
+}
+
+/// Prints the man page generated from the argument definitions, followed by the units, presets, and exit codes
+fn print_man_page() -> anyhow::Result<()> {
+    let mut man_page = Vec::<u8>::new();
+
+    clap_mangen::Man::new(ReadtimestampArgs::command()).render(&mut man_page)?;
+
+    let mut write_section = |title: &str, items: &[(String, String)]| -> anyhow::Result<()> {
+        writeln!(man_page, ".SH \"{title}\"")?;
+
+        for (term, description) in items {
+            writeln!(
+                man_page,
+                ".TP\n\\fB{}\\fR\n{}",
+                escape_roff(term),
+                escape_roff(description)
+            )?;
+        }
+
+        Ok(())
+    };
+
+    write_section(
+        "UNITS",
+        &Unit::ALL.map(|un| {
+            (
+                un.name().to_owned(),
+                format!("Tick: {}; epoch: {}", un.tick_name(), un.epoch_name()),
+            )
+        }),
+    )?;
+
+    write_section(
+        "PRESETS",
+        &PRESETS.map(|pr| {
+            (
+                pr.name.to_owned(),
+                format!("{}, read as {}", pr.description, pr.unit.name()),
+            )
+        }),
+    )?;
+
+    write_section(
+        "EXIT STATUS",
+        &[
+            (0_u8, "Success"),
+            (1_u8, "An error"),
+            (2_u8, "The arguments are invalid"),
+            (
+                EXIT_CODE_LIFETIME_WARNING,
+                "lifetime: more than --warn-percent of the lifetime has passed",
+            ),
+            (
+                EXIT_CODE_LIFETIME_EXPIRED,
+                "lifetime: the session or token has expired (--critical-percent)",
+            ),
+            (
+                EXIT_CODE_NO_TIMESTAMP,
+                "The input does not contain a timestamp (e.g. it has no numbers, or it is a UUID version without one)",
+            ),
+            (
+                EXIT_CODE_OUT_OF_RANGE,
+                "The timestamp has too many digits, or is too large or too small to be an instant",
+            ),
+            (
+                EXIT_CODE_UNPARSABLE,
+                "The timestamp could not be parsed (e.g. a malformed JWT or hexadecimal number)",
+            ),
+            (
+                EXIT_CODE_THRESHOLD_EXCEEDED,
+                "annotate: more timestamps failed or were ambiguous than --max-failed or --max-ambiguous allow",
+            ),
+        ]
+        .map(|(ex, st)| (ex.to_string(), st.to_owned())),
+    )?;
+
+    io::stdout().write_all(&man_page)?;
+
+    Ok(())
+}
+
+/// Escapes the characters that roff would otherwise interpret
+fn escape_roff(input: &str) -> String {
+    let escaped = input.replace('\\', "\\e").replace('-', "\\-");
+
+    if escaped.starts_with(['.', '\'']) {
+        format!("\\&{escaped}")
+    } else {
+        escaped
+    }
+}
+
+/// Prints a tab-separated line per instant: its value in `unit`, and its RFC 3339 form in `zone` (without colors, since
+/// the lines are meant for other programs)
+fn print_range(
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    step: time::Duration,
+    unit: Unit,
+    zone: Zone,
+) -> anyhow::Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    for of in readtimestamp::series::series(start, end, step)? {
+        writeln!(
+            stdout,
+            "{}\t{}",
+            unit.format_from_unix_nanoseconds(of.unix_timestamp_nanos()),
+            of.to_offset(zone.offset_at(of)?).format(&Rfc3339)?
+        )?;
+    }
+
+    Ok(())
+}
+
+fn print_ranges(start_year: i32, end_year: i32) -> anyhow::Result<()> {
+    let unit_range_vec = readtimestamp::unit_ranges(start_year, end_year)?;
+
+    println!(
+        "{}",
+        format!("Values that correspond to the years {start_year} through {end_year} (UTC):")
+            .bold()
+    );
+
+    for un in unit_range_vec {
+        let unit = pad_to_left(WIDTH, un.unit.name());
+
+        println!(
+            "({unit}) {} to {} ({})",
+            un.start.blue(),
+            un.end.blue(),
+            format!("{} to {} digits", un.start_digits(), un.end_digits()).cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Lists the zones whose names contain `pattern` (every zone if it is `None`), with their current offsets
+fn print_zones(pattern: Option<&str>) -> anyhow::Result<()> {
+    let name_vec = readtimestamp::tzinfo::zone_names(pattern.unwrap_or_default());
+
+    if name_vec.is_empty() {
+        anyhow::bail!(
+            "No time zone name contains \"{}\"",
+            pattern.unwrap_or_default()
+        );
+    }
+
+    let now = OffsetDateTime::now_utc();
+
+    let width = name_vec.iter().map(|st| st.len()).max().unwrap_or_default();
+
+    for st in name_vec {
+        let zone = Zone::Named(st);
+
+        println!(
+            "{st:<width$}  {}{}",
+            format_offset(zone.offset_at(now)?).bold(),
+            zone.abbreviation_at(now)
+                .map(|abb| format!(" {abb}"))
+                .unwrap_or_default()
+        );
+    }
+
+    Ok(())
+}
+
+fn print_tzinfo(
+    zone: &str,
+    around: Option<OffsetDateTime>,
+    count: usize,
+    twenty_four_hour_clock: bool,
+) -> anyhow::Result<()> {
+    let zone_info = readtimestamp::tzinfo::zone_info(
+        zone,
+        around.unwrap_or_else(OffsetDateTime::now_utc),
+        count,
+    )?;
+
+    let format_local_type = |lo: &LocalType| {
+        format!(
+            "{} {}{}",
+            Zone::Fixed(lo.offset),
+            lo.abbreviation,
+            if lo.is_dst { " (DST)" } else { "" }
+        )
+    };
+
+    let format_transition = |tr: &Transition| -> anyhow::Result<String> {
+        Ok(format!(
+            "  UTC: {} local: {}: {} -> {}",
+            format_date_time(tr.instant, twenty_four_hour_clock)?.blue(),
+            format_date_time(
+                tr.instant.to_offset(tr.after.offset),
+                twenty_four_hour_clock
+            )?
+            .purple(),
+            format_local_type(&tr.before),
+            format_local_type(&tr.after).bold()
+        ))
+    };
+
+    println!(
+        "{} (time zone database {})",
+        zone_info.name.bold(),
+        readtimestamp::tzinfo::database_version()
+    );
+    println!(
+        "At UTC: {}: {}",
+        format_date_time(zone_info.around, twenty_four_hour_clock)?.blue(),
+        format_local_type(&zone_info.local_type).bold()
+    );
+
+    println!("\nPrevious transitions:");
+
+    if zone_info.previous.is_empty() {
+        println!("  none");
+    }
+
+    for tr in zone_info.previous.iter().rev() {
+        println!("{}", format_transition(tr)?);
+    }
+
+    println!("\nNext transitions:");
+
+    if zone_info.next.is_empty() {
+        println!("  none");
+    }
+
+    for tr in &zone_info.next {
+        println!("{}", format_transition(tr)?);
+    }
+
+    Ok(())
+}
+
+fn parse_now(input: &str) -> anyhow::Result<OffsetDateTime> {
+    if let Some((_, of)) = parse_date_time(input) {
+        return Ok(of);
+    }
+
+    let nanoseconds = input
+        .parse::<Number>()?
+        .unix_nanoseconds(Unit::Seconds)
+        .ok_or_else(|| anyhow::anyhow!("\"{input}\" is too large"))?;
+
+    Ok(OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)?)
+}
+
+fn parse_date(input: &str) -> anyhow::Result<Date> {
+    Ok(Date::parse(input, DATE_FORMAT_DESCRIPTION)?)
+}
+
+fn parse_time_unit(input: &str) -> anyhow::Result<TimeUnit> {
+    let time_unit = match input.to_ascii_lowercase().as_str() {
+        "ns" | "nanoseconds" => TimeUnit::Nanoseconds,
+        "us" | "µs" | "microseconds" => TimeUnit::Microseconds,
+        "ms" | "milliseconds" => TimeUnit::Milliseconds,
+        "s" | "seconds" => TimeUnit::Seconds,
+        "min" | "minutes" => TimeUnit::Minutes,
+        "h" | "hours" => TimeUnit::Hours,
+        "d" | "days" => TimeUnit::Days,
+        "w" | "weeks" => TimeUnit::Weeks,
+        "mo" | "months" => TimeUnit::Months,
+        "y" | "years" => TimeUnit::Years,
+        _ => anyhow::bail!(
+            "Unrecognized unit \"{input}\" (expected ns, us, ms, s, min, h, d, w, mo, or y)"
+        ),
+    };
+
+    Ok(time_unit)
+}
+
+fn get_attempting_to_parse_string(timestamp: &str) -> (String, usize) {
+    const PREFIX: &str = "Attempting to parse \"";
+    const SUFFIX: &str = "\"";
+
+    // TODO
+    // Unchecked arithmetic
+    const PREFIX_LEN_PLUS_SUFFIX_LEN: usize = PREFIX.len() + SUFFIX.len();
+
+    let attempting_to_parse_string = format!("{PREFIX}{}{SUFFIX}", timestamp.bold());
+
+    (
+        attempting_to_parse_string,
+        PREFIX_LEN_PLUS_SUFFIX_LEN + timestamp.len(),
+    )
+}
+
+/// Prints a note if the offset cannot be determined
+fn get_offset(options: &Options) -> Option<UtcOffset> {
+    let settings = &options.settings;
+
+    match settings.zone.offset_at(settings.now()) {
+        Ok(ut) => Some(ut),
+        Err(er) => {
+            print_note(&format!("NOTE: Could not determine current time zone offset. Dates will only be displayed in UTC. Error reported: \"{er}\"."), options);
+
+            None
+        }
+    }
+}
+
+/// The local offset in effect at `offset_date_time`, which differs from the current one, `offset`, across daylight
+/// saving time, or `None` if the local offset is unknown
+fn local_offset_at(
+    offset_date_time: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    settings: &Settings,
+) -> Option<UtcOffset> {
+    offset.map(|ut| settings.zone.offset_at(offset_date_time).unwrap_or(ut))
+}
+
+fn get_data(
+    formatter: &RelativeFormatter,
+    now: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    number: Number,
+    candidate: Candidate,
+    options: &Options,
+) -> anyhow::Result<Data> {
+    let unit = candidate.unit;
+
+    let unit_name = unit.name();
+
+    let unit_str = pad_to_left(WIDTH, unit_name);
+
+    let unit_position = Unit::ALL
+        .iter()
+        .position(|un| *un == unit)
+        .unwrap_or(Unit::ALL.len());
+
+    let data = match candidate.result {
+        Ok(original) => {
+            let of = truncate(original, options)?;
+
+            let description = get_description(formatter, now, offset, of, options)?;
+
+            let calendar_info =
+                get_calendar_info(of, local_offset_at(of, offset, &options.settings), options)?;
+
+            let explanation = if options.explain {
+                get_explanation(
+                    number,
+                    unit,
+                    original,
+                    local_offset_at(original, offset, &options.settings),
+                    options,
+                )?
+            } else {
+                Vec::new()
+            };
+
+            Data {
+                business_days: get_business_days(of, now, offset, options),
+                calendar_info,
+                conversions: get_conversions(of, options),
+                daylight: get_daylight(of, offset, options)?,
+                delta: candidate.delta.map(|du| du - (original - of)),
+                description,
+                explanation,
+                instant: Some(of),
+                is_best: false,
+                julian_dates: options.julian_dates.then(|| format_julian_dates(of)),
+                score: candidate.score,
+                totp_step: get_totp_step(of, options),
+                transition: get_transition(of, &options.settings),
+                truncation: get_truncation(of, unit, options),
+                unit: unit_str,
+                unit_position,
+            }
+        }
+        Err(CandidateError::OutOfRange(co)) => Data {
+            business_days: None,
+            calendar_info: None,
+            conversions: Vec::new(),
+            daylight: None,
+            description: format!("error reported: \"{co}\""),
+            delta: None,
+            explanation: Vec::new(),
+            instant: None,
+            is_best: false,
+            julian_dates: None,
+            score: 0_f64,
+            totp_step: None,
+            transition: None,
+            truncation: None,
+            unit: unit_str,
+            unit_position,
+        },
+        Err(CandidateError::TooLarge) => Data {
+            business_days: None,
+            calendar_info: None,
+            conversions: Vec::new(),
+            daylight: None,
+            description: format!("error: number was too large to interpret as {unit_name}"),
+            delta: None,
+            explanation: Vec::new(),
+            instant: None,
+            is_best: false,
+            julian_dates: None,
+            score: 0_f64,
+            totp_step: None,
+            transition: None,
+            truncation: None,
+            unit: unit_str,
+            unit_position,
+        },
+    };
+
+    Ok(data)
+}
+
+/// A snowflake reading, labeled with the variant and showing the worker ID and sequence number
+fn get_snowflake_data(
+    formatter: &RelativeFormatter,
+    now: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    name: &str,
+    layout: Layout,
+    snowflake: Snowflake,
+    options: &Options,
+) -> anyhow::Result<Data> {
+    let of = truncate(snowflake.instant, options)?;
+
+    let calendar_info =
+        get_calendar_info(of, local_offset_at(of, offset, &options.settings), options)?;
+
+    // Compared with the first ID generated now
+    let digit_difference = layout
+        .first_id_at(snowflake.instant)
+        .zip(layout.first_id_at(now))
+        .map(|(io, int)| readtimestamp::interpret::digit_difference(io, int));
+
+    let mut explanation = vec![format!(
+        "{name} Snowflake ID: worker {}, sequence {}",
+        snowflake.worker, snowflake.sequence
+    )];
+
+    if options.explain {
+        explanation.push(format!(
+            "{}-bit timestamp: {} milliseconds since {} UTC, which is {} milliseconds since the Unix epoch",
+            layout.timestamp_bits,
+            snowflake.milliseconds,
+            format_date_time(OffsetDateTime::from_unix_timestamp_nanos(
+                i128::from(layout.epoch_milliseconds) * 1_000_000_i128
+            )?, options.twenty_four_hour)?,
+            i128::from(snowflake.milliseconds) + i128::from(layout.epoch_milliseconds)
+        ));
+    }
+
+    Ok(Data {
+        business_days: get_business_days(of, now, offset, options),
+        calendar_info,
+        conversions: get_conversions(of, options),
+        daylight: get_daylight(of, offset, options)?,
+        delta: Some(of - now),
+        description: get_description(formatter, now, offset, of, options)?,
+        explanation,
+        instant: Some(of),
+        is_best: false,
+        julian_dates: options.julian_dates.then(|| format_julian_dates(of)),
+        score: readtimestamp::interpret::score(snowflake.instant, now, digit_difference),
+        totp_step: get_totp_step(of, options),
+        transition: get_transition(of, &options.settings),
+        truncation: get_truncation(of, Unit::Milliseconds, options),
+        unit: pad_to_left(WIDTH, SNOWFLAKE),
+        unit_position: Unit::ALL.len(),
+    })
+}
+
+/// A reading in a format loaded from the formats directory, labeled with the format's name
+fn get_format_data(
+    formatter: &RelativeFormatter,
+    now: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    number: Number,
+    timestamp_format: &dyn TimestampFormat,
+    offset_date_time: OffsetDateTime,
+    options: &Options,
+) -> anyhow::Result<Data> {
+    let original = offset_date_time;
+
+    let offset_date_time = truncate(offset_date_time, options)?;
+
+    let calendar_info = get_calendar_info(
+        offset_date_time,
+        local_offset_at(offset_date_time, offset, &options.settings),
+        options,
+    )?;
+
+    Ok(Data {
+        business_days: get_business_days(offset_date_time, now, offset, options),
+        calendar_info,
+        conversions: get_conversions(offset_date_time, options),
+        daylight: get_daylight(offset_date_time, offset, options)?,
+        delta: Some(offset_date_time - now),
+        description: get_description(formatter, now, offset, offset_date_time, options)?,
+        explanation: vec![timestamp_format.describe(number)],
+        instant: Some(offset_date_time),
+        is_best: false,
+        julian_dates: options
+            .julian_dates
+            .then(|| format_julian_dates(offset_date_time)),
+        score: readtimestamp::interpret::score(original, now, None),
+        totp_step: get_totp_step(offset_date_time, options),
+        transition: get_transition(offset_date_time, &options.settings),
+        truncation: get_truncation(offset_date_time, Unit::Seconds, options),
+        unit: pad_to_left(WIDTH, timestamp_format.name()),
+        unit_position: Unit::ALL.len(),
+    })
+}
+
+/// The UTC and local date and time of `offset_date_time`, and how long before or after `now` it is
+///
+/// With `auto_precision`, the precision depends on how far from `now` the instant is (see [`Precision`])
+fn get_description(
+    formatter: &RelativeFormatter,
+    now: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    offset_date_time: OffsetDateTime,
+    options: &Options,
+) -> anyhow::Result<String> {
+    let duration = offset_date_time - now;
+
+    let precision = options
+        .auto_precision
+        .then(|| Precision::for_delta(duration));
+
+    let format = |of: OffsetDateTime| match &options.locale {
+        Some(lo) => lo.format(of, precision),
+        None => format_date_time_with_precision(of, precision, options.twenty_four_hour),
+    };
+
+    let date_formatted = format(offset_date_time)?;
+
+    let local_string = if let Some(ut) = offset {
+        let local = offset_date_time.to_offset(
+            local_offset_at(offset_date_time, Some(ut), &options.settings).unwrap_or(ut),
+        );
+
+        let local_formatted = match options.settings.zone.abbreviation_at(offset_date_time) {
+            Some(st) => format!(
+                "{} {st} ({})",
+                format(local)?,
+                format_offset(local.offset())
+            ),
+            None => format!("{} {}", format(local)?, format_offset(local.offset())),
+        };
+
+        format!(" local: {}", local_formatted.purple())
+    } else {
+        String::new()
+    };
+
+    let relative = if precision == Some(Precision::Milliseconds) {
+        // Relative times are not precise enough to be useful this close to now
+        #[expect(clippy::cast_precision_loss, reason = "Unimportant")]
+        let milliseconds = duration.whole_nanoseconds() as f64 / 1_000_000_f64;
+
+        format!("{milliseconds:+.3} ms")
+    } else {
+        format_relative(formatter, duration)
+    };
+
+    Ok(format!(
+        "UTC: {}{local_string} ({})",
+        date_formatted.blue(),
+        relative.cyan(),
+    ))
+}
+
+/// E.g. "Ambiguous local time: it happens at +01:00 and again at +00:00", when the local time of `offset_date_time` is
+/// in a daylight saving time transition
+fn get_transition(offset_date_time: OffsetDateTime, settings: &Settings) -> Option<String> {
+    let zone = settings.zone;
+
+    let local = offset_date_time.to_offset(zone.offset_at(offset_date_time).ok()?);
+
+    let transition = match zone
+        .wall_clock(PrimitiveDateTime::new(local.date(), local.time()))
+        .ok()?
+    {
+        WallClock::Unique => return None,
+        WallClock::Ambiguous { earlier, later } => format!(
+            "Ambiguous local time: it happens at {} and again at {}",
+            format_offset(earlier),
+            format_offset(later)
+        ),
+        WallClock::Skipped { before, after } => format!(
+            "Skipped local time: the clocks went from {} to {} over it",
+            format_offset(before),
+            format_offset(after)
+        ),
+    };
+
+    Some(transition)
+}
+
+/// E.g. "3 business days from now" or "1 business day ago", when business days were requested, comparing the local
+/// dates of `offset_date_time` and `now`
+fn get_business_days(
+    offset_date_time: OffsetDateTime,
+    now: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    options: &Options,
+) -> Option<String> {
+    let holidays = options.business_days.as_deref()?;
+
+    let local_date = |of: OffsetDateTime| {
+        of.to_offset(local_offset_at(of, offset, &options.settings).unwrap_or(UtcOffset::UTC))
+            .date()
+    };
+
+    let business_days =
+        business_days_between(local_date(now), local_date(offset_date_time), holidays);
+
+    Some(format!(
+        "{} business day{} {}",
+        business_days.unsigned_abs(),
+        if business_days.unsigned_abs() == 1_u64 {
+            ""
+        } else {
+            "s"
+        },
+        if business_days.is_negative() {
+            "ago"
+        } else {
+            "from now"
+        }
+    ))
+}
+
+/// `offset_date_time` floored as --truncate asks, or as it is
+fn truncate(offset_date_time: OffsetDateTime, options: &Options) -> anyhow::Result<OffsetDateTime> {
+    options.truncate.map_or(Ok(offset_date_time), |tr| {
+        tr.floor(offset_date_time, options.settings.zone)
+    })
+}
+
+/// E.g. "Truncated to the start of the day (UTC): 1699920000 in seconds", when truncation was requested
+fn get_truncation(
+    offset_date_time: OffsetDateTime,
+    unit: Unit,
+    options: &Options,
+) -> Option<String> {
+    let tr = options.truncate?;
+
+    Some(format!(
+        "Truncated to the start of the {} ({}): {} in {}",
+        tr.name(),
+        options.settings.zone,
+        unit.format_from_unix_nanoseconds(offset_date_time.unix_timestamp_nanos()),
+        unit.name()
+    ))
+}
+
+/// E.g. "Daylight at 52.52,13.4 (sunrise 4:43 AM +02:00, sunset 9:33 PM +02:00)", when a location was given, with the
+/// times in the local offset
+fn get_daylight(
+    offset_date_time: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    options: &Options,
+) -> anyhow::Result<Option<String>> {
+    let Some(lo) = options.location else {
+        return Ok(None);
+    };
+
+    let ut = local_offset_at(offset_date_time, offset, &options.settings).unwrap_or(UtcOffset::UTC);
+
+    let format_time = |of: OffsetDateTime| -> anyhow::Result<String> {
+        Ok(format!(
+            "{} {}",
+            of.to_offset(ut).format(on_clock(
+                FORMAT_DESCRIPTION_TIME_OF_DAY,
+                FORMAT_DESCRIPTION_24_HOUR_TIME_OF_DAY,
+                options.twenty_four_hour,
+            ))?,
+            format_offset(ut)
+        ))
+    };
+
+    let daylight = match lo.daylight(offset_date_time) {
+        Some(Daylight::Day {
+            sunrise,
+            sunset,
+            is_daylight,
+        }) => format!(
+            "{} at {lo} (sunrise {}, sunset {})",
+            if is_daylight { "Daylight" } else { "Night" },
+            format_time(sunrise)?,
+            format_time(sunset)?
+        ),
+        Some(Daylight::PolarDay) => format!("Daylight at {lo} (the sun does not set that day)"),
+        Some(Daylight::PolarNight) => format!("Night at {lo} (the sun does not rise that day)"),
+        None => return Ok(None),
+    };
+
+    Ok(Some(daylight))
+}
+
+/// E.g. "MJD 60262.925926, JD 2460263.425926"
+fn format_julian_dates(offset_date_time: OffsetDateTime) -> String {
+    let unix_nanoseconds = offset_date_time.unix_timestamp_nanos();
+
+    format!(
+        "MJD {}, JD {}",
+        Unit::ModifiedJulianDay.format_from_unix_nanoseconds(unix_nanoseconds),
+        Unit::JulianDay.format_from_unix_nanoseconds(unix_nanoseconds)
+    )
+}
+
+/// E.g. "day 318 of 365"
+fn format_day_of_year(date: Date) -> String {
+    format!(
+        "day {} of {}",
+        date.ordinal(),
+        time::util::days_in_year(date.year())
+    )
+}
+
+/// E.g. "Q4" or, with fiscal years starting in April, "Q4, FY2024 Q3"
+fn format_quarters(date: Date, fiscal_start: Option<Month>) -> String {
+    let (_, quarter) = fiscal_quarter(date, Month::January);
+
+    match fiscal_start {
+        Some(mo) => {
+            let (fiscal_year, fiscal_quarter) = fiscal_quarter(date, mo);
+
+            format!("Q{quarter}, FY{fiscal_year} Q{fiscal_quarter}")
+        }
+        None => format!("Q{quarter}"),
+    }
+}
+
+/// The Julian Day and Modified Julian Day of `offset_date_time`, and its ISO 8601 week date, ordinal date, and quarter
+/// in UTC and (if it falls on a different date) in the local time zone, or just the week date or ordinal date if only
+/// those were requested
+fn get_calendar_info(
+    offset_date_time: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    options: &Options,
+) -> anyhow::Result<Option<String>> {
+    if !options.calendar_info && !options.week_date && !options.ordinal_date {
+        return Ok(None);
+    }
+
+    let date = offset_date_time.date();
+
+    let mut calendar_info = String::new();
+
+    if options.calendar_info {
+        let unix_nanoseconds = offset_date_time.unix_timestamp_nanos();
+
+        write!(
+            calendar_info,
+            "JD {}, MJD {}, ",
+            Unit::JulianDay.format_from_unix_nanoseconds(unix_nanoseconds),
+            Unit::ModifiedJulianDay.format_from_unix_nanoseconds(unix_nanoseconds)
+        )?;
+    }
+
+    write!(calendar_info, "UTC: {}", format_date_fields(date, options)?)?;
+
+    if let Some(ut) = offset {
+        let local_date = offset_date_time.to_offset(ut).date();
+
+        if local_date != date {
+            write!(
+                calendar_info,
+                " local: {}",
+                format_date_fields(local_date, options)?
+            )?;
+        }
+    }
+
+    Ok(Some(calendar_info))
+}
+
+/// E.g. "week date 2023-W46-2, ordinal date 2023-318 (day 318 of 365), Q4", or just the week date or ordinal date if
+/// only those were requested
+fn format_date_fields(date: Date, options: &Options) -> anyhow::Result<String> {
+    let (week_date, ordinal_date) = format_calendar_info(date)?;
+
+    let mut field_vec = Vec::<String>::new();
+
+    if options.calendar_info || options.week_date {
+        field_vec.push(format!("week date {week_date}"));
+    }
+
+    if options.calendar_info || options.ordinal_date {
+        field_vec.push(format!(
+            "ordinal date {ordinal_date} ({})",
+            format_day_of_year(date)
+        ));
+    }
+
+    if options.calendar_info {
+        field_vec.push(format_quarters(date, options.fiscal_start));
+    }
+
+    Ok(field_vec.join(", "))
+}
+
+/// `offset_date_time` as a Unix timestamp in each of the four units, if conversions were requested
+fn get_conversions(offset_date_time: OffsetDateTime, options: &Options) -> Vec<String> {
+    if !options.convert {
+        return Vec::new();
+    }
+
+    let unix_nanoseconds = offset_date_time.unix_timestamp_nanos();
+
+    [
+        Unit::Seconds,
+        Unit::Milliseconds,
+        Unit::Microseconds,
+        Unit::Nanoseconds,
+    ]
+    .map(|un| {
+        format!(
+            "{} {}",
+            un.format_from_unix_nanoseconds(unix_nanoseconds),
+            un.name()
+        )
+    })
+    .to_vec()
+}
+
+/// The TOTP time step `offset_date_time` falls in, if TOTP time steps were requested
+fn get_totp_step(offset_date_time: OffsetDateTime, options: &Options) -> Option<String> {
+    options
+        .totp_period
+        .map(|us| readtimestamp::totp::step(offset_date_time, us).to_string())
+}
+
+/// The arithmetic that turns `number` into an instant, step by step
+fn get_explanation(
+    number: Number,
+    unit: Unit,
+    offset_date_time: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    options: &Options,
+) -> anyhow::Result<Vec<String>> {
+    if unit == Unit::Dos {
+        return get_dos_explanation(number, offset_date_time, offset, options);
+    }
+
+    let nanoseconds_per_tick = unit.nanoseconds_per_tick();
+
+    let nanoseconds = number
+        .nanoseconds(unit)
+        .ok_or_else(|| anyhow::anyhow!("Number is too large"))?;
+
+    let epoch_offset_nanoseconds = unit.epoch_offset_nanoseconds();
+
+    let mut explanation = vec![
+        format!(
+            "{number} {} × {nanoseconds_per_tick} nanoseconds per {} = {nanoseconds} nanoseconds",
+            unit.name(),
+            unit.tick_name()
+        ),
+        if epoch_offset_nanoseconds == 0_i128 {
+            format!("counted from {}", unit.epoch_name())
+        } else {
+            format!(
+                "counted from {}, which is {epoch_offset_nanoseconds} nanoseconds from the Unix epoch, so {nanoseconds} + {epoch_offset_nanoseconds} = {} nanoseconds since the Unix epoch",
+                unit.epoch_name(),
+                nanoseconds + epoch_offset_nanoseconds
+            )
+        },
+    ];
+
+    if let Some(st) = unit.correction_name() {
+        let uncorrected = nanoseconds + epoch_offset_nanoseconds;
+
+        if let Some(io) = unit.unix_nanoseconds(nanoseconds) {
+            if io != uncorrected {
+                explanation.push(format!(
+                    "corrected by {} nanoseconds for {st}: {io} nanoseconds since the Unix epoch",
+                    io - uncorrected
+                ));
+            }
+        }
+    }
+
+    if let Some(ut) = offset {
+        explanation.push(format!(
+            "local offset {} applied: {} {} = {}",
+            Zone::Fixed(ut),
+            format_date_time(offset_date_time, options.twenty_four_hour)?,
+            Zone::Fixed(ut),
+            format_date_time(offset_date_time.to_offset(ut), options.twenty_four_hour)?
+        ));
+    }
+
+    Ok(explanation)
+}
+
+/// The fields of a DOS packed date and time
+fn get_dos_explanation(
+    number: Number,
+    offset_date_time: OffsetDateTime,
+    offset: Option<UtcOffset>,
+    options: &Options,
+) -> anyhow::Result<Vec<String>> {
+    let packed = u32::try_from(
+        number
+            .integer_part()
+            .ok_or_else(|| anyhow::anyhow!("Number is too large"))?,
+    )?;
+
+    let fields = readtimestamp::dos::Fields::from_packed(packed);
+
+    let mut explanation = vec![
+        format!(
+            "{number} = 0x{packed:08X}: date 0x{:04X} (year 1980 + {}, month {}, day {}), time 0x{:04X} (hour {}, minute {}, second 2 × {})",
+            fields.date,
+            fields.year,
+            fields.month,
+            fields.day,
+            fields.time,
+            fields.hour,
+            fields.minute,
+            fields.half_second
+        ),
+        "read as UTC, since the time zone is not stored".to_owned(),
+    ];
+
+    if let Some(ut) = offset {
+        explanation.push(format!(
+            "local offset {} applied: {} {} = {}",
+            Zone::Fixed(ut),
+            format_date_time(offset_date_time, options.twenty_four_hour)?,
+            Zone::Fixed(ut),
+            format_date_time(offset_date_time.to_offset(ut), options.twenty_four_hour)?
+        ));
+    }
+
+    Ok(explanation)
+}
+
+/// `twelve_hour`, unless the 24-hour clock was chosen
+const fn on_clock(
+    twelve_hour: &'static [FormatItem<'static>],
+    twenty_four_hour: &'static [FormatItem<'static>],
+    twenty_four_hour_clock: bool,
+) -> &'static [FormatItem<'static>] {
+    if twenty_four_hour_clock {
+        twenty_four_hour
+    } else {
+        twelve_hour
+    }
+}
+
+/// Only includes the fractional part of the second when it is not zero
+fn format_date_time(
+    offset_date_time: OffsetDateTime,
+    twenty_four_hour_clock: bool,
+) -> anyhow::Result<String> {
+    let format_description = if offset_date_time.nanosecond() == 0_u32 {
+        on_clock(
+            FORMAT_DESCRIPTION,
+            FORMAT_DESCRIPTION_24_HOUR,
+            twenty_four_hour_clock,
+        )
+    } else {
+        on_clock(
+            FORMAT_DESCRIPTION_SUBSECOND,
+            FORMAT_DESCRIPTION_24_HOUR_SUBSECOND,
+            twenty_four_hour_clock,
+        )
+    };
+
+    Ok(offset_date_time.format(format_description)?)
+}
+
+/// Like [`format_date_time`], but to a fixed precision if one is given
+fn format_date_time_with_precision(
+    offset_date_time: OffsetDateTime,
+    precision: Option<Precision>,
+    twenty_four_hour_clock: bool,
+) -> anyhow::Result<String> {
+    let format_description = match precision {
+        None => return format_date_time(offset_date_time, twenty_four_hour_clock),
+        Some(Precision::Minutes) => on_clock(
+            FORMAT_DESCRIPTION_MINUTE,
+            FORMAT_DESCRIPTION_24_HOUR_MINUTE,
+            twenty_four_hour_clock,
+        ),
+        Some(Precision::Seconds) => on_clock(
+            FORMAT_DESCRIPTION,
+            FORMAT_DESCRIPTION_24_HOUR,
+            twenty_four_hour_clock,
+        ),
+        Some(Precision::Milliseconds) => on_clock(
+            FORMAT_DESCRIPTION_MILLISECOND,
+            FORMAT_DESCRIPTION_24_HOUR_MILLISECOND,
+            twenty_four_hour_clock,
+        ),
+    };
+
+    Ok(offset_date_time.format(format_description)?)
+}
+
+fn get_formatter(relative_format: RelativeFormat) -> RelativeFormatter {
+    let new_formatter = || {
+        let mut fo = Formatter::with_language(relative_format.language.boxed());
+
+        fo.min_unit(relative_format.min_unit);
+        fo.num_items(relative_format.num_items);
+
+        fo
+    };
+
+    let mut formatter = new_formatter();
+
+    formatter.ago("");
+
+    RelativeFormatter {
+        formatter,
+        ago_formatter: (relative_format.language != RelativeLanguage::English).then(new_formatter),
+    }
+}
+
+/// In English, e.g. "in 3 days" or "3 days ago", and in other languages, e.g. "+3 jours" or "il y a 3 jours" (since
+/// the languages only say how to write "ago")
+fn format_relative(relative_formatter: &RelativeFormatter, duration: time::Duration) -> String {
+    let duration_unsigned_abs = duration.unsigned_abs();
+
+    let duration_is_positive = duration.is_positive();
+
+    let formatter = &relative_formatter.formatter;
+
+    if let Some(fo) = &relative_formatter.ago_formatter {
+        return if duration_is_positive {
+            format!("+{}", formatter.convert(duration_unsigned_abs))
+        } else {
+            fo.convert(duration_unsigned_abs)
+        };
+    }
+
+    format!(
+        "{}{}{}",
+        if duration_is_positive { "in " } else { "" },
+        formatter.convert(duration_unsigned_abs),
+        if duration_is_positive { "" } else { " ago" }
+    )
+}
+
+/// The length of the longest of `strs`
+const fn longest(strs: &[&str]) -> usize {
+    let mut longest = 0_usize;
+    let mut us = 0_usize;
+
+    while us < strs.len() {
+        if strs[us].len() > longest {
+            longest = strs[us].len();
+        }
+
+        us += 1_usize;
+    }
+
+    longest
+}
+
+fn pad_to_left(width: usize, input: &str) -> String {
+    format!("{}{input}", " ".repeat(width - input.len()))
 }
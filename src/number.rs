@@ -0,0 +1,218 @@
+use crate::Unit;
+use std::{fmt, str::FromStr};
+
+/// The largest exponent magnitude accepted, since beyond it every mantissa that fits in an `i128` is either too large or
+/// rounds to zero (`i128::MAX` has 39 digits)
+const MAXIMUM_EXPONENT: u32 = 39_u32;
+
+/// A decimal number, `mantissa × 10^exponent`
+///
+/// Timestamps are kept in this form so that fractional inputs like "1700000000.123" are not rounded before a unit is
+/// applied
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Number {
+    pub mantissa: i128,
+    pub exponent: i32,
+}
+
+impl Number {
+    #[must_use]
+    pub const fn from_integer(integer: i128) -> Self {
+        Self {
+            mantissa: integer,
+            exponent: 0_i32,
+        }
+    }
+
+    #[must_use]
+    pub fn has_fraction(self) -> bool {
+        self.exponent < 0_i32
+            && 10_i128
+                .checked_pow(self.exponent.unsigned_abs())
+                .is_none_or(|io| self.mantissa % io != 0_i128)
+    }
+
+    /// The number rounded toward zero, or `None` if it does not fit in an `i128`
+    #[must_use]
+    pub fn integer_part(self) -> Option<i128> {
+        if self.exponent >= 0_i32 {
+            self.mantissa
+                .checked_mul(10_i128.checked_pow(self.exponent.unsigned_abs())?)
+        } else {
+            Some(
+                10_i128
+                    .checked_pow(self.exponent.unsigned_abs())
+                    .map_or(0_i128, |io| self.mantissa / io),
+            )
+        }
+    }
+
+    /// The number of nanoseconds this number represents when interpreted in `unit` (rounded down), or `None` if that
+    /// does not fit in an `i128`
+    #[must_use]
+    pub fn nanoseconds(self, unit: Unit) -> Option<i128> {
+        let scaled = self.mantissa.checked_mul(unit.nanoseconds_per_tick())?;
+
+        let nanoseconds = if self.exponent >= 0_i32 {
+            scaled.checked_mul(10_i128.checked_pow(self.exponent.unsigned_abs())?)?
+        } else {
+            match 10_i128.checked_pow(self.exponent.unsigned_abs()) {
+                Some(io) => scaled.div_euclid(io),
+                None if scaled < 0_i128 => -1_i128,
+                None => 0_i128,
+            }
+        };
+
+        Some(nanoseconds.div_euclid(unit.tick_divisor()))
+    }
+
+    /// Nanoseconds since the Unix epoch of the instant this number represents when interpreted in `unit`, or `None` if
+    /// that does not fit in an `i128`
+    #[must_use]
+    pub fn unix_nanoseconds(self, unit: Unit) -> Option<i128> {
+        unit.unix_nanoseconds(self.nanoseconds(unit)?)
+    }
+}
+
+impl FromStr for Number {
+    type Err = anyhow::Error;
+
+    /// Parses an integer, a decimal like "-1700000000.123", or scientific notation like "1.7e12"
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let (significand, exponent_digits) = st
+            .split_once(['e', 'E'])
+            .map_or((st, None), |(st, str)| (st, Some(str)));
+
+        let (integer_digits, fraction_digits) =
+            significand.split_once('.').unwrap_or((significand, ""));
+
+        let unsigned_integer_digits = integer_digits.strip_prefix('-').unwrap_or(integer_digits);
+
+        anyhow::ensure!(
+            !unsigned_integer_digits.is_empty()
+                && unsigned_integer_digits
+                    .bytes()
+                    .all(|by| by.is_ascii_digit())
+                && fraction_digits.bytes().all(|by| by.is_ascii_digit()),
+            "\"{st}\" is not a number"
+        );
+
+        let mantissa = format!("{integer_digits}{fraction_digits}").parse::<i128>()?;
+
+        let mut exponent = -i32::try_from(fraction_digits.len())?;
+
+        if let Some(str) = exponent_digits {
+            let unsigned_exponent_digits = str.strip_prefix(['+', '-']).unwrap_or(str);
+
+            anyhow::ensure!(
+                !unsigned_exponent_digits.is_empty()
+                    && unsigned_exponent_digits
+                        .bytes()
+                        .all(|by| by.is_ascii_digit()),
+                "\"{st}\" is not a number"
+            );
+
+            exponent = exponent
+                .checked_add(str.strip_prefix('+').unwrap_or(str).parse::<i32>()?)
+                .ok_or_else(|| anyhow::anyhow!("Exponent of \"{st}\" is too large"))?;
+
+            anyhow::ensure!(
+                exponent.unsigned_abs() <= MAXIMUM_EXPONENT,
+                "Exponent of \"{st}\" is too large"
+            );
+        }
+
+        Ok(Self { mantissa, exponent })
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exponent >= 0_i32 {
+            return write!(
+                fo,
+                "{}{}",
+                self.mantissa,
+                "0".repeat(self.exponent.unsigned_abs() as usize)
+            );
+        }
+
+        let fraction_len = self.exponent.unsigned_abs() as usize;
+
+        let digits = self.mantissa.unsigned_abs().to_string();
+
+        let padded = format!("{digits:0>width$}", width = fraction_len + 1_usize);
+
+        let (integer_digits, fraction_digits) = padded.split_at(padded.len() - fraction_len);
+
+        write!(
+            fo,
+            "{}{integer_digits}.{fraction_digits}",
+            if self.mantissa < 0_i128 { "-" } else { "" }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number::Number, Unit};
+
+    #[test]
+    fn test_number() -> anyhow::Result<()> {
+        let number = "1700000000.123".parse::<Number>()?;
+
+        anyhow::ensure!(number.has_fraction());
+        anyhow::ensure!(number.integer_part() == Some(1_700_000_000_i128));
+        anyhow::ensure!(number.nanoseconds(Unit::Seconds) == Some(1_700_000_000_123_000_000_i128));
+        anyhow::ensure!(number.nanoseconds(Unit::Nanoseconds) == Some(1_700_000_000_i128));
+        anyhow::ensure!(number.to_string() == "1700000000.123");
+
+        let negative = "-0.5".parse::<Number>()?;
+
+        anyhow::ensure!(negative.nanoseconds(Unit::Seconds) == Some(-500_000_000_i128));
+        anyhow::ensure!(negative.to_string() == "-0.5");
+
+        anyhow::ensure!(!"42.000".parse::<Number>()?.has_fraction());
+        anyhow::ensure!("1.2.3".parse::<Number>().is_err());
+        anyhow::ensure!(".5".parse::<Number>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_number_scientific() -> anyhow::Result<()> {
+        let scientific = "1.7e12".parse::<Number>()?;
+
+        anyhow::ensure!(scientific.integer_part() == Some(1_700_000_000_000_i128));
+        anyhow::ensure!(scientific.to_string() == "1700000000000");
+        anyhow::ensure!("17E-1".parse::<Number>()?.to_string() == "1.7");
+        anyhow::ensure!("1.7e".parse::<Number>().is_err());
+        anyhow::ensure!("1e-70000".parse::<Number>().is_err());
+        anyhow::ensure!("1e99999".parse::<Number>().is_err());
+        anyhow::ensure!("1e39".parse::<Number>()?.integer_part().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_number_excel() -> anyhow::Result<()> {
+        // Excel serial dates, either side of the nonexistent 1900-02-29 that Excel counts
+        anyhow::ensure!(
+            "45234.5".parse::<Number>()?.unix_nanoseconds(Unit::Excel)
+                == Some(1_699_099_200_000_000_000_i128)
+        );
+        anyhow::ensure!(
+            Number::from_integer(1_i128).unix_nanoseconds(Unit::Excel)
+                == Some(-2_208_988_800_000_000_000_i128)
+        );
+        anyhow::ensure!(
+            Number::from_integer(61_i128).unix_nanoseconds(Unit::Excel)
+                == Some(-2_203_891_200_000_000_000_i128)
+        );
+        anyhow::ensure!(
+            Unit::Excel.from_unix_nanoseconds(-2_208_988_800_000_000_000_i128) == 1_i128
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+//! Formats for programs to read the candidates in, instead of the one meant for people
+
+use crate::{
+    date_time,
+    interpret::{self, Settings},
+};
+use comfy_table::{presets, Table};
+use serde::Serialize;
+use std::{io::Write, str::FromStr};
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+/// How the candidates are printed
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// Aligned and colored, for people
+    #[default]
+    Human,
+    /// Aligned columns under a header row: the unit, the instant in UTC and locally, and the relative time
+    Table,
+    /// A header row, then one row per readable candidate
+    Csv,
+    /// A sequence with a mapping per readable candidate, with the same fields as the CSV columns
+    Yaml,
+    /// A JSON object per line, with the same fields as the CSV columns (or, for lines being annotated, the timestamps
+    /// found on them)
+    Jsonl,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        match st.to_ascii_lowercase().as_str() {
+            "human" => Ok(Self::Human),
+            "table" => Ok(Self::Table),
+            "csv" => Ok(Self::Csv),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "jsonl" | "ndjson" => Ok(Self::Jsonl),
+            _ => anyhow::bail!(
+                "Unrecognized output format \"{st}\" (expected \"human\", \"table\", \"csv\", \"yaml\", or \"jsonl\")"
+            ),
+        }
+    }
+}
+
+/// A readable candidate, with its instants written as RFC 3339 (or, for years RFC 3339 cannot write, as ISO 8601 with a
+/// signed six-digit year)
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Record {
+    pub unit: String,
+    pub utc: String,
+    /// In the local offset, or in UTC if it is unknown
+    pub local: String,
+    /// Whole seconds from now, negative in the past
+    pub delta_seconds: i64,
+    /// Whether this is the best candidate
+    pub best: bool,
+}
+
+impl Record {
+    #[must_use]
+    pub fn new(
+        unit: &str,
+        instant: OffsetDateTime,
+        offset: Option<UtcOffset>,
+        delta: Duration,
+        best: bool,
+    ) -> Self {
+        Self {
+            unit: unit.to_owned(),
+            utc: date_time::format_rfc3339(instant.to_offset(UtcOffset::UTC)),
+            local: date_time::format_rfc3339(instant.to_offset(offset.unwrap_or(UtcOffset::UTC))),
+            delta_seconds: delta.whole_seconds(),
+            best,
+        }
+    }
+}
+
+/// Every readable candidate for `input` (a timestamp, or a date and time), best first, or `None` if it is neither
+#[must_use]
+pub fn interpret_records(input: &str, settings: &Settings) -> Option<Vec<Record>> {
+    let now = settings.now();
+
+    let instants = interpret::interpret_input(input, settings)?;
+
+    let record_vec = instants
+        .into_iter()
+        .enumerate()
+        .map(|(us, (st, of))| {
+            Record::new(
+                &st,
+                of,
+                settings.zone.offset_at(of).ok(),
+                of - now,
+                us == 0_usize,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Some(record_vec)
+}
+
+/// Writes `records` to `writer` as a table with a header row, with each record's relative time given by `relative`
+///
+/// # Errors
+///
+/// Returns an error if `writer` cannot be written to
+pub fn write_table<W: Write>(
+    records: &[Record],
+    relative: impl Fn(&Record) -> String,
+    mut writer: W,
+) -> anyhow::Result<()> {
+    let mut table = Table::new();
+
+    table
+        .load_preset(presets::NOTHING)
+        .set_header(["Unit", "UTC", "Local", "Relative"]);
+
+    for re in records {
+        table.add_row([
+            re.unit.clone(),
+            re.utc.clone(),
+            re.local.clone(),
+            relative(re),
+        ]);
+    }
+
+    for st in table.lines() {
+        writeln!(writer, "{}", st.trim_end())?;
+    }
+
+    Ok(())
+}
+
+/// Writes `records` to `writer` as CSV, with a header row
+///
+/// # Errors
+///
+/// Returns an error if `writer` cannot be written to
+pub fn write_csv<W: Write>(records: &[Record], writer: W) -> anyhow::Result<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+
+    for re in records {
+        csv_writer.serialize(re)?;
+    }
+
+    csv_writer.flush()?;
+
+    Ok(())
+}
+
+/// Writes `records` to `writer` as a YAML sequence
+///
+/// # Errors
+///
+/// Returns an error if `writer` cannot be written to
+pub fn write_yaml<W: Write>(records: &[Record], writer: W) -> anyhow::Result<()> {
+    serde_yaml::to_writer(writer, records)?;
+
+    Ok(())
+}
+
+/// Writes `records` to `writer` as JSON Lines, one object per record
+///
+/// # Errors
+///
+/// Returns an error if `writer` cannot be written to
+pub fn write_jsonl<W: Write>(records: &[Record], mut writer: W) -> anyhow::Result<()> {
+    for re in records {
+        serde_json::to_writer(&mut writer, re)?;
+
+        writer.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interpret::Settings,
+        output::{
+            interpret_records, write_csv, write_jsonl, write_table, write_yaml, OutputFormat,
+            Record,
+        },
+    };
+    use time::{
+        macros::{datetime, offset},
+        Duration,
+    };
+
+    /// The best candidate in +01:00, then one with a fraction of a second and no known offset
+    fn records() -> [Record; 2_usize] {
+        [
+            Record::new(
+                "seconds",
+                datetime!(2023-11-14 22:13:20 UTC),
+                Some(offset!(+1)),
+                Duration::hours(-1_i64),
+                true,
+            ),
+            Record::new(
+                "nanoseconds",
+                datetime!(1970-01-01 0:00:01.7 UTC),
+                None,
+                Duration::seconds(-946_684_798_i64),
+                false,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_write_csv() -> anyhow::Result<()> {
+        let mut by = Vec::<u8>::new();
+
+        write_csv(&records(), &mut by)?;
+
+        anyhow::ensure!(
+            String::from_utf8(by)?
+                == "unit,utc,local,delta_seconds,best
+seconds,2023-11-14T22:13:20Z,2023-11-14T23:13:20+01:00,-3600,true
+nanoseconds,1970-01-01T00:00:01.7Z,1970-01-01T00:00:01.7Z,-946684798,false
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_yaml() -> anyhow::Result<()> {
+        let mut by = Vec::<u8>::new();
+
+        write_yaml(&records()[..1_usize], &mut by)?;
+
+        anyhow::ensure!(
+            String::from_utf8(by)?
+                == "- unit: seconds
+  utc: 2023-11-14T22:13:20Z
+  local: 2023-11-14T23:13:20+01:00
+  delta_seconds: -3600
+  best: true
+"
+        );
+        anyhow::ensure!("yml".parse::<OutputFormat>()? == OutputFormat::Yaml);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_jsonl() -> anyhow::Result<()> {
+        let mut by = Vec::<u8>::new();
+
+        write_jsonl(&records(), &mut by)?;
+
+        anyhow::ensure!(
+            String::from_utf8(by)?.lines().next()
+                == Some(
+                    r#"{"unit":"seconds","utc":"2023-11-14T22:13:20Z","local":"2023-11-14T23:13:20+01:00","delta_seconds":-3600,"best":true}"#
+                )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_table() -> anyhow::Result<()> {
+        let mut by = Vec::<u8>::new();
+
+        write_table(&records(), |re| format!("{}s", re.delta_seconds), &mut by)?;
+
+        anyhow::ensure!(
+            String::from_utf8(by)?
+                == " Unit         UTC                     Local                      Relative
+ seconds      2023-11-14T22:13:20Z    2023-11-14T23:13:20+01:00  -3600s
+ nanoseconds  1970-01-01T00:00:01.7Z  1970-01-01T00:00:01.7Z     -946684798s
+"
+        );
+        anyhow::ensure!("table".parse::<OutputFormat>()? == OutputFormat::Table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_format_from_str() -> anyhow::Result<()> {
+        anyhow::ensure!("CSV".parse::<OutputFormat>()? == OutputFormat::Csv);
+        anyhow::ensure!("xml".parse::<OutputFormat>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_new() -> anyhow::Result<()> {
+        let record = Record::new(
+            "seconds",
+            datetime!(-1200-01-01 0:00 UTC),
+            Some(offset!(+1)),
+            Duration::ZERO,
+            true,
+        );
+
+        anyhow::ensure!(record.utc == "-001200-01-01T00:00:00.000000000Z");
+        anyhow::ensure!(record.local == "-001200-01-01T01:00:00.000000000+01:00");
+
+        let records = interpret_records("-100000000000", &Settings::deterministic())
+            .ok_or_else(|| anyhow::anyhow!("Not interpreted"))?;
+
+        anyhow::ensure!(records.iter().any(|re| re.utc.starts_with('-')));
+        anyhow::ensure!(records.iter().any(|re| !re.utc.starts_with('-')));
+
+        Ok(())
+    }
+}
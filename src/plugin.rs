@@ -0,0 +1,263 @@
+//! Timestamp formats defined outside of this program, for in-house systems that count from their own epoch
+//!
+//! Each `.toml` file in `$XDG_CONFIG_HOME/readtimestamp/formats` (or `~/.config/readtimestamp/formats`) defines a
+//! format named after the file:
+//!
+//! ```toml
+//! # dotnet.toml
+//! description = ".NET DateTime ticks"
+//! epoch = "0001-01-01T00:00:00Z"
+//! tick_nanoseconds = 100
+//! digits = [17, 18]
+//! ```
+
+use crate::{config, date_time::parse_date_time, interpret::PLAUSIBLE_YEARS, number::Number, Unit};
+use serde::Deserialize;
+use std::{fs, ops::RangeInclusive, path::PathBuf, sync::OnceLock};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// The longest name a format can have, so its candidates line up with the built-in units
+pub const MAXIMUM_NAME_LENGTH: usize = 12_usize;
+
+/// Formats loaded with [`add_formats`], which every number is also tried in
+static CUSTOM_FORMATS: OnceLock<Vec<Box<dyn TimestampFormat>>> = OnceLock::new();
+
+/// A way of reading a number as an instant, besides the built-in units
+pub trait TimestampFormat: Send + Sync {
+    /// The label candidates in this format are shown with
+    fn name(&self) -> &str;
+
+    /// Whether `number` looks like it is in this format, before it is decoded
+    fn detect(&self, number: Number) -> bool;
+
+    /// The instant `number` represents in this format, or `None` if it cannot be represented
+    fn decode(&self, number: Number) -> Option<OffsetDateTime>;
+
+    /// How `number` is read in this format, shown alongside its candidate
+    fn describe(&self, number: Number) -> String;
+}
+
+/// A count of fixed-length ticks since an epoch, which is what the definition files describe
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochFormat {
+    pub name: String,
+    pub description: Option<String>,
+    pub epoch: OffsetDateTime,
+    pub tick_nanoseconds: u64,
+    /// How many digits the integer part of a timestamp in this format has
+    pub digits: RangeInclusive<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Definition {
+    description: Option<String>,
+    epoch: String,
+    tick_nanoseconds: u64,
+    digits: [u32; 2_usize],
+}
+
+impl EpochFormat {
+    /// Parses the definition file of the format named `name`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `definition` is not valid TOML, or if a field is missing or out of range
+    pub fn parse(name: &str, definition: &str) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !name.is_empty() && name.len() <= MAXIMUM_NAME_LENGTH,
+            "The name \"{name}\" is not 1 to {MAXIMUM_NAME_LENGTH} bytes long"
+        );
+
+        let Definition {
+            description,
+            epoch,
+            tick_nanoseconds,
+            digits: [minimum_digits, maximum_digits],
+        } = toml::from_str::<Definition>(definition)?;
+
+        let Some((_, epoch)) = parse_date_time(&epoch) else {
+            anyhow::bail!("The epoch \"{epoch}\" is not a date and time");
+        };
+
+        anyhow::ensure!(tick_nanoseconds > 0_u64, "tick_nanoseconds is 0");
+        anyhow::ensure!(
+            (1_u32..=maximum_digits).contains(&minimum_digits),
+            "digits [{minimum_digits}, {maximum_digits}] is not a range of digit counts"
+        );
+
+        Ok(Self {
+            name: name.to_owned(),
+            description,
+            epoch,
+            tick_nanoseconds,
+            digits: minimum_digits..=maximum_digits,
+        })
+    }
+}
+
+impl TimestampFormat for EpochFormat {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detect(&self, number: Number) -> bool {
+        number.integer_part().is_some_and(|io| {
+            !io.is_negative()
+                && self
+                    .digits
+                    .contains(&io.checked_ilog10().map_or(1_u32, |us| us + 1_u32))
+        })
+    }
+
+    fn decode(&self, number: Number) -> Option<OffsetDateTime> {
+        let ticks = Number {
+            mantissa: number
+                .mantissa
+                .checked_mul(i128::from(self.tick_nanoseconds))?,
+            exponent: number.exponent,
+        };
+
+        let unix_nanoseconds = self
+            .epoch
+            .unix_timestamp_nanos()
+            .checked_add(ticks.nanoseconds(Unit::Nanoseconds)?)?;
+
+        OffsetDateTime::from_unix_timestamp_nanos(unix_nanoseconds).ok()
+    }
+
+    fn describe(&self, number: Number) -> String {
+        let epoch = self
+            .epoch
+            .format(&Rfc3339)
+            .unwrap_or_else(|_| self.epoch.to_string());
+
+        let reading = format!(
+            "{number} ticks of {} ns since {epoch}",
+            self.tick_nanoseconds
+        );
+
+        match &self.description {
+            Some(st) => format!("{st}: {reading}"),
+            None => reading,
+        }
+    }
+}
+
+/// Reads every format defined in `directory`, or in the default directory if `directory` is `None`, sorted by name
+///
+/// It is not an error for the default directory to be missing.
+///
+/// # Errors
+///
+/// Returns an error if the directory or a definition file cannot be read, or if a definition is not valid
+pub fn load_formats(directory: Option<PathBuf>) -> anyhow::Result<Vec<EpochFormat>> {
+    let directory = match directory {
+        Some(pa) => pa,
+        None => match config::config_directory().map(|pa| pa.join("formats")) {
+            Some(pa) if pa.exists() => pa,
+            _ => return Ok(Vec::new()),
+        },
+    };
+
+    let mut format_vec = Vec::<EpochFormat>::new();
+
+    let read_dir = fs::read_dir(&directory)
+        .map_err(|er| anyhow::anyhow!("Could not read \"{}\": {er}", directory.display()))?;
+
+    for re in read_dir {
+        let path = re?.path();
+
+        if path.extension().is_none_or(|os| os != "toml") {
+            continue;
+        }
+
+        let name = path
+            .file_stem()
+            .and_then(|os| os.to_str())
+            .ok_or_else(|| anyhow::anyhow!("\"{}\" is not a valid name", path.display()))?;
+
+        let string = fs::read_to_string(&path)
+            .map_err(|er| anyhow::anyhow!("Could not read \"{}\": {er}", path.display()))?;
+
+        format_vec.push(
+            EpochFormat::parse(name, &string)
+                .map_err(|er| anyhow::anyhow!("Could not parse \"{}\": {er}", path.display()))?,
+        );
+    }
+
+    format_vec.sort_by(|ep, epo| ep.name.cmp(&epo.name));
+
+    Ok(format_vec)
+}
+
+/// Adds formats to try every number in
+///
+/// # Errors
+///
+/// Returns an error if formats were already added
+pub fn add_formats(format_vec: Vec<Box<dyn TimestampFormat>>) -> anyhow::Result<()> {
+    CUSTOM_FORMATS
+        .set(format_vec)
+        .map_err(|_| anyhow::anyhow!("Timestamp formats were already added"))
+}
+
+/// Decodes `number` in each of the formats added with [`add_formats`] that detect it, keeping the readings that fall
+/// in [`PLAUSIBLE_YEARS`]
+#[must_use]
+pub fn interpret(number: Number) -> Vec<(&'static dyn TimestampFormat, OffsetDateTime)> {
+    CUSTOM_FORMATS
+        .get()
+        .map_or(&[][..], Vec::as_slice)
+        .iter()
+        .filter(|ti| ti.detect(number))
+        .filter_map(|ti| Some((ti.as_ref(), ti.decode(number)?)))
+        .filter(|(_, of)| PLAUSIBLE_YEARS.contains(&of.year()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        number::Number,
+        plugin::{EpochFormat, TimestampFormat as _},
+    };
+    use time::macros::datetime;
+
+    #[test]
+    fn test_epoch_format() -> anyhow::Result<()> {
+        let epoch_format = EpochFormat::parse(
+            "dotnet",
+            r#"
+                description = ".NET DateTime ticks"
+                epoch = "0001-01-01T00:00:00Z"
+                tick_nanoseconds = 100
+                digits = [17, 18]
+            "#,
+        )?;
+
+        let number = "638355968000000000".parse::<Number>()?;
+
+        anyhow::ensure!(epoch_format.detect(number));
+        anyhow::ensure!(epoch_format.decode(number) == Some(datetime!(2023-11-14 22:13:20 UTC)));
+        anyhow::ensure!(
+            epoch_format.describe(number)
+                == ".NET DateTime ticks: 638355968000000000 ticks of 100 ns since 0001-01-01T00:00:00Z"
+        );
+
+        anyhow::ensure!(!epoch_format.detect("1700000000".parse::<Number>()?));
+
+        anyhow::ensure!(EpochFormat::parse(
+            "dotnet",
+            "epoch = \"soon\"\ntick_nanoseconds = 100\ndigits = [17, 18]"
+        )
+        .is_err());
+        anyhow::ensure!(EpochFormat::parse(
+            "dotnet",
+            "epoch = \"0001-01-01T00:00:00Z\"\ntick_nanoseconds = 100\ndigits = [18, 17]"
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use time::Duration;
+
+/// How precisely an instant is worth displaying, given how far it is from the reference instant
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Precision {
+    /// For instants years away, where the seconds are noise
+    Minutes,
+    Seconds,
+    /// For instants within a few seconds, where the fraction of the second is what matters
+    Milliseconds,
+}
+
+impl Precision {
+    /// Instants closer than this are displayed to the millisecond
+    pub const MILLISECONDS_WITHIN: Duration = Duration::seconds(10_i64);
+    /// Instants at least this far away are displayed to the minute
+    pub const MINUTES_FROM: Duration = Duration::days(365_i64);
+
+    /// The precision for an instant `delta` away from the reference instant
+    #[must_use]
+    pub fn for_delta(delta: Duration) -> Self {
+        let distance = delta.abs();
+
+        if distance < Self::MILLISECONDS_WITHIN {
+            Self::Milliseconds
+        } else if distance >= Self::MINUTES_FROM {
+            Self::Minutes
+        } else {
+            Self::Seconds
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precision::Precision;
+    use time::Duration;
+
+    #[test]
+    fn test_for_delta() -> anyhow::Result<()> {
+        anyhow::ensure!(
+            Precision::for_delta(Duration::milliseconds(-1_500_i64)) == Precision::Milliseconds
+        );
+        anyhow::ensure!(Precision::for_delta(Duration::hours(3_i64)) == Precision::Seconds);
+        anyhow::ensure!(Precision::for_delta(Duration::days(-400_i64)) == Precision::Minutes);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+//! The units that well-known products store timestamps in, so they do not have to be remembered
+
+use crate::{count_digits, number::Number, Unit};
+use std::str::FromStr;
+
+/// Every preset, by name
+pub const PRESETS: [Preset; 13_usize] = [
+    Preset::new(
+        "ad",
+        "Active Directory attributes like pwdLastSet",
+        Unit::Filetime,
+        18_usize,
+    ),
+    Preset::new(
+        "cassandra",
+        "Cassandra write times",
+        Unit::Microseconds,
+        16_usize,
+    ),
+    Preset::new(
+        "chrome",
+        "Chrome history and cookie times",
+        Unit::Webkit,
+        17_usize,
+    ),
+    Preset::new("excel", "Excel serial dates", Unit::Excel, 5_usize),
+    Preset::new(
+        "firefox",
+        "Firefox history times",
+        Unit::Microseconds,
+        16_usize,
+    ),
+    Preset::new("go", "Go UnixNano values", Unit::Nanoseconds, 19_usize),
+    Preset::new(
+        "influxdb",
+        "InfluxDB timestamps",
+        Unit::Nanoseconds,
+        19_usize,
+    ),
+    Preset::new("ios", "iOS and macOS Core Data dates", Unit::Cocoa, 9_usize),
+    Preset::new(
+        "java",
+        "Java currentTimeMillis values",
+        Unit::Milliseconds,
+        13_usize,
+    ),
+    Preset::new(
+        "javascript",
+        "JavaScript Date.now values",
+        Unit::Milliseconds,
+        13_usize,
+    ),
+    Preset::new(
+        "kafka",
+        "Kafka record timestamps",
+        Unit::Milliseconds,
+        13_usize,
+    ),
+    Preset::new("mongo", "MongoDB dates", Unit::Milliseconds, 13_usize),
+    Preset::new("python", "Python time.time values", Unit::Seconds, 10_usize),
+];
+
+/// How a product stores timestamps
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Preset {
+    pub name: &'static str,
+    /// What the product's timestamps are, in the plural
+    pub description: &'static str,
+    pub unit: Unit,
+    /// How many digits the integer part of a timestamp from this century has
+    pub digits: usize,
+}
+
+impl Preset {
+    const fn new(name: &'static str, description: &'static str, unit: Unit, digits: usize) -> Self {
+        Self {
+            name,
+            description,
+            unit,
+            digits,
+        }
+    }
+
+    /// Whether the integer part of `number` has as many digits as this product's timestamps usually do
+    #[must_use]
+    pub fn has_expected_digits(&self, number: Number) -> bool {
+        number
+            .integer_part()
+            .is_some_and(|io| count_digits(io) == self.digits)
+    }
+}
+
+impl FromStr for Preset {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let lowercase = st.to_ascii_lowercase();
+
+        PRESETS
+            .into_iter()
+            .find(|pr| pr.name == lowercase)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unrecognized preset \"{st}\" (expected one of {})",
+                    PRESETS.map(|pr| format!("\"{}\"", pr.name)).join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number::Number, preset::Preset, Unit};
+
+    #[test]
+    fn test_preset() -> anyhow::Result<()> {
+        let chrome = "Chrome".parse::<Preset>()?;
+
+        anyhow::ensure!(chrome.unit == Unit::Webkit);
+        anyhow::ensure!(chrome.has_expected_digits("13346078800000000".parse::<Number>()?));
+        anyhow::ensure!(!chrome.has_expected_digits("1700000000".parse::<Number>()?));
+
+        anyhow::ensure!("kafka".parse::<Preset>()?.unit == Unit::Milliseconds);
+        anyhow::ensure!("oracle".parse::<Preset>().is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+//! Python bindings for the interpretation engine, so notebooks get the same unit detection as the command line program
+//!
+//! Built with `maturin build --release` (see pyproject.toml), which makes an importable `readtimestamp` module.
+
+use crate::{interpret::Settings, output, Zone};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+
+/// Every readable candidate for `input` (a timestamp, or a date and time), best first, as a dict from each unit to a
+/// dict with the other fields of `--output csv`
+///
+/// `zone` is "UTC", "local" (used if it is missing), a name like "Europe/Berlin", or an offset like "+05:30". Raises
+/// `ValueError` if `zone` is not recognized, or if `input` is neither a timestamp nor a date and time.
+#[pyfunction]
+#[pyo3(signature = (input, zone = None))]
+fn interpret<'py>(
+    py: Python<'py>,
+    input: &str,
+    zone: Option<&str>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let zone = zone
+        .map_or(Ok(Zone::Local), str::parse::<Zone>)
+        .map_err(|er| PyValueError::new_err(er.to_string()))?;
+
+    let records = output::interpret_records(input.trim(), &Settings { now: None, zone })
+        .ok_or_else(|| {
+            PyValueError::new_err(format!("\"{input}\" is not a timestamp or a date and time"))
+        })?;
+
+    let candidates = PyDict::new(py);
+
+    for re in records {
+        let record = PyDict::new(py);
+
+        record.set_item("utc", re.utc)?;
+        record.set_item("local", re.local)?;
+        record.set_item("delta_seconds", re.delta_seconds)?;
+        record.set_item("best", re.best)?;
+
+        candidates.set_item(re.unit, record)?;
+    }
+
+    Ok(candidates)
+}
+
+#[pymodule]
+fn readtimestamp(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(interpret, module)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+//! A Rhai script that turns the input into what is parsed, for log lines whose structure the built-in extraction
+//! cannot make sense of
+//!
+//! The script sees the input as `input`, and its last expression is what is parsed instead: a string or a number, an
+//! array of them (each parsed in turn), or nothing (`()`) to parse the input as it is.
+//!
+//! ```rhai
+//! // "evt|v2|0x6553f100|ok" has the seconds in hexadecimal in its third field
+//! let fields = input.split("|");
+//!
+//! if fields.len() > 2 { parse_int(fields[2].sub_string(2), 16) }
+//! ```
+
+use rhai::{Dynamic, Engine, Scope, AST};
+use std::{fs, path::Path, sync::OnceLock};
+
+/// The script given on the command line, which every input is run through
+static SCRIPT: OnceLock<Script> = OnceLock::new();
+
+/// A compiled script
+pub struct Script {
+    engine: Engine,
+    ast: AST,
+}
+
+impl Script {
+    /// Compiles `source`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` is not valid Rhai
+    pub fn compile(source: &str) -> anyhow::Result<Self> {
+        let engine = Engine::new();
+
+        let ast = engine
+            .compile(source)
+            .map_err(|er| anyhow::anyhow!("Could not compile the script: {er}"))?;
+
+        Ok(Self { engine, ast })
+    }
+
+    /// Reads and compiles the script at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not valid Rhai
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let source = fs::read_to_string(path)
+            .map_err(|er| anyhow::anyhow!("Could not read \"{}\": {er}", path.display()))?;
+
+        Self::compile(&source)
+    }
+
+    /// Runs the script on `input`, returning what to parse instead
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the script fails, or if it returns something other than a string, a number, an array of
+    /// them, or nothing
+    pub fn extract(&self, input: &str) -> anyhow::Result<Vec<String>> {
+        let mut scope = Scope::new();
+
+        scope.push("input", input.to_owned());
+
+        let dynamic = self
+            .engine
+            .eval_ast_with_scope::<Dynamic>(&mut scope, &self.ast)
+            .map_err(|er| anyhow::anyhow!("The script failed: {er}"))?;
+
+        if dynamic.is_unit() {
+            return Ok(vec![input.to_owned()]);
+        }
+
+        if dynamic.is_array() {
+            return dynamic
+                .into_array()
+                .map_err(|st| anyhow::anyhow!("Expected an array, not {st}"))?
+                .iter()
+                .map(to_input)
+                .collect();
+        }
+
+        Ok(vec![to_input(&dynamic)?])
+    }
+}
+
+/// Makes every input be run through `script` before it is parsed
+///
+/// # Errors
+///
+/// Returns an error if a script was already set
+pub fn set(script: Script) -> anyhow::Result<()> {
+    SCRIPT
+        .set(script)
+        .map_err(|_| anyhow::anyhow!("A script was already set"))
+}
+
+/// What to parse instead of `input`, according to the script set with [`set`] (just `input` if none was set)
+///
+/// # Errors
+///
+/// Returns an error if the script fails or returns something that cannot be parsed
+pub fn extract(input: &str) -> anyhow::Result<Vec<String>> {
+    match SCRIPT.get() {
+        Some(sc) => sc.extract(input),
+        None => Ok(vec![input.to_owned()]),
+    }
+}
+
+fn to_input(dynamic: &Dynamic) -> anyhow::Result<String> {
+    if dynamic.is_string() || dynamic.is_int() || dynamic.is_float() {
+        Ok(dynamic.to_string())
+    } else {
+        anyhow::bail!(
+            "The script returned {} \"{dynamic}\" (expected a string, a number, an array of them, or nothing)",
+            dynamic.type_name()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::script::Script;
+
+    #[test]
+    fn test_extract() -> anyhow::Result<()> {
+        let script = Script::compile(
+            r#"
+                let fields = input.split("|");
+
+                if fields.len() > 2 { parse_int(fields[2].sub_string(2), 16) }
+            "#,
+        )?;
+
+        anyhow::ensure!(script.extract("evt|v2|0x6553f100|ok")? == ["1700000000"]);
+        anyhow::ensure!(script.extract("no fields")? == ["no fields"]);
+
+        let script = Script::compile("[input, 1700000000, 1.5]")?;
+
+        anyhow::ensure!(script.extract("a")? == ["a", "1700000000", "1.5"]);
+
+        anyhow::ensure!(Script::compile("true")?.extract("a").is_err());
+        anyhow::ensure!(Script::compile("let").is_err());
+
+        Ok(())
+    }
+}
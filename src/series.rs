@@ -0,0 +1,71 @@
+//! Evenly spaced instants between two others, e.g. for generating the timestamps of a load test
+
+use time::{Duration, OffsetDateTime};
+
+/// `start`, then every `step` after it up to and including `end`
+///
+/// # Errors
+///
+/// Returns an error if `step` is not positive, or if `end` is before `start`
+pub fn series(
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    step: Duration,
+) -> anyhow::Result<impl Iterator<Item = OffsetDateTime>> {
+    anyhow::ensure!(step.is_positive(), "The step {step} is not positive");
+    anyhow::ensure!(start <= end, "The end is before the start");
+
+    Ok(std::iter::successors(Some(start), move |of| {
+        of.checked_add(step).filter(|off| *off <= end)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::series::series;
+    use time::{macros::datetime, Duration};
+
+    #[test]
+    fn test_series() -> anyhow::Result<()> {
+        let instant_vec = series(
+            datetime!(2024-05-01 0:00 UTC),
+            datetime!(2024-05-01 2:30 UTC),
+            Duration::HOUR,
+        )?
+        .collect::<Vec<_>>();
+
+        anyhow::ensure!(
+            instant_vec
+                == [
+                    datetime!(2024-05-01 0:00 UTC),
+                    datetime!(2024-05-01 1:00 UTC),
+                    datetime!(2024-05-01 2:00 UTC),
+                ]
+        );
+
+        anyhow::ensure!(
+            series(
+                datetime!(2024-05-01 0:00 UTC),
+                datetime!(2024-05-01 1:00 UTC),
+                Duration::minutes(30_i64)
+            )?
+            .last()
+                == Some(datetime!(2024-05-01 1:00 UTC))
+        );
+
+        anyhow::ensure!(series(
+            datetime!(2024-05-01 0:00 UTC),
+            datetime!(2024-05-01 1:00 UTC),
+            Duration::ZERO
+        )
+        .is_err());
+        anyhow::ensure!(series(
+            datetime!(2024-05-01 1:00 UTC),
+            datetime!(2024-05-01 0:00 UTC),
+            Duration::HOUR
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
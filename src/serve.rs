@@ -0,0 +1,189 @@
+//! A tiny JSON API over HTTP, for interpreting timestamps without the binary installed
+//!
+//! `GET /parse?ts=1700000000` returns every readable candidate, best first, with the same fields as `--output csv`.
+
+use crate::{interpret::Settings, output};
+use serde_json::json;
+use tiny_http::{Header, Response, Server};
+
+/// Answers `url` (the path and query of a request), returning the status code and the JSON body
+///
+/// # Errors
+///
+/// Returns an error if the request cannot be answered
+pub fn respond(url: &str, settings: &Settings) -> anyhow::Result<(u16, String)> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+
+    if path != "/parse" {
+        return Ok((404_u16, json!({ "error": "Not found" }).to_string()));
+    }
+
+    let Some(ts) = query
+        .split('&')
+        .filter_map(|st| st.split_once('='))
+        .find(|(ke, _)| *ke == "ts")
+        .and_then(|(_, va)| percent_decode(va))
+    else {
+        return Ok((
+            400_u16,
+            json!({ "error": "The \"ts\" query parameter is missing" }).to_string(),
+        ));
+    };
+
+    let Some(records) = output::interpret_records(&ts, settings) else {
+        return Ok((
+            400_u16,
+            json!({ "error": format!("\"{ts}\" is not a timestamp or a date and time") })
+                .to_string(),
+        ));
+    };
+
+    Ok((
+        200_u16,
+        json!({ "input": ts, "candidates": records }).to_string(),
+    ))
+}
+
+/// Decodes "%XX" escapes and "+" (a space), or returns `None` if the result is not UTF-8
+fn percent_decode(input: &str) -> Option<String> {
+    let by = input.as_bytes();
+
+    let mut decoded = Vec::<u8>::with_capacity(by.len());
+
+    let mut us = 0_usize;
+
+    while us < by.len() {
+        match by[us] {
+            b'%' => {
+                let hex = input.get(us + 1_usize..us + 3_usize)?;
+
+                decoded.push(u8::from_str_radix(hex, 16_u32).ok()?);
+
+                us += 3_usize;
+            }
+            b'+' => {
+                decoded.push(b' ');
+
+                us += 1_usize;
+            }
+            bu => {
+                decoded.push(bu);
+
+                us += 1_usize;
+            }
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+/// Serves requests on `address` (e.g. "127.0.0.1:8080") until the process is stopped
+///
+/// # Errors
+///
+/// Returns an error if `address` cannot be listened on
+pub fn serve(address: &str, settings: Settings) -> anyhow::Result<()> {
+    let server = Server::http(address)
+        .map_err(|er| anyhow::anyhow!("Could not listen on {address}: {er}"))?;
+
+    serve_requests(&server, &settings)
+}
+
+/// Answers the requests `server` receives until it is closed, answering a request that cannot be answered with a
+/// status of 500 instead of stopping
+fn serve_requests(server: &Server, settings: &Settings) -> anyhow::Result<()> {
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .map_err(|()| anyhow::anyhow!("Invalid header"))?;
+
+    for re in server.incoming_requests() {
+        let (status, body) = respond(re.url(), settings).unwrap_or_else(|er| {
+            tracing::warn!(url = re.url(), error = %er, "Could not answer");
+
+            (500_u16, json!({ "error": er.to_string() }).to_string())
+        });
+
+        tracing::info!(url = re.url(), status);
+
+        // The client may have gone away, which is not a reason to stop serving others
+        if let Err(er) = re.respond(
+            Response::from_string(body)
+                .with_status_code(status)
+                .with_header(content_type.clone()),
+        ) {
+            tracing::warn!(error = %er, "Could not respond");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interpret::Settings,
+        serve::{respond, serve_requests},
+    };
+    use std::{
+        io::{Read as _, Write as _},
+        net::TcpStream,
+        thread,
+    };
+    use tiny_http::Server;
+
+    #[test]
+    fn test_respond() -> anyhow::Result<()> {
+        let settings = Settings::deterministic();
+
+        let (status, body) = respond("/parse?ts=1700000000", &settings)?;
+
+        anyhow::ensure!(status == 200_u16);
+        anyhow::ensure!(body.starts_with(
+            r#"{"input":"1700000000","candidates":[{"unit":"seconds","utc":"2023-11-14T22:13:20Z","local":"2023-11-14T22:13:20Z","delta_seconds":753315200,"best":true},"#
+        ));
+
+        let (status, body) = respond("/parse?ts=2023-11-14T23%3A13%3A20%2B01%3A00", &settings)?;
+
+        anyhow::ensure!(status == 200_u16);
+        anyhow::ensure!(body.contains(r#""utc":"2023-11-14T22:13:20Z""#));
+
+        anyhow::ensure!(respond("/parse?ts=soon", &settings)?.0 == 400_u16);
+        anyhow::ensure!(respond("/parse", &settings)?.0 == 400_u16);
+        anyhow::ensure!(respond("/", &settings)?.0 == 404_u16);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_serve_requests() -> anyhow::Result<()> {
+        let server = Server::http("127.0.0.1:0").map_err(|er| anyhow::anyhow!("{er}"))?;
+
+        let address = server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| anyhow::anyhow!("Not listening on an IP address"))?;
+
+        // Runs until the test process exits
+        thread::spawn(move || serve_requests(&server, &Settings::deterministic()));
+
+        let get = |path: &str| -> anyhow::Result<String> {
+            let mut tcp_stream = TcpStream::connect(address)?;
+
+            write!(
+                tcp_stream,
+                "GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"
+            )?;
+
+            let mut response = String::new();
+
+            tcp_stream.read_to_string(&mut response)?;
+
+            Ok(response)
+        };
+
+        // Instants in years before 0 are answered like any other, and did not stop the server
+        anyhow::ensure!(get("/parse?ts=-100000000000")?.starts_with("HTTP/1.1 200"));
+        anyhow::ensure!(get("/parse?ts=1700000000")?.starts_with("HTTP/1.1 200"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+//! Snowflake IDs, which pack a millisecond timestamp, a worker ID, and a sequence number into a 64-bit integer
+
+use crate::{date_time::parse_date_time, interpret::PLAUSIBLE_YEARS};
+use std::{str::FromStr, sync::OnceLock};
+use time::{Duration, OffsetDateTime};
+
+/// The snowflake variants every integer is tried as
+pub const KNOWN_LAYOUTS: [(&str, Layout); 2_usize] =
+    [("Twitter", Layout::TWITTER), ("Discord", Layout::DISCORD)];
+
+/// Layouts given on the command line or in the configuration file, which are tried after the known ones
+static CUSTOM_LAYOUTS: OnceLock<Vec<(String, Layout)>> = OnceLock::new();
+
+/// How far after its epoch a snowflake has to be to be worth showing, since smaller numbers are far more likely to be
+/// plain timestamps
+const MINIMUM_AGE: Duration = Duration::days(365_i64);
+
+/// How the bits of a snowflake ID are laid out, from the most significant: the timestamp, the worker ID, then the
+/// sequence number
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Layout {
+    /// The Unix time in milliseconds the timestamp counts from
+    pub epoch_milliseconds: i64,
+    pub timestamp_bits: u32,
+    pub worker_bits: u32,
+    pub sequence_bits: u32,
+}
+
+/// The widths of a layout's fields, written as "TIMESTAMP,WORKER,SEQUENCE" (e.g. "41,10,12")
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bits {
+    pub timestamp: u32,
+    pub worker: u32,
+    pub sequence: u32,
+}
+
+/// The parts of a decoded snowflake ID
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Snowflake {
+    pub instant: OffsetDateTime,
+    /// Milliseconds since the layout's epoch
+    pub milliseconds: u64,
+    pub worker: u64,
+    pub sequence: u64,
+}
+
+impl Default for Bits {
+    /// Twitter's widths, which most other variants copied
+    fn default() -> Self {
+        Self {
+            timestamp: Layout::TWITTER.timestamp_bits,
+            worker: Layout::TWITTER.worker_bits,
+            sequence: Layout::TWITTER.sequence_bits,
+        }
+    }
+}
+
+impl FromStr for Bits {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let bits_vec = st
+            .split(',')
+            .map(|str| {
+                str.trim().parse::<u32>().map_err(|er| {
+                    anyhow::anyhow!("Could not parse \"{str}\" as a number of bits: {er}")
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let [timestamp, worker, sequence] = bits_vec[..] else {
+            anyhow::bail!("Expected the timestamp, worker, and sequence widths (e.g. \"41,10,12\"), got \"{st}\"");
+        };
+
+        anyhow::ensure!(
+            timestamp > 0_u32,
+            "The timestamp has to have at least one bit"
+        );
+        anyhow::ensure!(
+            u64::from(timestamp) + u64::from(worker) + u64::from(sequence) <= u64::from(u64::BITS),
+            "The widths add up to more than {} bits",
+            u64::BITS
+        );
+
+        Ok(Self {
+            timestamp,
+            worker,
+            sequence,
+        })
+    }
+}
+
+impl Layout {
+    /// Twitter's layout, counting from 2010-11-04 01:42:54.657 UTC
+    pub const TWITTER: Self = Self {
+        epoch_milliseconds: 1_288_834_974_657_i64,
+        timestamp_bits: 41_u32,
+        worker_bits: 10_u32,
+        sequence_bits: 12_u32,
+    };
+
+    /// Discord's layout, counting from 2015-01-01 00:00:00 UTC, with all 64 bits in use (the worker ID is the internal
+    /// worker ID and the process ID together)
+    pub const DISCORD: Self = Self {
+        epoch_milliseconds: 1_420_070_400_000_i64,
+        timestamp_bits: 42_u32,
+        worker_bits: 10_u32,
+        sequence_bits: 12_u32,
+    };
+
+    #[must_use]
+    pub const fn new(epoch_milliseconds: i64, bits: Bits) -> Self {
+        Self {
+            epoch_milliseconds,
+            timestamp_bits: bits.timestamp,
+            worker_bits: bits.worker,
+            sequence_bits: bits.sequence,
+        }
+    }
+
+    /// The first ID that could be generated at `instant` (0 before the epoch), or `None` if it does not fit in an
+    /// `i128`
+    #[must_use]
+    pub fn first_id_at(&self, instant: OffsetDateTime) -> Option<i128> {
+        let milliseconds = (instant.unix_timestamp_nanos() / 1_000_000_i128)
+            .checked_sub(i128::from(self.epoch_milliseconds))?
+            .max(0_i128);
+
+        milliseconds.checked_mul(1_i128.checked_shl(self.worker_bits + self.sequence_bits)?)
+    }
+
+    /// Splits `id` into its parts, or returns `None` if it is negative or does not fit in the layout's bits
+    #[must_use]
+    pub fn decode(&self, id: i128) -> Option<Snowflake> {
+        let id = u64::try_from(id).ok()?;
+
+        let total_bits = self.timestamp_bits + self.worker_bits + self.sequence_bits;
+
+        if id.checked_shr(total_bits).unwrap_or_default() != 0_u64 {
+            return None;
+        }
+
+        let sequence = id & mask(self.sequence_bits);
+        let worker =
+            id.checked_shr(self.sequence_bits).unwrap_or_default() & mask(self.worker_bits);
+        let milliseconds = id
+            .checked_shr(self.worker_bits + self.sequence_bits)
+            .unwrap_or_default();
+
+        let unix_milliseconds = i128::from(milliseconds) + i128::from(self.epoch_milliseconds);
+
+        let instant =
+            OffsetDateTime::from_unix_timestamp_nanos(unix_milliseconds * 1_000_000_i128).ok()?;
+
+        Some(Snowflake {
+            instant,
+            milliseconds,
+            worker,
+            sequence,
+        })
+    }
+}
+
+impl Snowflake {
+    /// Whether the instant falls in [`PLAUSIBLE_YEARS`] and is at least a year after the epoch
+    #[must_use]
+    pub fn is_plausible(&self) -> bool {
+        PLAUSIBLE_YEARS.contains(&self.instant.year())
+            && Duration::milliseconds(i64::try_from(self.milliseconds).unwrap_or(i64::MAX))
+                >= MINIMUM_AGE
+    }
+}
+
+/// Adds layouts (each with the name it is labeled with) to try after the [`KNOWN_LAYOUTS`]
+///
+/// # Errors
+///
+/// Returns an error if layouts were already added
+pub fn add_layouts(layout_vec: Vec<(String, Layout)>) -> anyhow::Result<()> {
+    CUSTOM_LAYOUTS
+        .set(layout_vec)
+        .map_err(|_| anyhow::anyhow!("Snowflake layouts were already added"))
+}
+
+/// Parses an epoch given as Unix time in milliseconds (e.g. "1288834974657"), or as a date and time (e.g.
+/// "2020-01-01T00:00:00Z")
+///
+/// # Errors
+///
+/// Returns an error if `input` is neither, or if the instant is too far from the Unix epoch
+pub fn parse_epoch(input: &str) -> anyhow::Result<i64> {
+    if let Ok(io) = input.parse::<i64>() {
+        return Ok(io);
+    }
+
+    let Some((_, of)) = parse_date_time(input) else {
+        anyhow::bail!("\"{input}\" is neither Unix time in milliseconds nor a date and time");
+    };
+
+    Ok(i64::try_from(of.unix_timestamp_nanos() / 1_000_000_i128)?)
+}
+
+/// Decodes `id` in each of the [`KNOWN_LAYOUTS`] and the layouts added with [`add_layouts`], keeping the plausible
+/// readings
+#[must_use]
+pub fn interpret(id: i128) -> Vec<(&'static str, Layout, Snowflake)> {
+    let custom_layouts = CUSTOM_LAYOUTS.get().map_or(&[][..], Vec::as_slice);
+
+    KNOWN_LAYOUTS
+        .into_iter()
+        .chain(custom_layouts.iter().map(|(st, la)| (st.as_str(), *la)))
+        .filter_map(|(st, la)| Some((st, la, la.decode(id)?)))
+        .filter(|(_, _, sn)| sn.is_plausible())
+        .collect()
+}
+
+/// The lowest `bits` bits set
+fn mask(bits: u32) -> u64 {
+    1_u64.checked_shl(bits).map_or(u64::MAX, |io| io - 1_u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::snowflake::{interpret, parse_epoch, Bits, Layout};
+    use time::macros::datetime;
+
+    #[test]
+    fn test_snowflake() -> anyhow::Result<()> {
+        let snowflake = Layout::TWITTER
+            .decode(1_212_092_628_029_698_048_i128)
+            .ok_or_else(|| anyhow::anyhow!("Could not decode"))?;
+
+        anyhow::ensure!(snowflake.instant == datetime!(2019-12-31 19:26:16.771 UTC));
+        anyhow::ensure!(snowflake.worker == 327_u64);
+        anyhow::ensure!(snowflake.sequence == 0_u64);
+
+        let discord_snowflake = Layout::DISCORD
+            .decode(175_928_847_299_117_063_i128)
+            .ok_or_else(|| anyhow::anyhow!("Could not decode"))?;
+
+        anyhow::ensure!(discord_snowflake.instant == datetime!(2016-04-30 11:18:25.796 UTC));
+        anyhow::ensure!(discord_snowflake.worker == 32_u64);
+        anyhow::ensure!(discord_snowflake.sequence == 7_u64);
+
+        // Plausible in both layouts
+        anyhow::ensure!(
+            interpret(175_928_847_299_117_063_i128)
+                .into_iter()
+                .map(|(st, _, _)| st)
+                .collect::<Vec<_>>()
+                == ["Twitter", "Discord"]
+        );
+        // More than 63 bits, which only fits Discord's layout
+        anyhow::ensure!(Layout::DISCORD.decode(i128::from(u64::MAX)).is_some());
+
+        // A Unix timestamp in milliseconds, which would be a few minutes after the epoch
+        anyhow::ensure!(interpret(1_700_000_000_000_i128).is_empty());
+
+        anyhow::ensure!(Layout::TWITTER.decode(-1_i128).is_none());
+        anyhow::ensure!(Layout::TWITTER
+            .decode(i128::from(i64::MAX) + 1_i128)
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_layout() -> anyhow::Result<()> {
+        anyhow::ensure!(Layout::new(1_288_834_974_657_i64, Bits::default()) == Layout::TWITTER);
+        anyhow::ensure!(
+            Layout::new(1_420_070_400_000_i64, "42, 10, 12".parse::<Bits>()?) == Layout::DISCORD
+        );
+
+        anyhow::ensure!("41,10".parse::<Bits>().is_err());
+        anyhow::ensure!("43,10,12".parse::<Bits>().is_err());
+        anyhow::ensure!("0,10,12".parse::<Bits>().is_err());
+        anyhow::ensure!("4294967295,1,0".parse::<Bits>().is_err());
+
+        anyhow::ensure!(parse_epoch("1420070400000")? == 1_420_070_400_000_i64);
+        anyhow::ensure!(parse_epoch("2015-01-01T00:00:00Z")? == 1_420_070_400_000_i64);
+        anyhow::ensure!(parse_epoch("tomorrow").is_err());
+
+        // 2021-01-01 00:00:00 UTC plus 3 years, with a 40-bit timestamp, 8-bit worker ID, and 15-bit sequence number
+        let layout = Layout::new(1_609_459_200_000_i64, "40,8,15".parse::<Bits>()?);
+
+        let snowflake = layout
+            .decode((94_608_000_000_i128 << 23_u32) | (5_i128 << 15_u32) | 9_i128)
+            .ok_or_else(|| anyhow::anyhow!("Could not decode"))?;
+
+        anyhow::ensure!(snowflake.instant == datetime!(2024-01-01 0:00 UTC));
+        anyhow::ensure!(snowflake.worker == 5_u64);
+        anyhow::ensure!(snowflake.sequence == 9_u64);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+//! Sunrise and sunset, for telling whether an instant was in daylight at a place (e.g. to check a camera's or a door
+//! sensor's log against what it could have seen)
+//!
+//! This is the sunrise equation with the corrections for the equation of center and the obliquity of the ecliptic,
+//! which is good to a minute or two away from the poles.
+
+use std::{f64::consts::PI, fmt, str::FromStr};
+use time::OffsetDateTime;
+
+/// The Julian Day of 2000-01-01T12:00:00Z, which the equation counts from
+const J2000: f64 = 2_451_545_f64;
+/// The Julian Day of the Unix epoch
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5_f64;
+/// How far below the horizon the center of the sun is at sunrise and sunset, because of refraction and the size of
+/// its disk
+const SUNRISE_ELEVATION_DEGREES: f64 = -0.833_f64;
+/// The tilt of the Earth's axis
+const OBLIQUITY_DEGREES: f64 = 23.4397_f64;
+const SECONDS_PER_DAY: f64 = 86_400_f64;
+
+/// A place on Earth, in degrees (north and east are positive)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Location {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Where the sun is during the solar day around an instant
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Daylight {
+    /// The sun rises and sets that day
+    Day {
+        sunrise: OffsetDateTime,
+        sunset: OffsetDateTime,
+        /// Whether the instant is between sunrise and sunset
+        is_daylight: bool,
+    },
+    /// The sun does not set that day (the midnight sun)
+    PolarDay,
+    /// The sun does not rise that day
+    PolarNight,
+}
+
+impl Location {
+    /// The sunrise and sunset of the solar day (from solar midnight to solar midnight) that `instant` is in here, and
+    /// whether `instant` is between them
+    #[must_use]
+    pub fn daylight(self, instant: OffsetDateTime) -> Option<Daylight> {
+        #[expect(clippy::cast_precision_loss, reason = "Unimportant")]
+        let julian_day = instant.unix_timestamp() as f64 / SECONDS_PER_DAY + UNIX_EPOCH_JULIAN_DAY;
+
+        // The solar noon nearest the instant
+        let mean_solar_noon =
+            (julian_day - J2000 + self.longitude / 360_f64).round() - self.longitude / 360_f64;
+
+        let mean_anomaly = (357.5291_f64 + 0.985_600_28_f64 * mean_solar_noon).rem_euclid(360_f64);
+
+        let equation_of_center = 1.9148_f64 * sin_degrees(mean_anomaly)
+            + 0.02_f64 * sin_degrees(2_f64 * mean_anomaly)
+            + 0.0003_f64 * sin_degrees(3_f64 * mean_anomaly);
+
+        let ecliptic_longitude =
+            (mean_anomaly + equation_of_center + 180_f64 + 102.9372_f64).rem_euclid(360_f64);
+
+        let solar_transit = J2000 + mean_solar_noon + 0.0053_f64 * sin_degrees(mean_anomaly)
+            - 0.0069_f64 * sin_degrees(2_f64 * ecliptic_longitude);
+
+        let declination_sine = sin_degrees(ecliptic_longitude) * sin_degrees(OBLIQUITY_DEGREES);
+        let declination_cosine = declination_sine.asin().cos();
+
+        let hour_angle_cosine = (sin_degrees(SUNRISE_ELEVATION_DEGREES)
+            - sin_degrees(self.latitude) * declination_sine)
+            / (cos_degrees(self.latitude) * declination_cosine);
+
+        if hour_angle_cosine < -1_f64 {
+            return Some(Daylight::PolarDay);
+        }
+
+        if hour_angle_cosine > 1_f64 {
+            return Some(Daylight::PolarNight);
+        }
+
+        let half_day = hour_angle_cosine.acos().to_degrees() / 360_f64;
+
+        let sunrise = from_julian_day(solar_transit - half_day)?;
+        let sunset = from_julian_day(solar_transit + half_day)?;
+
+        Some(Daylight::Day {
+            sunrise,
+            sunset,
+            is_daylight: (sunrise..sunset).contains(&instant),
+        })
+    }
+}
+
+impl FromStr for Location {
+    type Err = anyhow::Error;
+
+    /// Accepts the latitude and longitude separated by a comma, e.g. "52.52,13.40"
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let (latitude, longitude) = st.split_once(',').ok_or_else(|| {
+            anyhow::anyhow!("\"{st}\" is not a latitude and longitude (e.g. \"52.52,13.40\")")
+        })?;
+
+        let latitude = latitude.trim().parse::<f64>()?;
+        let longitude = longitude.trim().parse::<f64>()?;
+
+        anyhow::ensure!(
+            (-90_f64..=90_f64).contains(&latitude),
+            "The latitude {latitude} is not between -90 and 90"
+        );
+        anyhow::ensure!(
+            (-180_f64..=180_f64).contains(&longitude),
+            "The longitude {longitude} is not between -180 and 180"
+        );
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fo, "{},{}", self.latitude, self.longitude)
+    }
+}
+
+fn sin_degrees(degrees: f64) -> f64 {
+    (degrees * PI / 180_f64).sin()
+}
+
+fn cos_degrees(degrees: f64) -> f64 {
+    (degrees * PI / 180_f64).cos()
+}
+
+/// Rounded to the second
+fn from_julian_day(julian_day: f64) -> Option<OffsetDateTime> {
+    let seconds = ((julian_day - UNIX_EPOCH_JULIAN_DAY) * SECONDS_PER_DAY).round();
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "Checked by from_unix_timestamp"
+    )]
+    OffsetDateTime::from_unix_timestamp(seconds as i64).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sun::{Daylight, Location};
+    use time::{macros::datetime, Duration};
+
+    /// How far from the published times the equation's can be
+    const TOLERANCE: Duration = Duration::minutes(3_i64);
+
+    #[test]
+    fn test_daylight() -> anyhow::Result<()> {
+        let berlin = "52.52,13.40".parse::<Location>()?;
+
+        let Some(Daylight::Day {
+            sunrise,
+            sunset,
+            is_daylight,
+        }) = berlin.daylight(datetime!(2024-06-21 12:00 UTC))
+        else {
+            anyhow::bail!("The sun does not rise and set in Berlin");
+        };
+
+        // 04:43 and 21:33 CEST
+        anyhow::ensure!((sunrise - datetime!(2024-06-21 2:43 UTC)).abs() < TOLERANCE);
+        anyhow::ensure!((sunset - datetime!(2024-06-21 19:33 UTC)).abs() < TOLERANCE);
+        anyhow::ensure!(is_daylight);
+
+        anyhow::ensure!(matches!(
+            berlin.daylight(datetime!(2024-06-21 23:00 UTC)),
+            Some(Daylight::Day {
+                is_daylight: false,
+                ..
+            })
+        ));
+
+        let tromso = "69.65,18.96".parse::<Location>()?;
+
+        anyhow::ensure!(
+            tromso.daylight(datetime!(2024-06-21 23:00 UTC)) == Some(Daylight::PolarDay)
+        );
+        anyhow::ensure!(
+            tromso.daylight(datetime!(2024-12-21 12:00 UTC)) == Some(Daylight::PolarNight)
+        );
+
+        anyhow::ensure!("52.52".parse::<Location>().is_err());
+        anyhow::ensure!("91,0".parse::<Location>().is_err());
+
+        Ok(())
+    }
+}
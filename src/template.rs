@@ -0,0 +1,96 @@
+//! Output shaped by a template, with `{{name}}` placeholders for the fields of each candidate (e.g. "{{unit}}: {{utc}}
+//! ({{relative}})")
+
+use std::str::FromStr;
+
+/// The names placeholders can have
+pub const FIELDS: [&str; 6_usize] = ["unit", "utc", "local", "relative", "delta_seconds", "best"];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Part {
+    Literal(String),
+    Field(&'static str),
+}
+
+/// A parsed template, whose placeholders are all known fields
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Template(Vec<Part>);
+
+impl Template {
+    /// Fills in each placeholder with what `field` returns for its name
+    #[must_use]
+    pub fn render(&self, field: impl Fn(&str) -> String) -> String {
+        self.0
+            .iter()
+            .map(|pa| match pa {
+                Part::Literal(st) => st.clone(),
+                Part::Field(st) => field(st),
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Template {
+    type Err = anyhow::Error;
+
+    /// Whitespace inside the braces is ignored (e.g. "{{ utc }}")
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        let mut part_vec = Vec::<Part>::new();
+
+        let mut rest = st;
+
+        while let Some(us) = rest.find("{{") {
+            if us > 0_usize {
+                part_vec.push(Part::Literal(rest[..us].to_owned()));
+            }
+
+            let after = &rest[us + 2_usize..];
+
+            let end = after.find("}}").ok_or_else(|| {
+                anyhow::anyhow!("The placeholder at \"{}\" is not closed", &rest[us..])
+            })?;
+
+            let name = after[..end].trim();
+
+            let field = FIELDS.into_iter().find(|fi| *fi == name).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unrecognized placeholder \"{{{{{name}}}}}\" (expected one of {})",
+                    FIELDS.map(|fi| format!("\"{fi}\"")).join(", ")
+                )
+            })?;
+
+            part_vec.push(Part::Field(field));
+
+            rest = &after[end + 2_usize..];
+        }
+
+        if !rest.is_empty() {
+            part_vec.push(Part::Literal(rest.to_owned()));
+        }
+
+        Ok(Self(part_vec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::template::Template;
+
+    #[test]
+    fn test_template() -> anyhow::Result<()> {
+        let template = "{{unit}}: {{ utc }} ({{relative}})".parse::<Template>()?;
+
+        anyhow::ensure!(template.render(str::to_uppercase) == "UNIT: UTC (RELATIVE)");
+        anyhow::ensure!(
+            "no placeholders"
+                .parse::<Template>()?
+                .render(|_| String::new())
+                == "no placeholders"
+        );
+
+        anyhow::ensure!("{{unit}".parse::<Template>().is_err());
+        anyhow::ensure!("{{timezone}}".parse::<Template>().is_err());
+
+        Ok(())
+    }
+}
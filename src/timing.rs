@@ -0,0 +1,81 @@
+//! How long each stage of turning input into output took, for reporting with `--timing`
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Time spent in each stage, accumulated over every timestamp handled so far
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timing {
+    /// Recognizing the input and interpreting it in each unit
+    pub parsing: Duration,
+    /// Determining the UTC offset of the time zone output is displayed in
+    pub zone_resolution: Duration,
+    /// Formatting (and printing) the output
+    pub formatting: Duration,
+}
+
+impl Timing {
+    /// Runs `function`, adding the time it took to `stage`
+    pub fn measure<T>(stage: &mut Duration, function: impl FnOnce() -> T) -> T {
+        let instant = Instant::now();
+
+        let result = function();
+
+        *stage += instant.elapsed();
+
+        result
+    }
+
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.parsing + self.zone_resolution + self.formatting
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fo,
+            "parsing {} µs, time zone resolution {} µs, formatting {} µs (total {} µs)",
+            self.parsing.as_micros(),
+            self.zone_resolution.as_micros(),
+            self.formatting.as_micros(),
+            self.total().as_micros()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timing::Timing;
+    use std::time::Duration;
+
+    #[test]
+    fn test_timing() -> anyhow::Result<()> {
+        let mut timing = Timing::default();
+
+        let value = Timing::measure(&mut timing.parsing, || 42_i32);
+
+        anyhow::ensure!(value == 42_i32);
+
+        timing.zone_resolution += Duration::from_micros(3_u64);
+        timing.formatting += Duration::from_micros(45_u64);
+
+        anyhow::ensure!(timing.total() >= Duration::from_micros(48_u64));
+
+        let timing = Timing {
+            parsing: Duration::from_micros(12_u64),
+            zone_resolution: Duration::from_micros(3_u64),
+            formatting: Duration::from_micros(45_u64),
+        };
+
+        anyhow::ensure!(
+            timing.to_string()
+                == "parsing 12 µs, time zone resolution 3 µs, formatting 45 µs (total 60 µs)"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! The time steps of time-based one-time passwords (RFC 6238), for debugging clock drift in two-factor authentication
+
+use std::fmt;
+use time::OffsetDateTime;
+
+/// The step length almost every authenticator app uses
+pub const DEFAULT_PERIOD: u64 = 30_u64;
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000_i128;
+
+/// The TOTP time step an instant falls in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Step {
+    /// The counter the one-time password is generated from, the Unix time in seconds divided by the period (rounded
+    /// down)
+    pub counter: i128,
+    /// Whole seconds until the next step starts, from 1 to the period
+    pub seconds_remaining: i128,
+    pub period: u64,
+}
+
+/// The step `instant` falls in when steps are `period` seconds long
+#[must_use]
+pub fn step(instant: OffsetDateTime, period: u64) -> Step {
+    let seconds = instant
+        .unix_timestamp_nanos()
+        .div_euclid(NANOSECONDS_PER_SECOND);
+
+    let period_i128 = i128::from(period.max(1_u64));
+
+    Step {
+        counter: seconds.div_euclid(period_i128),
+        seconds_remaining: period_i128 - seconds.rem_euclid(period_i128),
+        period,
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fo,
+            "TOTP counter {} ({}-second steps), {} s left in the step",
+            self.counter, self.period, self.seconds_remaining
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::totp::{step, DEFAULT_PERIOD};
+    use time::macros::datetime;
+
+    #[test]
+    fn test_step() -> anyhow::Result<()> {
+        // The first test vector of RFC 6238
+        let totp_step = step(datetime!(1970-01-01 0:00:59 UTC), DEFAULT_PERIOD);
+
+        anyhow::ensure!(totp_step.counter == 1_i128);
+        anyhow::ensure!(totp_step.seconds_remaining == 1_i128);
+
+        let totp_step = step(datetime!(2023-11-14 22:13:20.5 UTC), DEFAULT_PERIOD);
+
+        anyhow::ensure!(totp_step.counter == 56_666_666_i128);
+        anyhow::ensure!(totp_step.seconds_remaining == 10_i128);
+        anyhow::ensure!(
+            totp_step.to_string()
+                == "TOTP counter 56666666 (30-second steps), 10 s left in the step"
+        );
+
+        anyhow::ensure!(
+            step(datetime!(2023-11-14 22:14:00 UTC), 60_u64).seconds_remaining == 60_i128
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+//! An interactive converter that interprets the input again on every keystroke, showing every readable candidate in
+//! several time zones at once
+//!
+//! The selected candidate is copied with an OSC 52 escape sequence, which the terminal puts on the system clipboard
+//! (this also works over SSH, but some terminals have to be told to allow it).
+
+use crate::{
+    date_time,
+    interpret::{self, Settings},
+    Zone,
+};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Paragraph, Row, Table, TableState},
+    DefaultTerminal, Frame,
+};
+use std::io::{self, Write as _};
+use time::Duration;
+
+const HELP: &str = "Enter: copy   Up/Down: select   Ctrl+U: clear   Esc: quit";
+
+/// What a keystroke asks for
+enum Action {
+    Continue,
+    Copy(String),
+    Quit,
+}
+
+/// What the input currently says, and which candidate is selected
+pub struct App {
+    input: String,
+    zones: Vec<Zone>,
+    settings: Settings,
+    table_state: TableState,
+    /// Shown instead of the help until the next keystroke
+    status: Option<String>,
+}
+
+impl App {
+    /// Shows every instant in each of `zones`, in order
+    #[must_use]
+    pub fn new(zones: Vec<Zone>, settings: Settings) -> Self {
+        Self {
+            input: String::new(),
+            zones,
+            settings,
+            table_state: TableState::default().with_selected(0_usize),
+            status: None,
+        }
+    }
+
+    /// The cells of the table: the label, the instant in each zone (RFC 3339), and how far it is from now
+    ///
+    /// A zone whose offset cannot be found gets the error in its cell, so the other zones and rows are still shown.
+    #[must_use]
+    pub fn rows(&self) -> Vec<Vec<String>> {
+        let now = self.settings.now();
+
+        interpret::interpret_input(&self.input, &self.settings)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(st, of)| {
+                let mut cell_vec = vec![st];
+
+                for zo in &self.zones {
+                    cell_vec.push(zo.offset_at(of).map_or_else(
+                        |er| er.to_string(),
+                        |ut| date_time::format_rfc3339(of.to_offset(ut)),
+                    ));
+                }
+
+                let delta = Duration::seconds((of - now).whole_seconds());
+
+                cell_vec.push(format!(
+                    "{}{delta}",
+                    if delta.is_negative() { "" } else { "+" }
+                ));
+
+                cell_vec
+            })
+            .collect()
+    }
+
+    /// Enter copies the selected instant in the first zone
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        self.status = None;
+
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Esc => return Action::Quit,
+            KeyCode::Char('c') if control => return Action::Quit,
+            KeyCode::Char('u') if control => self.input.clear(),
+            KeyCode::Char(ch) => self.input.push(ch),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Up => self.table_state.select_previous(),
+            KeyCode::Down => self.table_state.select_next(),
+            KeyCode::Enter => {
+                let row_vec = self.rows();
+
+                let selected = self
+                    .table_state
+                    .selected()
+                    .and_then(|us| row_vec.get(us))
+                    .and_then(|ve| ve.get(1_usize));
+
+                if let Some(st) = selected {
+                    return Action::Copy(st.clone());
+                }
+            }
+            _ => {}
+        }
+
+        Action::Continue
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>) {
+        let [input_area, table_area, status_area] = Layout::vertical([
+            Constraint::Length(3_u16),
+            Constraint::Min(1_u16),
+            Constraint::Length(1_u16),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(
+            Paragraph::new(self.input.as_str()).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Timestamp or date"),
+            ),
+            input_area,
+        );
+
+        let row_vec = self.rows();
+
+        // Keeps a row selected as the input changes (the table unselects everything while it is empty)
+        let last = row_vec.len().saturating_sub(1_usize);
+
+        self.table_state.select(Some(
+            self.table_state
+                .selected()
+                .map_or(0_usize, |us| us.min(last)),
+        ));
+
+        let mut header_vec = vec!["unit".to_owned()];
+
+        header_vec.extend(self.zones.iter().map(ToString::to_string));
+        header_vec.push("from now".to_owned());
+
+        let mut width_vec = vec![Constraint::Length(12_u16)];
+
+        width_vec.extend(self.zones.iter().map(|_| Constraint::Length(36_u16)));
+        width_vec.push(Constraint::Min(10_u16));
+
+        let table = Table::new(row_vec.into_iter().map(Row::new), width_vec)
+            .header(Row::new(header_vec).style(Style::default().add_modifier(Modifier::BOLD)))
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(table, table_area, &mut self.table_state);
+
+        frame.render_widget(
+            Paragraph::new(self.status.as_deref().unwrap_or(HELP)),
+            status_area,
+        );
+
+        frame.set_cursor_position((
+            input_area.x + 1_u16 + u16::try_from(self.input.chars().count()).unwrap_or(u16::MAX),
+            input_area.y + 1_u16,
+        ));
+    }
+}
+
+/// Runs the app until Esc or Ctrl+C is pressed, restoring the terminal afterwards
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be drawn on or read from
+pub fn run(zones: Vec<Zone>, settings: Settings) -> anyhow::Result<()> {
+    let mut terminal = ratatui::init();
+
+    let result = run_app(&mut terminal, &mut App::new(zones, settings));
+
+    ratatui::restore();
+
+    result
+}
+
+fn run_app(terminal: &mut DefaultTerminal, app: &mut App) -> anyhow::Result<()> {
+    loop {
+        terminal.draw(|fr| app.draw(fr))?;
+
+        let Event::Key(ke) = event::read()? else {
+            continue;
+        };
+
+        if ke.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match app.handle_key(ke) {
+            Action::Continue => {}
+            Action::Copy(st) => {
+                copy(&st)?;
+
+                app.status = Some(format!("Copied {st}"));
+            }
+            Action::Quit => return Ok(()),
+        }
+    }
+}
+
+/// Asks the terminal to put `text` on the clipboard
+fn copy(text: &str) -> anyhow::Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+
+    stdout.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{interpret::Settings, tui::App, Zone};
+
+    #[test]
+    fn test_rows() -> anyhow::Result<()> {
+        let mut app = App::new(
+            vec![Zone::Utc, "+01:00".parse::<Zone>()?],
+            Settings::deterministic(),
+        );
+
+        app.input = "1700000000".to_owned();
+
+        let row_vec = app.rows();
+
+        anyhow::ensure!(
+            row_vec.first()
+                == Some(&vec![
+                    "seconds".to_owned(),
+                    "2023-11-14T22:13:20Z".to_owned(),
+                    "2023-11-14T23:13:20+01:00".to_owned(),
+                    "+8718d22h13m20s".to_owned(),
+                ])
+        );
+
+        app.input = "2023-11-14T22:13:20Z".to_owned();
+
+        anyhow::ensure!(app.rows().len() == 1_usize);
+
+        app.input = "not a timestamp".to_owned();
+
+        anyhow::ensure!(app.rows().is_empty());
+
+        app.input = "-100000000000".to_owned();
+
+        anyhow::ensure!(app
+            .rows()
+            .iter()
+            .any(|ve| ve.get(1_usize).is_some_and(|st| st.starts_with("-001199-"))));
+
+        Ok(())
+    }
+}
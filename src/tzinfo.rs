@@ -0,0 +1,181 @@
+//! Offset history of IANA time zones, from the copy of the time zone database bundled into the binary
+
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+/// How far from the instant of interest to look for transitions
+const SEARCH_YEARS: i64 = 100_i64;
+
+/// The step used to look for transitions, which can miss a pair of transitions less than this far apart
+const SEARCH_STEP: Duration = Duration::DAY;
+
+/// What the clocks in a zone show during a period between transitions
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocalType {
+    pub offset: UtcOffset,
+    pub is_dst: bool,
+    /// e.g. "IST" or "GMT"
+    pub abbreviation: String,
+}
+
+/// An instant at which a zone's offset, daylight saving time flag, or abbreviation changes
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transition {
+    pub instant: OffsetDateTime,
+    pub before: LocalType,
+    pub after: LocalType,
+}
+
+pub struct ZoneInfo {
+    pub name: String,
+    pub around: OffsetDateTime,
+    /// In effect at `around`
+    pub local_type: LocalType,
+    /// The latest transition first
+    pub previous: Vec<Transition>,
+    /// The earliest transition first
+    pub next: Vec<Transition>,
+}
+
+/// The version of the bundled time zone database, e.g. "2025a"
+#[must_use]
+pub const fn database_version() -> &'static str {
+    tzdb::VERSION
+}
+
+/// The names of the zones in the database that contain `pattern` (ignoring case), in alphabetical order
+#[must_use]
+pub fn zone_names(pattern: &str) -> Vec<&'static str> {
+    let pattern = pattern.to_lowercase();
+
+    tzdb::TZ_NAMES
+        .iter()
+        .copied()
+        .filter(|st| st.to_lowercase().contains(&pattern))
+        .collect()
+}
+
+/// Looks up the zone named `name` (e.g. "Europe/Dublin") and finds up to `count` transitions on each side of `around`
+///
+/// # Errors
+///
+/// Returns an error if there is no zone named `name`
+pub fn zone_info(name: &str, around: OffsetDateTime, count: usize) -> anyhow::Result<ZoneInfo> {
+    let time_zone_ref = tzdb::tz_by_name(name)
+        .ok_or_else(|| anyhow::anyhow!("Unrecognized time zone \"{name}\""))?;
+
+    let local_type_at = |instant: OffsetDateTime| -> anyhow::Result<LocalType> {
+        let local_time_type = time_zone_ref
+            .find_local_time_type(instant.unix_timestamp())
+            .map_err(|er| anyhow::anyhow!("{er}"))?;
+
+        Ok(LocalType {
+            offset: UtcOffset::from_whole_seconds(local_time_type.ut_offset())?,
+            is_dst: local_time_type.is_dst(),
+            abbreviation: local_time_type.time_zone_designation().to_owned(),
+        })
+    };
+
+    let previous = find_transitions(&local_type_at, around, -SEARCH_STEP, count)?;
+    let next = find_transitions(&local_type_at, around, SEARCH_STEP, count)?;
+
+    Ok(ZoneInfo {
+        name: name.to_owned(),
+        around,
+        local_type: local_type_at(around)?,
+        previous,
+        next,
+    })
+}
+
+/// Steps from `start` by `step` until `count` transitions are found or [`SEARCH_YEARS`] have been searched, narrowing
+/// each one down to the second
+fn find_transitions(
+    local_type_at: &dyn Fn(OffsetDateTime) -> anyhow::Result<LocalType>,
+    start: OffsetDateTime,
+    step: Duration,
+    count: usize,
+) -> anyhow::Result<Vec<Transition>> {
+    let limit = Duration::days(SEARCH_YEARS * 365_i64);
+
+    let mut transition_vec = Vec::<Transition>::with_capacity(count);
+
+    // Transitions happen on whole seconds
+    let mut instant = start.replace_nanosecond(0_u32)?;
+    let mut local_type = local_type_at(instant)?;
+
+    while transition_vec.len() < count && (instant - start).abs() < limit {
+        let Some(next_instant) = instant.checked_add(step) else {
+            break;
+        };
+
+        let next_local_type = local_type_at(next_instant)?;
+
+        if next_local_type != local_type {
+            // "earlier" always has the type in effect before the transition
+            let (mut earlier, mut later) = if step.is_negative() {
+                (next_instant, instant)
+            } else {
+                (instant, next_instant)
+            };
+
+            let before = local_type_at(earlier)?;
+
+            while later - earlier > Duration::SECOND {
+                let middle = earlier + Duration::seconds((later - earlier).whole_seconds() / 2_i64);
+
+                if local_type_at(middle)? == before {
+                    earlier = middle;
+                } else {
+                    later = middle;
+                }
+            }
+
+            transition_vec.push(Transition {
+                instant: later,
+                before,
+                after: local_type_at(later)?,
+            });
+        }
+
+        instant = next_instant;
+        local_type = next_local_type;
+    }
+
+    Ok(transition_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tzinfo::{zone_info, zone_names};
+    use time::macros::{datetime, offset};
+
+    #[test]
+    fn test_zone_info() -> anyhow::Result<()> {
+        let dublin = zone_info("Europe/Dublin", datetime!(2024-05-01 12:00 UTC), 2_usize)?;
+
+        anyhow::ensure!(dublin.local_type.offset == offset!(+1));
+        anyhow::ensure!(dublin.local_type.abbreviation == "IST");
+
+        anyhow::ensure!(dublin.previous[0_usize].instant == datetime!(2024-03-31 1:00 UTC));
+        anyhow::ensure!(dublin.previous[1_usize].instant == datetime!(2023-10-29 1:00 UTC));
+        anyhow::ensure!(dublin.next[0_usize].instant == datetime!(2024-10-27 1:00 UTC));
+        anyhow::ensure!(dublin.next[0_usize].after.abbreviation == "GMT");
+
+        anyhow::ensure!(dublin.next.len() == 2_usize);
+
+        anyhow::ensure!(zone_info(
+            "Mars/Olympus_Mons",
+            datetime!(2024-05-01 12:00 UTC),
+            1_usize
+        )
+        .is_err());
+
+        let york_vec = zone_names("york");
+
+        anyhow::ensure!(york_vec.contains(&"America/New_York"));
+        anyhow::ensure!(york_vec.iter().all(|st| st.to_lowercase().contains("york")));
+        anyhow::ensure!(zone_names("Mars").is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+//! Whether something with a validity period, like a token or a certificate, is valid at an instant
+
+use time::{Duration, OffsetDateTime};
+
+/// Where an instant falls relative to a validity period
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    /// The start of the period is this far away
+    NotYetValid(Duration),
+    /// Expires this far away, or never if the period has no end
+    Valid(Option<Duration>),
+    /// Expired this long ago
+    Expired(Duration),
+}
+
+/// Where `now` falls relative to the period from `not_before` to `not_after` (either of which can be open)
+#[must_use]
+pub fn verdict(
+    not_before: Option<OffsetDateTime>,
+    not_after: Option<OffsetDateTime>,
+    now: OffsetDateTime,
+) -> Verdict {
+    if let Some(of) = not_after {
+        if of <= now {
+            return Verdict::Expired(now - of);
+        }
+    }
+
+    if let Some(of) = not_before {
+        if of > now {
+            return Verdict::NotYetValid(of - now);
+        }
+    }
+
+    Verdict::Valid(not_after.map(|of| of - now))
+}
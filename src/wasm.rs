@@ -0,0 +1,41 @@
+//! JavaScript bindings for the interpretation engine, so a converter running in the browser gives the same answers as
+//! the command line program
+//!
+//! Built with `wasm-pack build --no-default-features --features wasm`, which leaves out everything only the command
+//! line program needs (the clipboard, the terminal, and the HTTP server).
+
+use crate::{interpret::Settings, output, Zone};
+use wasm_bindgen::prelude::{wasm_bindgen, JsError, JsValue};
+
+/// Every readable candidate for `input` (a timestamp, or a date and time), best first, as an array of objects with the
+/// same fields as `--output csv`
+///
+/// `zone` is "UTC", "local" (the browser's time zone, which is used if it is missing), a name like "Europe/Berlin", or
+/// an offset like "+05:30".
+///
+/// # Errors
+///
+/// Throws if `zone` is not recognized, or if `input` is neither a timestamp nor a date and time
+#[wasm_bindgen]
+pub fn interpret(input: &str, zone: Option<String>) -> Result<JsValue, JsError> {
+    let zone = zone
+        .map_or(Ok(Zone::Local), |st| st.parse::<Zone>())
+        .map_err(|er| JsError::new(&er.to_string()))?;
+
+    let settings = Settings { now: None, zone };
+
+    let records = output::interpret_records(input.trim(), &settings).ok_or_else(|| {
+        JsError::new(&format!(
+            "\"{input}\" is not a timestamp or a date and time"
+        ))
+    })?;
+
+    Ok(serde_wasm_bindgen::to_value(&records)?)
+}
+
+/// The version of the interpretation engine
+#[wasm_bindgen]
+#[must_use]
+pub fn version() -> String {
+    env!("CARGO_PKG_VERSION").to_owned()
+}
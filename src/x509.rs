@@ -0,0 +1,207 @@
+//! The validity periods of X.509 certificates in PEM files
+//!
+//! Only as much DER is read as it takes to get to the validity period, so nothing else in a certificate is checked.
+
+use crate::validity::{self, Verdict};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use time::{macros::format_description, OffsetDateTime, PrimitiveDateTime};
+
+const PEM_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_END: &str = "-----END CERTIFICATE-----";
+
+const TAG_SEQUENCE: u8 = 0x30_u8;
+const TAG_UTC_TIME: u8 = 0x17_u8;
+const TAG_GENERALIZED_TIME: u8 = 0x18_u8;
+/// The explicitly tagged version, which version 1 certificates leave out
+const TAG_VERSION: u8 = 0xA0_u8;
+
+/// When a certificate starts and stops being valid
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Validity {
+    pub not_before: OffsetDateTime,
+    pub not_after: OffsetDateTime,
+}
+
+impl Validity {
+    #[must_use]
+    pub fn verdict(&self, now: OffsetDateTime) -> Verdict {
+        validity::verdict(Some(self.not_before), Some(self.not_after), now)
+    }
+}
+
+/// Reads the validity period of every certificate in `pem`, in order (e.g. a leaf certificate and its chain)
+///
+/// # Errors
+///
+/// Returns an error if there are no certificates, or if one cannot be decoded
+pub fn parse_pem(pem: &str) -> anyhow::Result<Vec<Validity>> {
+    let mut validity_vec = Vec::<Validity>::new();
+
+    let mut rest = pem;
+
+    while let Some((_, after_begin)) = rest.split_once(PEM_BEGIN) {
+        let Some((base64, after_end)) = after_begin.split_once(PEM_END) else {
+            anyhow::bail!(
+                "Certificate {} has no end line",
+                validity_vec.len() + 1_usize
+            );
+        };
+
+        let der = STANDARD
+            .decode(base64.split_whitespace().collect::<String>())
+            .map_err(|er| {
+                anyhow::anyhow!(
+                    "Could not decode certificate {}: {er}",
+                    validity_vec.len() + 1_usize
+                )
+            })?;
+
+        validity_vec.push(parse_der(&der).map_err(|er| {
+            anyhow::anyhow!(
+                "Could not read certificate {}: {er}",
+                validity_vec.len() + 1_usize
+            )
+        })?);
+
+        rest = after_end;
+    }
+
+    anyhow::ensure!(!validity_vec.is_empty(), "There is no \"{PEM_BEGIN}\" line");
+
+    Ok(validity_vec)
+}
+
+/// Reads the validity period of a DER-encoded certificate
+///
+/// # Errors
+///
+/// Returns an error if the certificate is malformed before the end of its validity period
+pub fn parse_der(der: &[u8]) -> anyhow::Result<Validity> {
+    let (certificate, _) = read_element(der, TAG_SEQUENCE)?;
+    let (tbs_certificate, _) = read_element(certificate, TAG_SEQUENCE)?;
+
+    let mut rest = tbs_certificate;
+
+    if rest.first() == Some(&TAG_VERSION) {
+        rest = read_any_element(rest)?.2;
+    }
+
+    // The serial number, the signature algorithm, and the issuer
+    for _ in 0_u8..3_u8 {
+        rest = read_any_element(rest)?.2;
+    }
+
+    let (validity, _) = read_element(rest, TAG_SEQUENCE)?;
+
+    let (not_before, rest) = read_time(validity)?;
+    let (not_after, _) = read_time(rest)?;
+
+    Ok(Validity {
+        not_before,
+        not_after,
+    })
+}
+
+/// Reads a `UTCTime` (which has a two-digit year, 1950 through 2049) or a `GeneralizedTime`
+fn read_time(by: &[u8]) -> anyhow::Result<(OffsetDateTime, &[u8])> {
+    let (tag, contents, rest) = read_any_element(by)?;
+
+    let string = std::str::from_utf8(contents)?;
+
+    let generalized_time = match tag {
+        TAG_UTC_TIME => {
+            let year = string.get(..2_usize).unwrap_or_default().parse::<u8>()?;
+
+            format!("{}{string}", if year >= 50_u8 { "19" } else { "20" })
+        }
+        TAG_GENERALIZED_TIME => string.to_owned(),
+        _ => anyhow::bail!("Expected a time, found tag {tag:#04x}"),
+    };
+
+    let primitive_date_time = PrimitiveDateTime::parse(
+        &generalized_time,
+        format_description!("[year][month][day][hour][minute][second]Z"),
+    )
+    .map_err(|er| anyhow::anyhow!("Could not parse time \"{string}\": {er}"))?;
+
+    Ok((primitive_date_time.assume_utc(), rest))
+}
+
+fn read_element(by: &[u8], expected_tag: u8) -> anyhow::Result<(&[u8], &[u8])> {
+    let (tag, contents, rest) = read_any_element(by)?;
+
+    anyhow::ensure!(
+        tag == expected_tag,
+        "Expected tag {expected_tag:#04x}, found {tag:#04x}"
+    );
+
+    Ok((contents, rest))
+}
+
+/// Splits the element at the start of `by` into its tag and contents, and returns what follows it
+fn read_any_element(by: &[u8]) -> anyhow::Result<(u8, &[u8], &[u8])> {
+    let [tag, first_length_byte, rest @ ..] = by else {
+        anyhow::bail!("Unexpected end of data");
+    };
+
+    // Short lengths are one byte, and longer ones have the number of length bytes that follow in the low bits
+    let (length, rest) = if *first_length_byte < 0x80_u8 {
+        (usize::from(*first_length_byte), rest)
+    } else {
+        let length_byte_count = usize::from(first_length_byte & 0x7F_u8);
+
+        anyhow::ensure!(
+            (1_usize..=4_usize).contains(&length_byte_count) && rest.len() >= length_byte_count,
+            "Invalid length"
+        );
+
+        let (length_bytes, rest) = rest.split_at(length_byte_count);
+
+        let length = length_bytes
+            .iter()
+            .fold(0_usize, |us, byt| (us << 8_u32) | usize::from(*byt));
+
+        (length, rest)
+    };
+
+    anyhow::ensure!(rest.len() >= length, "Unexpected end of data");
+
+    let (contents, rest) = rest.split_at(length);
+
+    Ok((*tag, contents, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::x509::parse_pem;
+    use time::macros::datetime;
+
+    /// A self-signed certificate whose notBefore is a `UTCTime` and whose notAfter is a `GeneralizedTime`
+    const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIBdTCCARugAwIBAgIUBEiZiCheSXd/T8yNa2feE/WI6EYwCgYIKoZIzj0EAwIw
+DzENMAsGA1UEAwwEdGVzdDAgFw0yNjEwMTYxNjEyMDNaGA8yMDU0MDMwMzE2MTIw
+M1owDzENMAsGA1UEAwwEdGVzdDBZMBMGByqGSM49AgEGCCqGSM49AwEHA0IABN/X
+c3ZQmXMZNU7SXzOm2kxG3vYfiVGqQTmgTOI8IFRyZZ6geH0e58mz0twsC8FhnXYO
+Tju3W90vwWIxfGEHUFGjUzBRMB0GA1UdDgQWBBRtSrWD7aVU+2QrizrBpULUV+77
+0DAfBgNVHSMEGDAWgBRtSrWD7aVU+2QrizrBpULUV+770DAPBgNVHRMBAf8EBTAD
+AQH/MAoGCCqGSM49BAMCA0gAMEUCIFboZdmDAe0Ibkk7DPR3BGiCcSjZWh1p7D8H
+1wSl4TtkAiEA2apN9Bg756OF6r9JjLW7T8/OKXYKp9K8O+iCSRiHkj0=
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn test_parse_pem() -> anyhow::Result<()> {
+        let validity_vec = parse_pem(CERTIFICATE)?;
+
+        anyhow::ensure!(validity_vec.len() == 1_usize);
+        anyhow::ensure!(validity_vec[0_usize].not_before == datetime!(2026-10-16 16:12:03 UTC));
+        anyhow::ensure!(validity_vec[0_usize].not_after == datetime!(2054-03-03 16:12:03 UTC));
+
+        anyhow::ensure!(parse_pem(&CERTIFICATE.repeat(2_usize))?.len() == 2_usize);
+
+        anyhow::ensure!(parse_pem("").is_err());
+        anyhow::ensure!(parse_pem(&CERTIFICATE.replace("MIIBdTCC", "MIIBdTCD")).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use std::{env, fmt, str::FromStr};
+use time::{Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// How far on either side of a wall clock time to look for a different offset, which is enough for daylight saving
+/// time transitions (but can miss one of two transitions less than this far apart)
+const TRANSITION_WINDOW: Duration = Duration::DAY;
+
+/// A time zone that dates can be displayed in or resolved against
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Zone {
+    Utc,
+    Local,
+    Fixed(UtcOffset),
+    /// A zone in the bundled time zone database, by its canonical name (e.g. "Europe/Berlin")
+    Named(&'static str),
+}
+
+/// How many instants a wall clock time in a zone stands for
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WallClock {
+    /// Exactly one
+    Unique,
+    /// Two, because the clocks were turned back over it (e.g. at the end of daylight saving time)
+    Ambiguous {
+        earlier: UtcOffset,
+        later: UtcOffset,
+    },
+    /// None, because the clocks were turned forward over it (e.g. at the start of daylight saving time)
+    Skipped { before: UtcOffset, after: UtcOffset },
+}
+
+impl Zone {
+    /// The offset from UTC in effect in this zone at `instant`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is the local zone and the local offset cannot be determined
+    ///
+    /// This is the only place the local offset is asked for. The local zone is looked up in the bundled time zone
+    /// database, so the offset follows the zone's rules at `instant` (rather than being the current one) and can be
+    /// found from any thread. Only when the local zone has no name in the database is the operating system (or, with
+    /// the "wasm" feature, JavaScript's `Date`) asked instead.
+    pub fn offset_at(self, instant: OffsetDateTime) -> anyhow::Result<UtcOffset> {
+        let utc_offset = match self {
+            Self::Utc => UtcOffset::UTC,
+            Self::Local => match look_up(None, instant) {
+                Some((ut, _)) => ut,
+                None => UtcOffset::local_offset_at(instant)?,
+            },
+            Self::Fixed(ut) => ut,
+            Self::Named(st) => {
+                look_up(Some(st), instant)
+                    .ok_or_else(|| anyhow::anyhow!("No offset for {st} at {instant}"))?
+                    .0
+            }
+        };
+
+        Ok(utc_offset)
+    }
+
+    /// The abbreviation of the time in this zone at `instant` (e.g. "CET" or "PDT"), or `None` if it has none (e.g. a
+    /// fixed offset, or a zone the time zone database only knows by its offset, like "+05")
+    ///
+    /// The local zone is looked up by name in the bundled time zone database, so this is `None` if its name is unknown.
+    #[must_use]
+    pub fn abbreviation_at(self, instant: OffsetDateTime) -> Option<String> {
+        let name = match self {
+            Self::Utc => return Some("UTC".to_owned()),
+            Self::Local => None,
+            Self::Fixed(_) => return None,
+            Self::Named(st) => Some(st),
+        };
+
+        let (_, abbreviation) = look_up(name, instant)?;
+
+        abbreviation
+            .starts_with(char::is_alphabetic)
+            .then_some(abbreviation)
+    }
+
+    /// Interprets `date_time` as a wall clock time in this zone
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is the local zone and the local offset cannot be determined
+    pub fn resolve(self, date_time: PrimitiveDateTime) -> anyhow::Result<OffsetDateTime> {
+        // The offset depends on the instant, which depends on the offset, so guess using UTC first and then correct
+        let guess = date_time.assume_utc();
+
+        let guess_offset = self.offset_at(guess)?;
+
+        let offset = self.offset_at(date_time.assume_offset(guess_offset))?;
+
+        Ok(date_time.assume_offset(offset))
+    }
+
+    /// Whether `date_time` happens once, twice, or not at all in this zone, because of a transition around it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is the local zone and the local offset cannot be determined
+    pub fn wall_clock(self, date_time: PrimitiveDateTime) -> anyhow::Result<WallClock> {
+        let guess = date_time.assume_utc();
+
+        let before = self.offset_at(guess.saturating_sub(TRANSITION_WINDOW))?;
+        let after = self.offset_at(guess.saturating_add(TRANSITION_WINDOW))?;
+
+        if before == after {
+            return Ok(WallClock::Unique);
+        }
+
+        // Whether reading `date_time` at `offset` gives an instant at which `offset` is in effect
+        let is_valid = |offset: UtcOffset| -> anyhow::Result<bool> {
+            Ok(self.offset_at(date_time.assume_offset(offset))? == offset)
+        };
+
+        let wall_clock = match (is_valid(before)?, is_valid(after)?) {
+            (true, true) => WallClock::Ambiguous {
+                earlier: before,
+                later: after,
+            },
+            (false, false) => WallClock::Skipped { before, after },
+            _ => WallClock::Unique,
+        };
+
+        Ok(wall_clock)
+    }
+}
+
+impl FromStr for Zone {
+    type Err = anyhow::Error;
+
+    fn from_str(st: &str) -> Result<Self, Self::Err> {
+        match st {
+            "UTC" | "utc" | "Z" | "z" => return Ok(Self::Utc),
+            "local" => return Ok(Self::Local),
+            _ => {}
+        }
+
+        if let Some(na) = tzdb::TZ_NAMES.iter().find(|na| na.eq_ignore_ascii_case(st)) {
+            return Ok(Self::Named(na));
+        }
+
+        let (sign, rest) = match st.as_bytes().first() {
+            Some(b'+') => (1_i8, &st[1_usize..]),
+            Some(b'-') => (-1_i8, &st[1_usize..]),
+            _ => anyhow::bail!(
+                "Unrecognized time zone \"{st}\" (expected \"UTC\", \"local\", a name like \"Europe/Berlin\", or an offset like \"+05:30\")"
+            ),
+        };
+
+        let (hours, minutes) = rest.split_once(':').unwrap_or((rest, "0"));
+
+        let hours = hours.parse::<i8>()?;
+        let minutes = minutes.parse::<i8>()?;
+
+        let utc_offset = UtcOffset::from_hms(sign * hours, sign * minutes, 0_i8)?;
+
+        Ok(Self::Fixed(utc_offset))
+    }
+}
+
+/// The offset and abbreviation in effect at `instant` in the zone named `name` in the bundled time zone database, or in
+/// the local zone if `name` is `None`
+///
+/// The local zone is the one named by the `TZ` environment variable if it is set (as the operating system does), and
+/// otherwise the system's.
+fn look_up(name: Option<&str>, instant: OffsetDateTime) -> Option<(UtcOffset, String)> {
+    let time_zone_ref = match name {
+        Some(st) => tzdb::tz_by_name(st),
+        None => match env::var("TZ") {
+            Ok(st) if !st.is_empty() => tzdb::tz_by_name(st.trim_start_matches(':')),
+            _ => tzdb::local_tz(),
+        },
+    }?;
+
+    let local_time_type = time_zone_ref
+        .find_local_time_type(instant.unix_timestamp())
+        .ok()?;
+
+    Some((
+        UtcOffset::from_whole_seconds(local_time_type.ut_offset()).ok()?,
+        local_time_type.time_zone_designation().to_owned(),
+    ))
+}
+
+/// `utc_offset` as "+05:30" (without seconds, unlike `UtcOffset`'s own formatting)
+#[must_use]
+pub fn format_offset(utc_offset: UtcOffset) -> String {
+    let (hours, minutes, _) = utc_offset.as_hms();
+
+    format!(
+        "{}{:02}:{:02}",
+        if utc_offset.is_negative() { '-' } else { '+' },
+        hours.unsigned_abs(),
+        minutes.unsigned_abs()
+    )
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, fo: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Utc => fo.write_str("UTC"),
+            Self::Local => fo.write_str("local"),
+            Self::Fixed(ut) => fo.write_str(&format_offset(*ut)),
+            Self::Named(st) => fo.write_str(st),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::zone::{WallClock, Zone};
+    use time::macros::{datetime, offset};
+
+    #[test]
+    fn test_named_zone() -> anyhow::Result<()> {
+        let berlin = "europe/berlin".parse::<Zone>()?;
+
+        anyhow::ensure!(berlin == Zone::Named("Europe/Berlin"));
+        anyhow::ensure!(berlin.to_string() == "Europe/Berlin");
+
+        anyhow::ensure!(berlin.offset_at(datetime!(2024-01-15 12:00 UTC))? == offset!(+1));
+        anyhow::ensure!(berlin.offset_at(datetime!(2024-07-15 12:00 UTC))? == offset!(+2));
+        anyhow::ensure!(
+            berlin
+                .abbreviation_at(datetime!(2024-07-15 12:00 UTC))
+                .as_deref()
+                == Some("CEST")
+        );
+
+        anyhow::ensure!(
+            berlin.wall_clock(datetime!(2024-03-31 2:30))?
+                == WallClock::Skipped {
+                    before: offset!(+1),
+                    after: offset!(+2)
+                }
+        );
+        anyhow::ensure!(
+            berlin.wall_clock(datetime!(2024-10-27 2:30))?
+                == WallClock::Ambiguous {
+                    earlier: offset!(+2),
+                    later: offset!(+1)
+                }
+        );
+        anyhow::ensure!(berlin.wall_clock(datetime!(2024-07-15 12:00))? == WallClock::Unique);
+
+        anyhow::ensure!("+05:30"
+            .parse::<Zone>()?
+            .abbreviation_at(datetime!(2024-07-15 12:00 UTC))
+            .is_none());
+        anyhow::ensure!("Mars/Olympus_Mons".parse::<Zone>().is_err());
+
+        Ok(())
+    }
+}